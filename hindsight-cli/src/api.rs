@@ -4,14 +4,15 @@
 //! to bridge from the CLI's synchronous code to the async API client.
 
 use anyhow::Result;
-use hindsight_client::Client as AsyncClient;
+use hindsight_client::{Capability, Client as AsyncClient};
 pub use hindsight_client::types;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::HashMap;
 
 // Types not defined in OpenAPI spec (TODO: add to openapi.json)
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AgentStats {
     pub bank_id: String,
     pub total_nodes: i32,
@@ -25,7 +26,7 @@ pub struct AgentStats {
     pub failed_operations: i32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Operation {
     pub id: String,
     pub task_type: String,
@@ -36,7 +37,7 @@ pub struct Operation {
     pub error_message: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct OperationsResponse {
     pub bank_id: String,
     pub operations: Vec<Operation>,
@@ -49,7 +50,7 @@ pub struct TraceInfo {
 }
 
 // Unified result for put_memories that handles both sync and async responses
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MemoryPutResult {
     pub success: bool,
     pub items_count: i64,
@@ -95,6 +96,24 @@ impl ApiClient {
         Ok(ApiClient { client, http_client, base_url, runtime })
     }
 
+    /// Version of the OpenAPI spec this CLI was built against
+    pub fn spec_version() -> &'static str {
+        AsyncClient::spec_version()
+    }
+
+    /// Status code of a plain `GET`; any response means the connection (and
+    /// TLS handshake) worked
+    pub fn probe(&self, path: &str, verbose: bool) -> Result<u16> {
+        self.runtime.block_on(async {
+            let url = format!("{}{}", self.base_url, path);
+            if verbose {
+                eprintln!("GET {}", url);
+            }
+            let response = self.http_client.get(&url).send().await?;
+            Ok(response.status().as_u16())
+        })
+    }
+
     pub fn list_agents(&self, _verbose: bool) -> Result<Vec<types::BankListItem>> {
         self.runtime.block_on(async {
             let response = self.client.list_banks(None).await?;
@@ -185,6 +204,7 @@ impl ApiClient {
         verbose: bool,
     ) -> Result<FileRetainResult> {
         self.runtime.block_on(async {
+            self.client.require(Capability::FileRetain).await?;
             let url = format!("{}/v1/default/banks/{}/files/retain", self.base_url, bank_id);
 
             let files_metadata: Vec<serde_json::Value> = files
@@ -286,7 +306,7 @@ impl ApiClient {
 
     pub fn clear_memories(&self, agent_id: &str, fact_type: Option<&str>, _verbose: bool) -> Result<types::DeleteResponse> {
         self.runtime.block_on(async {
-            let response = self.client.clear_bank_memories(agent_id, None, Some(fact_type)).await?;
+            let response = self.client.clear_bank_memories(agent_id, fact_type, None).await?;
             Ok(response.into_inner())
         })
     }
@@ -324,9 +344,11 @@ impl ApiClient {
         })
     }
 
-    pub fn list_operations(&self, agent_id: &str, _verbose: bool) -> Result<OperationsResponse> {
+    pub fn list_operations(&self, agent_id: &str, limit: Option<i64>, offset: Option<i64>, _verbose: bool) -> Result<OperationsResponse> {
         self.runtime.block_on(async {
-            let response = self.client.list_operations(agent_id, None, None, None, None).await?;
+            let limit = limit.and_then(|limit| std::num::NonZeroU64::new(limit.max(0) as u64));
+            let offset = offset.map(|offset| offset.max(0) as u64);
+            let response = self.client.list_operations(agent_id, limit, offset, None, None).await?;
             let value = response.into_inner();
             // Convert to JSON Value first, then parse into our type
             let json_value = serde_json::to_value(&value)?;
@@ -335,6 +357,16 @@ impl ApiClient {
         })
     }
 
+    /// Pending and processing operations of a bank (at most `limit` of them; `total` counts all).
+    /// The API's `pending` filter matches both states, and reports both as `pending`.
+    pub fn list_pending_operations(&self, bank_id: &str, limit: u64, _verbose: bool) -> Result<types::OperationsListResponse> {
+        self.runtime.block_on(async {
+            let limit = std::num::NonZeroU64::new(limit);
+            let response = self.client.list_operations(bank_id, limit, None, Some("pending"), None).await?;
+            Ok(response.into_inner())
+        })
+    }
+
     pub fn cancel_operation(&self, agent_id: &str, operation_id: &str, _verbose: bool) -> Result<types::DeleteResponse> {
         self.runtime.block_on(async {
             let response = self.client.cancel_operation(agent_id, operation_id, None).await?;
@@ -461,7 +493,7 @@ impl ApiClient {
         _verbose: bool,
     ) -> Result<types::BankConfigResponse> {
         self.runtime.block_on(async {
-            let response = self.client.get_bank_config(bank_id, None).await?;
+            let response = self.client.guard(Capability::BankConfig, self.client.get_bank_config(bank_id, None)).await?;
             Ok(response.into_inner())
         })
     }
@@ -476,7 +508,7 @@ impl ApiClient {
             // Convert HashMap to serde_json::Map
             let updates_map: serde_json::Map<String, serde_json::Value> = updates.into_iter().collect();
             let request = types::BankConfigUpdate { updates: updates_map };
-            let response = self.client.update_bank_config(bank_id, None, &request).await?;
+            let response = self.client.guard(Capability::BankConfig, self.client.update_bank_config(bank_id, None, &request)).await?;
             Ok(response.into_inner())
         })
     }
@@ -487,7 +519,7 @@ impl ApiClient {
         _verbose: bool,
     ) -> Result<types::BankConfigResponse> {
         self.runtime.block_on(async {
-            let response = self.client.reset_bank_config(bank_id, None).await?;
+            let response = self.client.guard(Capability::BankConfig, self.client.reset_bank_config(bank_id, None)).await?;
             Ok(response.into_inner())
         })
     }
@@ -535,25 +567,39 @@ impl ApiClient {
         })
     }
 
-    pub fn metrics(&self, _verbose: bool) -> Result<serde_json::Value> {
+    /// Prometheus exposition text. The generated client expects JSON here, but
+    /// the endpoint serves `text/plain`, so this goes through `http_client`.
+    pub fn metrics(&self, verbose: bool) -> Result<String> {
         self.runtime.block_on(async {
-            let response = self.client.metrics_endpoint_metrics_get().await?;
-            Ok(response.into_inner())
+            let url = format!("{}/metrics", self.base_url);
+            if verbose {
+                eprintln!("GET {}", url);
+            }
+
+            let response = self.http_client.get(&url).send().await?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                anyhow::bail!("Metrics request failed ({}): {}", status, text);
+            }
+            Ok(response.text().await?)
         })
     }
 
     // --- Mental Model Methods ---
 
-    pub fn list_mental_models(&self, bank_id: &str, _verbose: bool) -> Result<types::MentalModelListResponse> {
+    pub fn list_mental_models(&self, bank_id: &str, limit: Option<i64>, offset: Option<i64>, _verbose: bool) -> Result<types::MentalModelListResponse> {
         self.runtime.block_on(async {
-            let response = self.client.list_mental_models(bank_id, None, None, None, None, None).await?;
+            let limit = limit.and_then(|limit| std::num::NonZeroU64::new(limit.max(0) as u64));
+            let offset = offset.map(|offset| offset.max(0) as u64);
+            let response = self.client.guard(Capability::MentalModels, self.client.list_mental_models(bank_id, limit, offset, None, None, None)).await?;
             Ok(response.into_inner())
         })
     }
 
     pub fn get_mental_model(&self, bank_id: &str, mental_model_id: &str, _verbose: bool) -> Result<types::MentalModelResponse> {
         self.runtime.block_on(async {
-            let response = self.client.get_mental_model(bank_id, mental_model_id, None).await?;
+            let response = self.client.guard(Capability::MentalModels, self.client.get_mental_model(bank_id, mental_model_id, None)).await?;
             Ok(response.into_inner())
         })
     }
@@ -565,7 +611,7 @@ impl ApiClient {
         _verbose: bool,
     ) -> Result<types::CreateMentalModelResponse> {
         self.runtime.block_on(async {
-            let response = self.client.create_mental_model(bank_id, None, request).await?;
+            let response = self.client.guard(Capability::MentalModels, self.client.create_mental_model(bank_id, None, request)).await?;
             Ok(response.into_inner())
         })
     }
@@ -578,37 +624,39 @@ impl ApiClient {
         _verbose: bool,
     ) -> Result<types::MentalModelResponse> {
         self.runtime.block_on(async {
-            let response = self.client.update_mental_model(bank_id, mental_model_id, None, request).await?;
+            let response = self.client.guard(Capability::MentalModels, self.client.update_mental_model(bank_id, mental_model_id, None, request)).await?;
             Ok(response.into_inner())
         })
     }
 
     pub fn delete_mental_model(&self, bank_id: &str, mental_model_id: &str, _verbose: bool) -> Result<serde_json::Value> {
         self.runtime.block_on(async {
-            let response = self.client.delete_mental_model(bank_id, mental_model_id, None).await?;
+            let response = self.client.guard(Capability::MentalModels, self.client.delete_mental_model(bank_id, mental_model_id, None)).await?;
             Ok(response.into_inner())
         })
     }
 
     pub fn refresh_mental_model(&self, bank_id: &str, mental_model_id: &str, _verbose: bool) -> Result<types::AsyncOperationSubmitResponse> {
         self.runtime.block_on(async {
-            let response = self.client.refresh_mental_model(bank_id, mental_model_id, None).await?;
+            let response = self.client.guard(Capability::MentalModels, self.client.refresh_mental_model(bank_id, mental_model_id, None)).await?;
             Ok(response.into_inner())
         })
     }
 
     // --- Directive Methods ---
 
-    pub fn list_directives(&self, bank_id: &str, _verbose: bool) -> Result<types::DirectiveListResponse> {
+    pub fn list_directives(&self, bank_id: &str, limit: Option<i64>, offset: Option<i64>, _verbose: bool) -> Result<types::DirectiveListResponse> {
         self.runtime.block_on(async {
-            let response = self.client.list_directives(bank_id, None, None, None, None, None, None).await?;
+            let limit = limit.and_then(|limit| std::num::NonZeroU64::new(limit.max(0) as u64));
+            let offset = offset.map(|offset| offset.max(0) as u64);
+            let response = self.client.guard(Capability::Directives, self.client.list_directives(bank_id, None, limit, offset, None, None, None)).await?;
             Ok(response.into_inner())
         })
     }
 
     pub fn get_directive(&self, bank_id: &str, directive_id: &str, _verbose: bool) -> Result<types::DirectiveResponse> {
         self.runtime.block_on(async {
-            let response = self.client.get_directive(bank_id, directive_id, None).await?;
+            let response = self.client.guard(Capability::Directives, self.client.get_directive(bank_id, directive_id, None)).await?;
             Ok(response.into_inner())
         })
     }
@@ -620,7 +668,7 @@ impl ApiClient {
         _verbose: bool,
     ) -> Result<types::DirectiveResponse> {
         self.runtime.block_on(async {
-            let response = self.client.create_directive(bank_id, None, request).await?;
+            let response = self.client.guard(Capability::Directives, self.client.create_directive(bank_id, None, request)).await?;
             Ok(response.into_inner())
         })
     }
@@ -633,14 +681,14 @@ impl ApiClient {
         _verbose: bool,
     ) -> Result<types::DirectiveResponse> {
         self.runtime.block_on(async {
-            let response = self.client.update_directive(bank_id, directive_id, None, request).await?;
+            let response = self.client.guard(Capability::Directives, self.client.update_directive(bank_id, directive_id, None, request)).await?;
             Ok(response.into_inner())
         })
     }
 
     pub fn delete_directive(&self, bank_id: &str, directive_id: &str, _verbose: bool) -> Result<serde_json::Value> {
         self.runtime.block_on(async {
-            let response = self.client.delete_directive(bank_id, directive_id, None).await?;
+            let response = self.client.guard(Capability::Directives, self.client.delete_directive(bank_id, directive_id, None)).await?;
             Ok(response.into_inner())
         })
     }
@@ -1,8 +1,26 @@
 use anyhow::{anyhow, Result};
-use crate::api::ApiClient;
+use crate::api::{types::BankListItem, ApiClient};
+use crate::markdown;
 use crate::output::{self, OutputFormat};
+use crate::table::{self, Table};
 use crate::ui;
 
+/// Rows of `bank list`
+fn banks_table(banks: &[BankListItem]) -> Table {
+    let mut table = Table::new(&["bank_id", "name", "mission", "created_at", "updated_at"])
+        .with_default_columns(&["bank_id", "name", "mission"]);
+    for bank in banks {
+        table.push(vec![
+            bank.bank_id.clone(),
+            bank.name.clone().unwrap_or_default(),
+            bank.mission.clone().unwrap_or_default(),
+            bank.created_at.clone().unwrap_or_default(),
+            bank.updated_at.clone().unwrap_or_default(),
+        ]);
+    }
+    table
+}
+
 pub fn list(client: &ApiClient, verbose: bool, output_format: OutputFormat) -> Result<()> {
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching banks..."))
@@ -23,12 +41,10 @@ pub fn list(client: &ApiClient, verbose: bool, output_format: OutputFormat) -> R
                     ui::print_warning("No banks found");
                 } else {
                     ui::print_info(&format!("Found {} bank(s)", banks_list.len()));
-                    for bank in &banks_list {
-                        println!("  - {}", bank.bank_id);
-                    }
+                    table::print(&banks_table(&banks_list))?;
                 }
             } else {
-                output::print_output(&banks_list, output_format)?;
+                output::print_list(&banks_list, output_format, || banks_table(&banks_list))?;
             }
             Ok(())
         }
@@ -136,6 +152,8 @@ pub fn stats(client: &ApiClient, bank_id: &str, verbose: bool, output_format: Ou
                         println!("  {} {}", ui::dim("failed:"), stats.failed_operations);
                     }
                 }
+            } else if output_format == OutputFormat::Markdown {
+                print!("{}", markdown::bank_stats(&stats));
             } else {
                 output::print_output(&stats, output_format)?;
             }
@@ -434,7 +452,10 @@ pub fn graph(
 
                 println!("{}", ui::dim("Use JSON output for full graph data: -o json"));
             } else {
-                output::print_output(&result, output_format)?;
+                // CSV/TSV export the table view of the graph (one row per memory unit)
+                output::print_list(&result, output_format, || {
+                    Table::from_objects(&["id", "text", "context", "date", "entities"], &result.table_rows)
+                })?;
             }
             Ok(())
         }
@@ -550,7 +571,7 @@ pub fn consolidate(
                 std::thread::sleep(std::time::Duration::from_secs(poll_interval));
                 let elapsed = start.elapsed().as_secs();
 
-                let ops_result = client.list_operations(bank_id, verbose);
+                let ops_result = client.list_operations(bank_id, None, None, verbose);
                 match ops_result {
                     Ok(ops) => {
                         // Find the operation by ID
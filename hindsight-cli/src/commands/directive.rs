@@ -3,11 +3,31 @@
 use anyhow::Result;
 
 use crate::api::ApiClient;
-use crate::output::{self, OutputFormat};
+use crate::output::{self, OutputFormat, Page};
+use crate::table::{self, Table};
 use crate::ui;
 
 use hindsight_client::types;
 
+/// Rows of `directive list`
+fn directives_table(directives: &[types::DirectiveResponse]) -> Table {
+    let mut table = Table::new(&["id", "name", "active", "priority", "content", "tags", "created_at", "updated_at"])
+        .with_default_columns(&["id", "name", "active", "content"]);
+    for directive in directives {
+        table.push(vec![
+            directive.id.clone(),
+            directive.name.clone(),
+            directive.is_active.to_string(),
+            directive.priority.to_string(),
+            directive.content.clone(),
+            directive.tags.join(", "),
+            directive.created_at.clone().unwrap_or_default(),
+            directive.updated_at.clone().unwrap_or_default(),
+        ]);
+    }
+    table
+}
+
 /// List directives for a bank
 pub fn list(
     client: &ApiClient,
@@ -15,13 +35,22 @@ pub fn list(
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
+    // NDJSON streams every directives, a page at a time
+    if output_format == OutputFormat::Ndjson {
+        return output::stream_pages(std::io::stdout().lock(), 0, output::STREAM_PAGE_SIZE, |offset, limit| {
+            let page = client.list_directives(bank_id, Some(limit), Some(offset), verbose)?;
+            // No total in the response; a short page ends the stream
+            Ok(Page { items: page.items, total: i64::MAX })
+        });
+    }
+
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching directives..."))
     } else {
         None
     };
 
-    let response = client.list_directives(bank_id, verbose);
+    let response = client.list_directives(bank_id, None, None, verbose);
 
     if let Some(mut sp) = spinner {
         sp.finish();
@@ -35,29 +64,10 @@ pub fn list(
                 if result.items.is_empty() {
                     println!("  {}", ui::dim("No directives found."));
                 } else {
-                    for directive in &result.items {
-                        let status = if directive.is_active {
-                            ui::gradient_start("active")
-                        } else {
-                            ui::dim("inactive")
-                        };
-                        println!(
-                            "  {} {} [{}]",
-                            ui::gradient_start(&directive.id),
-                            directive.name,
-                            status
-                        );
-
-                        // Show content preview
-                        let preview: String = directive.content.chars().take(80).collect();
-                        let ellipsis = if directive.content.len() > 80 { "..." } else { "" };
-                        println!("    {}{}", ui::dim(&preview), ellipsis);
-
-                        println!();
-                    }
+                    table::print(&directives_table(&result.items))?;
                 }
             } else {
-                output::print_output(&result, output_format)?;
+                output::print_list(&result, output_format, || directives_table(&result.items))?;
             }
             Ok(())
         }
@@ -232,7 +242,8 @@ pub fn delete(
             if output_format == OutputFormat::Pretty {
                 ui::print_success(&format!("Directive '{}' deleted successfully", directive_id));
             } else {
-                println!("{{\"success\": true}}");
+                let result = types::DeleteResponse { success: true, message: None, deleted_count: None };
+                output::print_output(&result, output_format)?;
             }
             Ok(())
         }
@@ -0,0 +1,605 @@
+//! `hindsight doctor`: check everything between the CLI and a working API.
+//!
+//! Checks run in order from local configuration out to a real recall, and a
+//! check whose prerequisite failed is skipped rather than reported as a
+//! second failure. Each failure or warning carries the fix to try.
+
+use anyhow::Result;
+use colored::Colorize;
+use hindsight_client::{parse_version, Compatibility};
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use crate::api::{types, ApiClient, RecallRequest};
+use crate::commands::health::HealthResponse;
+use crate::config::Config;
+use crate::output::{self, OutputFormat};
+use crate::ui;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// A recall slower than this is reported as a warning
+const SLOW_RECALL: Duration = Duration::from_secs(3);
+/// How often `--wait-until-healthy` polls `/health`
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Checks in the order they run. "API client" is left out: it only appears
+/// when the client cannot be built.
+const CHECKS: &[&str] = &[
+    "Configuration",
+    "Config file",
+    "DNS",
+    "TCP",
+    "TLS",
+    "Health",
+    "Version",
+    "Compatibility",
+    "Authentication",
+    "Bank config API",
+    "Recall latency",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Warn,
+    Fail,
+    /// Not run because an earlier check failed or it does not apply
+    Skip,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    /// What to try when the check did not pass
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remedy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+/// `doctor` output for JSON and YAML
+#[derive(Debug, Serialize, JsonSchema)]
+pub(crate) struct DoctorReport {
+    /// No check failed (warnings allowed)
+    pub ok: bool,
+    pub checks: Vec<Check>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, detail: impl Into<String>) -> Self {
+        Check { name, status, detail: detail.into(), remedy: None, duration_ms: None }
+    }
+
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, Status::Pass, detail)
+    }
+
+    fn skip(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, Status::Skip, detail)
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, remedy: impl Into<String>) -> Self {
+        Self::new(name, Status::Fail, detail).remedy(remedy)
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, remedy: impl Into<String>) -> Self {
+        Self::new(name, Status::Warn, detail).remedy(remedy)
+    }
+
+    fn remedy(mut self, remedy: impl Into<String>) -> Self {
+        self.remedy = Some(remedy.into());
+        self
+    }
+
+    fn timed(mut self, started: Instant) -> Self {
+        self.duration_ms = Some(started.elapsed().as_millis() as u64);
+        self
+    }
+}
+
+/// Compare the server's API version with the spec the CLI was built from
+fn compatibility(cli: &str, server: &str) -> Check {
+    const NAME: &str = "Compatibility";
+    let detail = format!("API {}, CLI built for {}", server, cli);
+    match hindsight_client::compatibility(cli, server) {
+        Compatibility::Exact => Check::pass(NAME, detail),
+        Compatibility::Compatible => {
+            Check::warn(NAME, detail, "Commands added since the older version may be unavailable")
+        }
+        Compatibility::Incompatible => {
+            let remedy = if parse_version(server) > parse_version(cli) {
+                "Upgrade the CLI to match the server"
+            } else {
+                "Upgrade the API server, or use a CLI built for its version"
+            };
+            Check::fail(NAME, detail, remedy)
+        }
+        Compatibility::Unknown => Check::warn(
+            NAME,
+            format!("cannot compare API {} with CLI spec {}", server, cli),
+            "Check that the server is a Hindsight API",
+        ),
+    }
+}
+
+/// Why a config file holding an API key is too open, from its Unix mode
+fn permission_problem(mode: u32, has_api_key: bool) -> Option<String> {
+    (has_api_key && mode & 0o077 != 0).then(|| format!("holds an API key but has mode {:o}", mode & 0o777))
+}
+
+/// Whether an error is the server refusing our credentials
+fn is_auth_error(error: &anyhow::Error) -> bool {
+    let text = error.to_string();
+    text.contains("401") || text.contains("403")
+}
+
+struct Doctor {
+    checks: Vec<Check>,
+    /// Features reported by `/version`
+    features: Option<types::FeaturesInfo>,
+    verbose: bool,
+}
+
+impl Doctor {
+    fn add(&mut self, check: Check) -> Status {
+        let status = check.status;
+        self.checks.push(check);
+        status
+    }
+
+    /// Report every check that did not run as skipped, blaming the last failure
+    fn skip_rest(&mut self) {
+        let Some(failed) = self.checks.iter().rev().find(|c| c.status == Status::Fail).map(|c| c.name) else {
+            return;
+        };
+        for &name in CHECKS {
+            if !self.checks.iter().any(|c| c.name == name) {
+                self.add(Check::skip(name, format!("{} failed", failed)));
+            }
+        }
+    }
+
+    fn config(&mut self) -> Option<Config> {
+        let config = match Config::load() {
+            Ok(config) => {
+                self.add(Check::pass("Configuration", format!("{} (from {})", config.api_url, config.source)));
+                Some(config)
+            }
+            Err(e) => {
+                self.add(Check::fail(
+                    "Configuration",
+                    format!("{}", e),
+                    "Run 'hindsight configure' or set HINDSIGHT_API_URL",
+                ));
+                None
+            }
+        };
+
+        let Some(path) = Config::config_file_path() else {
+            self.add(Check::skip("Config file", "no home directory"));
+            return config;
+        };
+        if !path.exists() {
+            self.add(Check::pass("Config file", format!("{} not present, using environment or defaults", path.display())));
+            return config;
+        }
+        let check = match fs::read_to_string(&path) {
+            Err(e) => Check::fail(
+                "Config file",
+                format!("{}: {}", path.display(), e),
+                format!("Make {} readable, or run 'hindsight configure' to rewrite it", path.display()),
+            ),
+            Ok(content) => {
+                let has_api_key = content.lines().any(|line| line.trim().starts_with("api_key"));
+                match file_mode(&path).and_then(|mode| permission_problem(mode, has_api_key)) {
+                    Some(problem) => Check::warn(
+                        "Config file",
+                        format!("{} {}", path.display(), problem),
+                        format!("chmod 600 {}", path.display()),
+                    ),
+                    None => Check::pass("Config file", format!("{} is readable", path.display())),
+                }
+            }
+        };
+        self.add(check);
+        config
+    }
+
+    /// DNS, then TCP, then TLS for `https` URLs; true if all passed
+    fn network(&mut self, client: &ApiClient, api_url: &str) -> bool {
+        let url = match reqwest::Url::parse(api_url) {
+            Ok(url) => url,
+            Err(e) => {
+                self.add(Check::fail("DNS", format!("invalid URL {}: {}", api_url, e), "Fix the API URL"));
+                return false;
+            }
+        };
+        let host = url.host_str().unwrap_or_default().to_string();
+        let port = url.port_or_known_default().unwrap_or(80);
+
+        let started = Instant::now();
+        let addresses: Vec<SocketAddr> = match (host.as_str(), port).to_socket_addrs() {
+            Ok(addresses) => addresses.collect(),
+            Err(e) => {
+                self.add(
+                    Check::fail("DNS", format!("cannot resolve {}: {}", host, e), "Check the host name in the API URL and your DNS")
+                        .timed(started),
+                );
+                return false;
+            }
+        };
+        let resolved: Vec<String> = addresses.iter().map(|a| a.ip().to_string()).collect();
+        self.add(Check::pass("DNS", format!("{} → {}", host, resolved.join(", "))).timed(started));
+
+        let started = Instant::now();
+        let connected = addresses.iter().find_map(|a| TcpStream::connect_timeout(a, CONNECT_TIMEOUT).ok().map(|_| *a));
+        let Some(address) = connected else {
+            self.add(
+                Check::fail(
+                    "TCP",
+                    format!("cannot connect to port {} on {}", port, resolved.join(", ")),
+                    "Start the API server, or check the port and any firewall in between",
+                )
+                .timed(started),
+            );
+            return false;
+        };
+        self.add(Check::pass("TCP", format!("connected to {}", address)).timed(started));
+
+        if url.scheme() != "https" {
+            self.add(Check::skip("TLS", "plain http"));
+            return true;
+        }
+        let started = Instant::now();
+        match client.probe("/", self.verbose) {
+            Ok(_) => self.add(Check::pass("TLS", "handshake and certificate OK").timed(started)) == Status::Pass,
+            Err(e) => {
+                self.add(
+                    Check::fail("TLS", format!("{:#}", e), "Check the server certificate, or use http:// if the server has no TLS")
+                        .timed(started),
+                );
+                false
+            }
+        }
+    }
+
+    /// `/health`, `/version` and compatibility; true if the API is healthy
+    fn api(&mut self, client: &ApiClient) -> bool {
+        let started = Instant::now();
+        let healthy = match client.health(self.verbose).map(serde_json::from_value::<HealthResponse>) {
+            Ok(Ok(health)) if health.status == "healthy" => {
+                let database = health.database.map(|d| format!(", database {}", d)).unwrap_or_default();
+                self.add(Check::pass("Health", format!("healthy{}", database)).timed(started));
+                true
+            }
+            Ok(Ok(health)) => {
+                let database = health.database.map(|d| format!(", database {}", d)).unwrap_or_default();
+                self.add(
+                    Check::fail("Health", format!("{}{}", health.status, database), "Check the API server logs and its database")
+                        .timed(started),
+                );
+                false
+            }
+            Ok(Err(e)) => {
+                self.add(Check::fail("Health", format!("unexpected response: {}", e), "Check that the URL points to a Hindsight API"));
+                false
+            }
+            Err(e) => {
+                self.add(Check::fail("Health", format!("{}", e), "Check the API server logs and its database").timed(started));
+                false
+            }
+        };
+
+        let started = Instant::now();
+        match client.get_version(self.verbose) {
+            Ok(version) => {
+                let features = &version.features;
+                let enabled: Vec<&str> = [
+                    ("bank config API", features.bank_config_api),
+                    ("file upload API", features.file_upload_api),
+                    ("mcp", features.mcp),
+                    ("observations", features.observations),
+                    ("worker", features.worker),
+                ]
+                .into_iter()
+                .filter_map(|(name, on)| on.then_some(name))
+                .collect();
+                let features = if enabled.is_empty() { "none".to_string() } else { enabled.join(", ") };
+                self.add(Check::pass("Version", format!("{}, features: {}", version.api_version, features)).timed(started));
+                self.add(compatibility(ApiClient::spec_version(), &version.api_version));
+                self.features = Some(version.features);
+            }
+            Err(e) => {
+                self.add(Check::fail("Version", format!("{}", e), "Upgrade the API server; /version is missing").timed(started));
+                self.add(Check::skip("Compatibility", "version unknown"));
+            }
+        }
+        healthy
+    }
+
+    /// Authentication, then the bank-level checks against `bank_id` or the first bank
+    fn bank(&mut self, client: &ApiClient, config: &Config, bank_id: Option<&str>) {
+        let key = if config.api_key.is_some() { "API key accepted" } else { "no API key configured; none required" };
+        let started = Instant::now();
+        let banks = match client.list_agents(self.verbose) {
+            Ok(banks) => {
+                self.add(Check::pass("Authentication", format!("{}, {} bank(s) visible", key, banks.len())).timed(started));
+                banks
+            }
+            Err(e) => {
+                let remedy = if is_auth_error(&e) {
+                    "Set HINDSIGHT_API_KEY or run 'hindsight configure --api-key <key>'"
+                } else {
+                    "Check the API server logs"
+                };
+                self.add(Check::fail("Authentication", format!("{}", e), remedy).timed(started));
+                self.add(Check::skip("Bank config API", "authentication failed"));
+                self.add(Check::skip("Recall latency", "authentication failed"));
+                return;
+            }
+        };
+
+        let Some(bank_id) = bank_id.map(str::to_string).or_else(|| banks.first().map(|b| b.bank_id.clone())) else {
+            self.add(Check::skip("Bank config API", "no banks; pass --bank to pick one"));
+            self.add(Check::skip("Recall latency", "no banks; pass --bank to pick one"));
+            return;
+        };
+
+        // The 404 of a disabled config API has no detail once parsed, so trust /version
+        if self.features.as_ref().is_some_and(|f| !f.bank_config_api) {
+            self.add(Check::warn(
+                "Bank config API",
+                "disabled on the server; 'bank config' and 'bank set-config' will fail",
+                "Set HINDSIGHT_API_ENABLE_BANK_CONFIG_API=true on the API server",
+            ));
+        } else {
+            let started = Instant::now();
+            let check = match client.get_bank_config(&bank_id, self.verbose) {
+                Ok(_) => Check::pass("Bank config API", format!("enabled (bank {})", bank_id)),
+                Err(e) => Check::fail("Bank config API", format!("bank {}: {}", bank_id, e), "Check the bank ID and the API server logs"),
+            };
+            self.add(check.timed(started));
+        }
+
+        let request = RecallRequest {
+            query: "hindsight doctor".to_string(),
+            types: None,
+            budget: Some(types::Budget::Low),
+            max_tokens: 256,
+            trace: false,
+            query_timestamp: None,
+            include: None,
+            tags: None,
+            tags_match: types::TagsMatch::Any,
+        };
+        let started = Instant::now();
+        let check = match client.recall(&bank_id, &request, self.verbose) {
+            Ok(response) => {
+                let elapsed = started.elapsed();
+                let detail = format!("{} ms on bank {}, {} result(s)", elapsed.as_millis(), bank_id, response.results.len());
+                if elapsed > SLOW_RECALL {
+                    Check::warn("Recall latency", detail, "Check the API server's database and embedding model load")
+                } else {
+                    Check::pass("Recall latency", detail)
+                }
+            }
+            Err(e) => Check::fail("Recall latency", format!("bank {}: {}", bank_id, e), "Check the API server logs"),
+        };
+        self.add(check.timed(started));
+    }
+}
+
+#[cfg(unix)]
+fn file_mode(path: &std::path::Path) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).ok().map(|m| m.permissions().mode())
+}
+
+#[cfg(not(unix))]
+fn file_mode(_path: &std::path::Path) -> Option<u32> {
+    None
+}
+
+fn status_icon(status: Status) -> String {
+    match status {
+        Status::Pass => ui::gradient_start("✓"),
+        Status::Warn => "!".bright_yellow().bold().to_string(),
+        Status::Fail => "✗".bright_red().bold().to_string(),
+        Status::Skip => ui::dim("-"),
+    }
+}
+
+/// "8 passed, 1 warning, 1 failed, 2 skipped", leaving out zero counts
+fn summary(checks: &[Check]) -> String {
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let parts = [
+        (count(Status::Pass), "passed", "passed"),
+        (count(Status::Warn), "warning", "warnings"),
+        (count(Status::Fail), "failed", "failed"),
+        (count(Status::Skip), "skipped", "skipped"),
+    ];
+    let parts: Vec<String> = parts
+        .iter()
+        .filter(|(n, _, _)| *n > 0)
+        .map(|(n, one, many)| format!("{} {}", n, if *n == 1 { one } else { many }))
+        .collect();
+    parts.join(", ")
+}
+
+fn print_checklist(checks: &[Check]) {
+    ui::print_section_header("Hindsight Doctor");
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    for check in checks {
+        let detail = if check.status == Status::Skip { ui::dim(&check.detail) } else { check.detail.clone() };
+        println!("  {} {:<width$}  {}", status_icon(check.status), check.name, detail, width = width);
+        if let Some(remedy) = &check.remedy {
+            println!("    {:<width$}  {}", "", ui::dim(&format!("→ {}", remedy)), width = width);
+        }
+    }
+    println!();
+    println!("  {}", summary(checks));
+    println!();
+}
+
+/// Run every check and print the checklist; exits with status 1 if any failed
+pub fn run(bank_id: Option<&str>, verbose: bool, output_format: OutputFormat) -> Result<()> {
+    let mut doctor = Doctor { checks: Vec::new(), features: None, verbose };
+    let spinner = if output_format == OutputFormat::Pretty {
+        Some(ui::create_spinner("Running checks..."))
+    } else {
+        None
+    };
+
+    if let Some(config) = doctor.config() {
+        match ApiClient::new(config.api_url.clone(), config.api_key.clone()) {
+            Err(e) => {
+                doctor.add(Check::fail("API client", format!("{}", e), "Check the API key for invalid characters"));
+            }
+            Ok(client) => {
+                if doctor.network(&client, &config.api_url) && doctor.api(&client) {
+                    doctor.bank(&client, &config, bank_id);
+                }
+            }
+        }
+    }
+    doctor.skip_rest();
+
+    if let Some(mut sp) = spinner {
+        sp.finish();
+    }
+
+    let ok = !doctor.checks.iter().any(|c| c.status == Status::Fail);
+    if output_format == OutputFormat::Pretty {
+        print_checklist(&doctor.checks);
+    } else {
+        output::print_output(&DoctorReport { ok, checks: doctor.checks }, output_format)?;
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Poll `/health` until the API reports healthy; exits with status 1 after `timeout`
+pub fn wait_until_healthy(timeout: Duration, verbose: bool, output_format: OutputFormat) -> Result<()> {
+    let (check, message) = match Config::load() {
+        Err(e) => {
+            let check = Check::fail("Configuration", format!("{}", e), "Run 'hindsight configure' or set HINDSIGHT_API_URL");
+            let message = format!("Configuration error: {}", check.detail);
+            (check, message)
+        }
+        Ok(config) => match ApiClient::new(config.api_url.clone(), config.api_key.clone()) {
+            Err(e) => {
+                let check = Check::fail("API client", format!("{}", e), "Check the API key for invalid characters");
+                let message = format!("Cannot create the API client: {}", check.detail);
+                (check, message)
+            }
+            Ok(client) => {
+                let check = poll_health(&client, &config.api_url, timeout, verbose, output_format);
+                let message = format!("{} is {}", config.api_url, check.detail);
+                (check, message)
+            }
+        },
+    };
+
+    let ok = check.status == Status::Pass;
+    if output_format != OutputFormat::Pretty {
+        output::print_output(&DoctorReport { ok, checks: vec![check] }, output_format)?;
+    } else if ok {
+        ui::print_success(&message);
+    } else {
+        ui::print_error(&message);
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// The Health check once `/health` reports healthy, or failed after `timeout`
+fn poll_health(client: &ApiClient, api_url: &str, timeout: Duration, verbose: bool, output_format: OutputFormat) -> Check {
+    let spinner = if output_format == OutputFormat::Pretty {
+        Some(ui::create_spinner(&format!("Waiting for {} to become healthy...", api_url)))
+    } else {
+        None
+    };
+
+    let started = Instant::now();
+    let check = loop {
+        let problem = match client.health(verbose).map(serde_json::from_value::<HealthResponse>) {
+            Ok(Ok(health)) if health.status == "healthy" => {
+                let waited = format!("healthy after {:.1}s", started.elapsed().as_secs_f64());
+                break Check::pass("Health", waited).timed(started);
+            }
+            Ok(Ok(health)) => format!("status {}", health.status),
+            Ok(Err(e)) => format!("unexpected response: {}", e),
+            Err(e) => format!("{}", e),
+        };
+        if started.elapsed() + POLL_INTERVAL > timeout {
+            let detail = format!("not healthy after {}s: {}", timeout.as_secs(), problem);
+            break Check::fail("Health", detail, "Check the API server logs, or wait longer with --timeout").timed(started);
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    };
+
+    if let Some(mut sp) = spinner {
+        sp.finish();
+    }
+    check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_compatibility() {
+        assert_eq!(compatibility("0.4.13", "0.4.2").status, Status::Pass);
+        assert_eq!(compatibility("0.4.13", "0.5.0").status, Status::Fail);
+        assert_eq!(compatibility("0.4.13", "0.5.0").remedy.as_deref(), Some("Upgrade the CLI to match the server"));
+        assert_eq!(compatibility("1.2.0", "1.4.1").status, Status::Warn);
+        assert_eq!(compatibility("2.0.0", "1.9.0").status, Status::Fail);
+        assert_eq!(compatibility("1.0.0", "unknown").status, Status::Warn);
+    }
+
+    #[test]
+    fn test_permissions_and_summary() {
+        assert_eq!(permission_problem(0o100644, true).as_deref(), Some("holds an API key but has mode 644"));
+        assert_eq!(permission_problem(0o100600, true), None);
+        assert_eq!(permission_problem(0o100644, false), None);
+
+        let checks = vec![
+            Check::pass("A", ""),
+            Check::pass("B", ""),
+            Check::warn("C", "", "fix"),
+            Check::skip("D", ""),
+        ];
+        assert_eq!(summary(&checks), "2 passed, 1 warning, 1 skipped");
+        assert!(is_auth_error(&anyhow::anyhow!("Error Response: status: 401 Unauthorized")));
+    }
+
+    #[test]
+    fn test_checks_after_a_failure_are_skipped() {
+        // A port nothing listens on
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let api_url = format!("http://127.0.0.1:{}", port);
+        let client = ApiClient::new(api_url.clone(), None).unwrap();
+
+        let checks = vec![Check::pass("Configuration", ""), Check::pass("Config file", "")];
+        let mut doctor = Doctor { checks, features: None, verbose: false };
+        assert!(!doctor.network(&client, &api_url));
+        doctor.skip_rest();
+
+        let rows: Vec<(&str, Status)> = doctor.checks.iter().map(|c| (c.name, c.status)).collect();
+        assert_eq!(&rows[2..4], &[("DNS", Status::Pass), ("TCP", Status::Fail)]);
+        let skipped: Vec<&str> = doctor.checks[4..].iter().map(|c| c.name).collect();
+        assert_eq!(skipped, vec!["TLS", "Health", "Version", "Compatibility", "Authentication", "Bank config API", "Recall latency"]);
+        assert!(doctor.checks[4..].iter().all(|c| c.status == Status::Skip && c.detail == "TCP failed"));
+        assert_eq!(summary(&doctor.checks), "3 passed, 1 failed, 7 skipped");
+    }
+}
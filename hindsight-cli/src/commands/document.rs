@@ -2,9 +2,16 @@ use anyhow::Result;
 use chrono::{Duration as ChronoDuration, NaiveDate, Utc};
 use std::collections::BTreeMap;
 use crate::api::ApiClient;
-use crate::output::{self, OutputFormat};
+use crate::output::{self, OutputFormat, Page};
+use crate::table::{self, Table};
 use crate::ui;
 
+/// Rows of `document list`
+fn documents_table<'a>(documents: impl IntoIterator<Item = &'a serde_json::Map<String, serde_json::Value>>) -> Table {
+    Table::from_objects(&["id", "created_at", "updated_at", "text_length", "memory_unit_count", "tags"], documents)
+        .with_default_columns(&["id", "created_at", "updated_at", "text_length", "memory_unit_count"])
+}
+
 pub fn list(
     client: &ApiClient,
     agent_id: &str,
@@ -20,6 +27,14 @@ pub fn list(
         return list_with_date(client, agent_id, date.as_deref(), verbose, output_format);
     }
 
+    // NDJSON streams every page from `offset`, `limit` items at a time
+    if output_format == OutputFormat::Ndjson {
+        return output::stream_pages(std::io::stdout().lock(), offset as i64, limit as i64, |offset, limit| {
+            let page = client.list_documents(agent_id, query.as_deref(), Some(limit as i32), Some(offset as i32), verbose)?;
+            Ok(Page { items: page.items, total: page.total })
+        });
+    }
+
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching documents..."))
     } else {
@@ -36,21 +51,12 @@ pub fn list(
         Ok(docs_response) => {
             if output_format == OutputFormat::Pretty {
                 ui::print_info(&format!("Documents for bank '{}' (total: {})", agent_id, docs_response.total));
-                for doc in &docs_response.items {
-                    let id = doc.get("id").and_then(|v| v.as_str()).unwrap_or("unknown");
-                    let created = doc.get("created_at").and_then(|v| v.as_str()).unwrap_or("unknown");
-                    let updated = doc.get("updated_at").and_then(|v| v.as_str()).unwrap_or("unknown");
-                    let text_len = doc.get("text_length").and_then(|v| v.as_i64()).unwrap_or(0);
-                    let mem_count = doc.get("memory_unit_count").and_then(|v| v.as_i64()).unwrap_or(0);
-
-                    println!("\n  Document ID: {}", id);
-                    println!("    Created: {}", created);
-                    println!("    Updated: {}", updated);
-                    println!("    Text Length: {}", text_len);
-                    println!("    Memory Units: {}", mem_count);
+                if !docs_response.items.is_empty() {
+                    println!();
+                    table::print(&documents_table(&docs_response.items))?;
                 }
             } else {
-                output::print_output(&docs_response, output_format)?;
+                output::print_list(&docs_response, output_format, || documents_table(&docs_response.items))?;
             }
             Ok(())
         }
@@ -121,20 +127,16 @@ fn list_with_date(
         ));
         println!();
 
-        // Show documents grouped by date (reverse order - newest first)
-        for (date_str, docs) in by_date.iter().rev() {
-            println!("  {} ({} documents)", date_str, docs.len());
-            for doc in docs {
-                let id = doc.get("id").and_then(|v| v.as_str()).unwrap_or("unknown");
-                let mem_count = doc.get("memory_unit_count").and_then(|v| v.as_i64()).unwrap_or(0);
-                println!("    - {} ({} memories)", id, mem_count);
-            }
-            println!();
+        // Newest dates first
+        if filtered_count > 0 {
+            table::print(&documents_table(by_date.values().rev().flatten().filter_map(|doc| doc.as_object())))?;
         }
     } else {
         // JSON/YAML output - convert to a list structure
         let output: Vec<serde_json::Value> = by_date.values().flatten().cloned().collect();
-        output::print_output(&output, output_format)?;
+        output::print_list(&output, output_format, || {
+            documents_table(output.iter().filter_map(|doc| doc.as_object()))
+        })?;
     }
 
     Ok(())
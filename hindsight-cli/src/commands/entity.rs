@@ -1,8 +1,26 @@
 use anyhow::Result;
 use crate::api::ApiClient;
-use crate::output::{self, OutputFormat};
+use crate::api::types::EntityListItem;
+use crate::markdown;
+use crate::output::{self, OutputFormat, Page};
+use crate::table::{self, Table};
 use crate::ui;
 
+/// Rows of `entity list`
+fn entities_table(entities: &[EntityListItem]) -> Table {
+    let mut table = Table::new(&["id", "canonical_name", "mention_count", "first_seen", "last_seen"]);
+    for entity in entities {
+        table.push(vec![
+            entity.id.clone(),
+            entity.canonical_name.clone(),
+            entity.mention_count.to_string(),
+            entity.first_seen.clone().unwrap_or_default(),
+            entity.last_seen.clone().unwrap_or_default(),
+        ]);
+    }
+    table
+}
+
 pub fn list(
     client: &ApiClient,
     bank_id: &str,
@@ -10,6 +28,14 @@ pub fn list(
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
+    // NDJSON streams every entity, `limit` at a time
+    if output_format == OutputFormat::Ndjson {
+        return output::stream_pages(std::io::stdout().lock(), 0, limit, |offset, limit| {
+            let page = client.list_entities(bank_id, Some(limit), Some(offset), verbose)?;
+            Ok(Page { items: page.items, total: page.total })
+        });
+    }
+
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching entities..."))
     } else {
@@ -31,21 +57,9 @@ pub fn list(
         }
 
         println!("Total entities: {}\n", response.items.len());
-
-        for entity in &response.items {
-            println!("ID: {}", entity.id);
-            println!("  Name: {}", entity.canonical_name);
-            println!("  Mentions: {}", entity.mention_count);
-            if let Some(first_seen) = &entity.first_seen {
-                println!("  First seen: {}", first_seen);
-            }
-            if let Some(last_seen) = &entity.last_seen {
-                println!("  Last seen: {}", last_seen);
-            }
-            println!();
-        }
+        table::print(&entities_table(&response.items))?;
     } else {
-        output::print_output(&response, output_format)?;
+        output::print_list(&response, output_format, || entities_table(&response.items))?;
     }
 
     Ok(())
@@ -85,6 +99,8 @@ pub fn get(
         }
 
         println!();
+    } else if output_format == OutputFormat::Markdown {
+        print!("{}", markdown::entity(&response));
     } else {
         output::print_output(&response, output_format)?;
     }
@@ -0,0 +1,86 @@
+//! The API calls the explorer makes, behind a trait.
+//!
+//! The App only talks to the server through [`ExploreApi`], so it can be
+//! driven against a canned backend in tests. [`ApiClient`] implements it by
+//! forwarding to its own methods.
+
+use anyhow::Result;
+use hindsight_client::types::{
+    BankListItem, ChunkResponse, ConsolidationResponse, DeleteResponse, DocumentResponse, EntityListResponse,
+    ListDocumentsResponse, ListMemoryUnitsResponse, RecallRequest, RecallResponse, ReflectRequest, ReflectResponse,
+    RetainRequest,
+};
+
+use crate::api::{AgentStats, ApiClient, MemoryPutResult, OperationsResponse};
+
+/// Server operations used by the explorer. Calls block; the App runs them on
+/// loader threads, hence `Send + Sync`.
+pub trait ExploreApi: Send + Sync {
+    fn list_banks(&self) -> Result<Vec<BankListItem>>;
+    fn get_stats(&self, bank_id: &str) -> Result<AgentStats>;
+    fn list_memories(&self, bank_id: &str, limit: i64, offset: i64) -> Result<ListMemoryUnitsResponse>;
+    fn list_entities(&self, bank_id: &str, limit: i64) -> Result<EntityListResponse>;
+    fn list_documents(&self, bank_id: &str, limit: i32, offset: i32) -> Result<ListDocumentsResponse>;
+    fn list_operations(&self, bank_id: &str) -> Result<OperationsResponse>;
+    fn get_document(&self, bank_id: &str, document_id: &str) -> Result<DocumentResponse>;
+    fn get_chunk(&self, chunk_id: &str) -> Result<ChunkResponse>;
+    fn delete_document(&self, bank_id: &str, document_id: &str) -> Result<DeleteResponse>;
+    fn recall(&self, bank_id: &str, request: &RecallRequest) -> Result<RecallResponse>;
+    fn reflect(&self, bank_id: &str, request: &ReflectRequest) -> Result<ReflectResponse>;
+    fn retain(&self, bank_id: &str, request: &RetainRequest, async_mode: bool) -> Result<MemoryPutResult>;
+    fn trigger_consolidation(&self, bank_id: &str) -> Result<ConsolidationResponse>;
+}
+
+impl ExploreApi for ApiClient {
+    fn list_banks(&self) -> Result<Vec<BankListItem>> {
+        self.list_agents(false)
+    }
+
+    fn get_stats(&self, bank_id: &str) -> Result<AgentStats> {
+        ApiClient::get_stats(self, bank_id, false)
+    }
+
+    fn list_memories(&self, bank_id: &str, limit: i64, offset: i64) -> Result<ListMemoryUnitsResponse> {
+        ApiClient::list_memories(self, bank_id, None, None, Some(limit), Some(offset), false)
+    }
+
+    fn list_entities(&self, bank_id: &str, limit: i64) -> Result<EntityListResponse> {
+        ApiClient::list_entities(self, bank_id, Some(limit), None, false)
+    }
+
+    fn list_documents(&self, bank_id: &str, limit: i32, offset: i32) -> Result<ListDocumentsResponse> {
+        ApiClient::list_documents(self, bank_id, None, Some(limit), Some(offset), false)
+    }
+
+    fn list_operations(&self, bank_id: &str) -> Result<OperationsResponse> {
+        ApiClient::list_operations(self, bank_id, None, None, false)
+    }
+
+    fn get_document(&self, bank_id: &str, document_id: &str) -> Result<DocumentResponse> {
+        ApiClient::get_document(self, bank_id, document_id, false)
+    }
+
+    fn get_chunk(&self, chunk_id: &str) -> Result<ChunkResponse> {
+        ApiClient::get_chunk(self, chunk_id, false)
+    }
+
+    fn delete_document(&self, bank_id: &str, document_id: &str) -> Result<DeleteResponse> {
+        ApiClient::delete_document(self, bank_id, document_id, false)
+    }
+
+    fn recall(&self, bank_id: &str, request: &RecallRequest) -> Result<RecallResponse> {
+        ApiClient::recall(self, bank_id, request, false)
+    }
+
+    fn reflect(&self, bank_id: &str, request: &ReflectRequest) -> Result<ReflectResponse> {
+        ApiClient::reflect(self, bank_id, request, false)
+    }
+
+    fn retain(&self, bank_id: &str, request: &RetainRequest, async_mode: bool) -> Result<MemoryPutResult> {
+        ApiClient::retain(self, bank_id, request, async_mode, false)
+    }
+
+    fn trigger_consolidation(&self, bank_id: &str) -> Result<ConsolidationResponse> {
+        ApiClient::trigger_consolidation(self, bank_id, false)
+    }
+}
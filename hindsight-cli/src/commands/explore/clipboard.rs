@@ -0,0 +1,91 @@
+//! Copying from the explorer with OSC 52.
+//!
+//! The terminal sets the system clipboard from an escape sequence, so yanking
+//! works over SSH without any clipboard tool on the remote host. Inside tmux
+//! the sequence is wrapped in a DCS passthrough, which needs
+//! `set -g allow-passthrough on`.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::Value;
+
+/// Which part of the selected item to copy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum YankPart {
+    Id,
+    Text,
+    Json,
+}
+
+impl YankPart {
+    /// Key pressed after the yank key (`y` repeats the default: text)
+    pub fn from_key(c: char) -> Option<Self> {
+        match c {
+            'i' => Some(YankPart::Id),
+            't' | 'y' => Some(YankPart::Text),
+            'j' => Some(YankPart::Json),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            YankPart::Id => "ID",
+            YankPart::Text => "text",
+            YankPart::Json => "JSON",
+        }
+    }
+}
+
+/// The item a yank copies from
+pub struct Selection {
+    pub id: Option<String>,
+    pub text: String,
+    pub json: Value,
+}
+
+impl Selection {
+    pub fn part(&self, part: YankPart) -> Option<String> {
+        match part {
+            YankPart::Id => self.id.clone(),
+            YankPart::Text => Some(self.text.clone()).filter(|t| !t.is_empty()),
+            YankPart::Json => serde_json::to_string_pretty(&self.json).ok(),
+        }
+    }
+}
+
+/// Escape sequence that puts `text` on the system clipboard
+pub fn osc52(text: &str, in_tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+    if in_tmux {
+        // Escape characters inside a DCS passthrough are doubled
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+pub fn in_tmux() -> bool {
+    std::env::var_os("TMUX").is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_osc52() {
+        assert_eq!(osc52("hello", false), "\x1b]52;c;aGVsbG8=\x07");
+        assert_eq!(osc52("hello", true), "\x1bPtmux;\x1b\x1b]52;c;aGVsbG8=\x07\x1b\\");
+    }
+
+    #[test]
+    fn test_selection_parts() {
+        let selection = Selection { id: None, text: "Alice works at Google".to_string(), json: json!({"text": "Alice"}) };
+        assert_eq!(selection.part(YankPart::Id), None);
+        assert_eq!(selection.part(YankPart::Text).as_deref(), Some("Alice works at Google"));
+        assert_eq!(selection.part(YankPart::Json).as_deref(), Some("{\n  \"text\": \"Alice\"\n}"));
+        assert_eq!(YankPart::from_key('y'), Some(YankPart::Text));
+        assert_eq!(YankPart::from_key('x'), None);
+    }
+}
@@ -0,0 +1,426 @@
+//! Compose form for retaining memories from inside the explorer.
+//!
+//! A draft is edited either inline, one field at a time, or as a text file in
+//! `$VISUAL`/`$EDITOR`. The editor file has `key: value` header lines, a `---`
+//! separator, and the memory content below it:
+//!
+//! ```text
+//! context: team meeting
+//! tags: work, planning
+//! timestamp: 2024-05-01T09:30:00Z
+//! document_id: standup-2024-05-01
+//! async: no
+//! ---
+//! Alice will own the Q3 roadmap.
+//! ```
+
+use chrono::{DateTime, NaiveDate, Utc};
+use hindsight_client::types::{DocumentResponse, MemoryItem, RetainRequest};
+
+use crate::config;
+
+const SEPARATOR: &str = "---";
+
+/// Field of the inline form, in tab order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Content,
+    Context,
+    Tags,
+    Timestamp,
+    DocumentId,
+    Mode,
+}
+
+impl Field {
+    pub const ALL: [Field; 6] = [
+        Field::Content,
+        Field::Context,
+        Field::Tags,
+        Field::Timestamp,
+        Field::DocumentId,
+        Field::Mode,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Field::Content => "Content",
+            Field::Context => "Context",
+            Field::Tags => "Tags",
+            Field::Timestamp => "Timestamp",
+            Field::DocumentId => "Document ID",
+            Field::Mode => "Mode",
+        }
+    }
+
+    fn offset(&self, by: isize) -> Field {
+        let len = Field::ALL.len() as isize;
+        let index = Field::ALL.iter().position(|f| f == self).unwrap_or(0) as isize;
+        Field::ALL[(index + by).rem_euclid(len) as usize]
+    }
+}
+
+/// A memory being written. Fields are kept as typed and validated on submit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Draft {
+    pub content: String,
+    pub context: String,
+    /// Comma or space separated
+    pub tags: String,
+    /// RFC 3339 or `YYYY-MM-DD`; empty for none
+    pub timestamp: String,
+    /// Empty to generate one, like `memory retain`
+    pub document_id: String,
+    pub async_mode: bool,
+}
+
+impl Draft {
+    /// Prefill from a document loaded in the document view, so retaining it
+    /// again replaces the document (the API upserts by document ID)
+    pub fn from_document(doc: &DocumentResponse) -> Self {
+        Self {
+            content: doc.original_text.clone(),
+            tags: doc.tags.join(", "),
+            document_id: doc.id.clone(),
+            ..Self::default()
+        }
+    }
+
+    pub fn field(&self, field: Field) -> &str {
+        match field {
+            Field::Content => &self.content,
+            Field::Context => &self.context,
+            Field::Tags => &self.tags,
+            Field::Timestamp => &self.timestamp,
+            Field::DocumentId => &self.document_id,
+            Field::Mode => if self.async_mode { "async" } else { "sync" },
+        }
+    }
+
+    fn field_mut(&mut self, field: Field) -> Option<&mut String> {
+        match field {
+            Field::Content => Some(&mut self.content),
+            Field::Context => Some(&mut self.context),
+            Field::Tags => Some(&mut self.tags),
+            Field::Timestamp => Some(&mut self.timestamp),
+            Field::DocumentId => Some(&mut self.document_id),
+            Field::Mode => None,
+        }
+    }
+
+    /// Text to open in an external editor
+    pub fn to_editor_text(&self, bank_id: &str) -> String {
+        format!(
+            "# Compose a memory for bank '{}'. Lines starting with '#' above the --- line are ignored.\n\
+             # Leave a field empty to omit it. The memory content goes below the --- line.\n\
+             context: {}\n\
+             tags: {}\n\
+             timestamp: {}\n\
+             document_id: {}\n\
+             async: {}\n\
+             {}\n\
+             {}\n",
+            bank_id,
+            self.context,
+            self.tags,
+            self.timestamp,
+            self.document_id,
+            if self.async_mode { "yes" } else { "no" },
+            SEPARATOR,
+            self.content
+        )
+    }
+
+    /// Parse text written in an external editor (see the module docs)
+    pub fn from_editor_text(text: &str) -> Result<Self, String> {
+        let mut draft = Draft::default();
+        let mut lines = text.lines().enumerate();
+
+        let mut found_separator = false;
+        for (i, raw_line) in lines.by_ref() {
+            let line = raw_line.trim();
+            if line == SEPARATOR {
+                found_separator = true;
+                break;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("line {}: expected `field: value` or the --- separator", i + 1))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "context" => draft.context = value,
+                "tags" => draft.tags = value,
+                "timestamp" => draft.timestamp = value,
+                "document_id" | "document" => draft.document_id = value,
+                "async" => draft.async_mode = parse_yes_no(&value).ok_or_else(|| {
+                    format!("line {}: async must be yes or no, not '{}'", i + 1, value)
+                })?,
+                other => return Err(format!("line {}: unknown field `{}`", i + 1, other)),
+            }
+        }
+        if !found_separator {
+            return Err(format!("missing the {} line before the content", SEPARATOR));
+        }
+
+        let content: Vec<&str> = lines.map(|(_, line)| line).collect();
+        draft.content = content.join("\n").trim().to_string();
+        Ok(draft)
+    }
+
+    /// Validate the draft and build the retain request. Returns the request
+    /// and the document ID it uses.
+    pub fn to_request(&self) -> Result<(RetainRequest, String), String> {
+        let content = self.content.trim();
+        if content.is_empty() {
+            return Err("Content cannot be empty".to_string());
+        }
+
+        let timestamp = match self.timestamp.trim() {
+            "" => None,
+            value => Some(parse_timestamp(value)?),
+        };
+        let tags: Vec<String> = self
+            .tags
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|t| !t.is_empty())
+            .map(|t| t.to_string())
+            .collect();
+        let document_id = match self.document_id.trim() {
+            "" => config::generate_doc_id(),
+            id => id.to_string(),
+        };
+        let context = Some(self.context.trim().to_string()).filter(|c| !c.is_empty());
+
+        let item = MemoryItem {
+            content: content.to_string(),
+            context,
+            metadata: None,
+            timestamp,
+            document_id: Some(document_id.clone()),
+            entities: None,
+            tags: if tags.is_empty() { None } else { Some(tags) },
+        };
+        let request = RetainRequest {
+            items: vec![item],
+            async_: self.async_mode,
+            document_tags: None,
+        };
+        Ok((request, document_id))
+    }
+}
+
+fn parse_yes_no(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "yes" | "y" | "true" | "on" | "async" => Some(true),
+        "no" | "n" | "false" | "off" | "sync" | "" => Some(false),
+        _ => None,
+    }
+}
+
+/// Accept RFC 3339 (`2024-05-01T09:30:00Z`) or a plain date (midnight UTC)
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc())
+        .ok_or_else(|| format!("Invalid timestamp '{}' (use RFC 3339, e.g. 2024-05-01T09:30:00Z, or YYYY-MM-DD)", value))
+}
+
+/// The inline compose form
+#[derive(Debug, Clone)]
+pub struct ComposeForm {
+    pub bank_id: String,
+    pub draft: Draft,
+    pub field: Field,
+    /// Editor text that failed to parse, reopened as-is so edits aren't lost
+    pub rejected_editor_text: Option<String>,
+}
+
+impl ComposeForm {
+    pub fn new(bank_id: String, draft: Draft) -> Self {
+        Self { bank_id, draft, field: Field::Content, rejected_editor_text: None }
+    }
+
+    pub fn next_field(&mut self) {
+        self.field = self.field.offset(1);
+    }
+
+    pub fn prev_field(&mut self) {
+        self.field = self.field.offset(-1);
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        match self.draft.field_mut(self.field) {
+            Some(text) => text.push(c),
+            None if c == ' ' => self.toggle_mode(),
+            None => {}
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if let Some(text) = self.draft.field_mut(self.field) {
+            text.pop();
+        }
+    }
+
+    /// Enter adds a line to the content; elsewhere it moves on (or toggles the mode)
+    pub fn enter(&mut self) {
+        match self.field {
+            Field::Content => self.draft.content.push('\n'),
+            Field::Mode => self.toggle_mode(),
+            _ => self.next_field(),
+        }
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.draft.async_mode = !self.draft.async_mode;
+    }
+
+    /// Text for the external editor: the rejected text if the last edit failed to parse
+    pub fn editor_text(&self) -> String {
+        self.rejected_editor_text
+            .clone()
+            .unwrap_or_else(|| self.draft.to_editor_text(&self.bank_id))
+    }
+
+    /// Take the result of an external edit; keeps the text around if it is invalid
+    pub fn apply_editor_text(&mut self, text: String) -> Result<(), String> {
+        match Draft::from_editor_text(&text) {
+            Ok(draft) => {
+                self.draft = draft;
+                self.rejected_editor_text = None;
+                Ok(())
+            }
+            Err(e) => {
+                self.rejected_editor_text = Some(text);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Editor command from `$VISUAL` or `$EDITOR` (falling back to `vi`), split into program and arguments
+pub fn editor_command() -> (String, Vec<String>) {
+    let command = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    let mut parts = command.split_whitespace().map(|s| s.to_string());
+    let program = parts.next().unwrap_or_else(|| "vi".to_string());
+    (program, parts.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft() -> Draft {
+        Draft {
+            content: "Alice will own the Q3 roadmap.\n\n# Notes\nBob agreed.".to_string(),
+            context: "team meeting".to_string(),
+            tags: "work, planning".to_string(),
+            timestamp: "2024-05-01T09:30:00Z".to_string(),
+            document_id: "standup".to_string(),
+            async_mode: true,
+        }
+    }
+
+    #[test]
+    fn test_editor_text_round_trip() {
+        let original = draft();
+        let parsed = Draft::from_editor_text(&original.to_editor_text("demo")).unwrap();
+        // Markdown headings in the content are not comments
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn test_editor_text_errors() {
+        assert!(Draft::from_editor_text("context: x\nno separator").unwrap_err().contains("line 2"));
+        assert!(Draft::from_editor_text("context: x\n").unwrap_err().contains("---"));
+        assert!(Draft::from_editor_text("mood: happy\n---\ntext").unwrap_err().contains("mood"));
+        assert!(Draft::from_editor_text("async: maybe\n---\ntext").is_err());
+    }
+
+    #[test]
+    fn test_to_request() {
+        let (request, document_id) = draft().to_request().unwrap();
+        assert_eq!(document_id, "standup");
+        assert!(request.async_);
+        let item = &request.items[0];
+        assert_eq!(item.tags, Some(vec!["work".to_string(), "planning".to_string()]));
+        assert_eq!(item.context.as_deref(), Some("team meeting"));
+        assert_eq!(item.timestamp.unwrap().to_rfc3339(), "2024-05-01T09:30:00+00:00");
+
+        let minimal = Draft { content: "fact".to_string(), timestamp: "2024-05-01".to_string(), ..Draft::default() };
+        let (request, document_id) = minimal.to_request().unwrap();
+        assert!(document_id.starts_with("cli_put_"));
+        assert_eq!(request.items[0].tags, None);
+        assert_eq!(request.items[0].context, None);
+        assert_eq!(request.items[0].timestamp.unwrap().to_rfc3339(), "2024-05-01T00:00:00+00:00");
+
+        assert!(Draft::default().to_request().unwrap_err().contains("empty"));
+        let bad_time = Draft { content: "fact".to_string(), timestamp: "yesterday".to_string(), ..Draft::default() };
+        assert!(bad_time.to_request().unwrap_err().contains("Invalid timestamp"));
+    }
+
+    #[test]
+    fn test_form_editing() {
+        let mut form = ComposeForm::new("demo".to_string(), Draft::default());
+        for c in "line one".chars() {
+            form.insert_char(c);
+        }
+        form.enter();
+        form.insert_char('x');
+        assert_eq!(form.draft.content, "line one\nx");
+
+        form.prev_field();
+        assert_eq!(form.field, Field::Mode);
+        form.insert_char(' ');
+        assert!(form.draft.async_mode);
+
+        form.next_field();
+        form.next_field();
+        assert_eq!(form.field, Field::Context);
+        form.insert_char('c');
+        form.enter();
+        assert_eq!(form.field, Field::Tags);
+        assert_eq!(form.draft.context, "c");
+    }
+
+    #[test]
+    fn test_rejected_editor_text_is_kept() {
+        let mut form = ComposeForm::new("demo".to_string(), Draft::default());
+        assert!(form.apply_editor_text("oops".to_string()).is_err());
+        assert_eq!(form.editor_text(), "oops");
+
+        form.apply_editor_text("---\nfact".to_string()).unwrap();
+        assert_eq!(form.draft.content, "fact");
+        assert!(form.editor_text().contains("bank 'demo'"));
+    }
+
+    #[test]
+    fn test_from_document() {
+        let doc: DocumentResponse = serde_json::from_value(serde_json::json!({
+            "id": "doc-1",
+            "bank_id": "bank",
+            "original_text": "Original",
+            "content_hash": null,
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-01T00:00:00Z",
+            "memory_unit_count": 0,
+            "tags": ["a", "b"],
+        }))
+        .unwrap();
+        let draft = Draft::from_document(&doc);
+        assert_eq!(draft.document_id, "doc-1");
+        assert_eq!(draft.content, "Original");
+        assert_eq!(draft.tags, "a, b");
+    }
+}
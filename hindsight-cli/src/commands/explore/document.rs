@@ -0,0 +1,342 @@
+//! Document detail view: original text, chunk boundaries and derived memories.
+//!
+//! The API has no endpoint listing a document's chunks or memory units, so the
+//! detail is assembled client-side. Chunk IDs are `{bank}_{document}_{index}`
+//! with consecutive indexes, and every memory unit carries the `chunk_id` it
+//! was extracted from.
+
+use anyhow::Result;
+use hindsight_client::types::{ChunkResponse, DocumentResponse};
+use ratatui::widgets::ListState;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use super::backend::ExploreApi;
+
+/// Upper bound on chunks fetched for one document
+const MAX_CHUNKS: i64 = 500;
+/// Page size used when scanning the bank for derived memory units
+const MEMORY_PAGE_SIZE: i64 = 500;
+/// Memory units scanned before giving up on finding all derived units
+const MAX_SCANNED_MEMORIES: i64 = 10_000;
+
+/// Everything shown in the document view, loaded in the background
+#[derive(Debug, Clone)]
+pub struct DocumentDetail {
+    pub document: DocumentResponse,
+    /// Chunks in index order
+    pub chunks: Vec<ChunkResponse>,
+    /// Memory units extracted from this document, in chunk order
+    pub units: Vec<Map<String, Value>>,
+    /// False when the scan stopped before every derived unit was found
+    pub units_complete: bool,
+}
+
+pub fn chunk_id(bank_id: &str, document_id: &str, index: i64) -> String {
+    format!("{}_{}_{}", bank_id, document_id, index)
+}
+
+/// Chunk index of `chunk_id` if it belongs to the given document
+pub fn chunk_index(chunk_id: &str, bank_id: &str, document_id: &str) -> Option<i64> {
+    let index = chunk_id
+        .strip_prefix(bank_id)?
+        .strip_prefix('_')?
+        .strip_prefix(document_id)?
+        .strip_prefix('_')?;
+    if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    index.parse().ok()
+}
+
+/// Chunk index of a memory unit (as returned by `list_memories`) within a document
+pub fn unit_chunk_index(unit: &Map<String, Value>, bank_id: &str, document_id: &str) -> Option<i64> {
+    unit.get("chunk_id")
+        .and_then(|v| v.as_str())
+        .and_then(|id| chunk_index(id, bank_id, document_id))
+}
+
+/// Fetch a document with its chunks and the memory units derived from it
+pub fn load(client: &dyn ExploreApi, bank_id: &str, document_id: &str) -> Result<DocumentDetail> {
+    let document = client.get_document(bank_id, document_id)?;
+
+    // Chunk indexes are consecutive, so the first missing one ends the document
+    let mut chunks = Vec::new();
+    for index in 0..MAX_CHUNKS {
+        match client.get_chunk(&chunk_id(bank_id, document_id, index)) {
+            Ok(chunk) => chunks.push(chunk),
+            Err(_) => break,
+        }
+    }
+
+    let mut units = Vec::new();
+    let mut offset = 0;
+    let units_complete = loop {
+        if units.len() as i64 >= document.memory_unit_count {
+            break true;
+        }
+        if offset >= MAX_SCANNED_MEMORIES {
+            break false;
+        }
+        let page = client.list_memories(bank_id, MEMORY_PAGE_SIZE, offset)?;
+        let count = page.items.len() as i64;
+        units.extend(
+            page.items
+                .into_iter()
+                .filter(|unit| unit_chunk_index(unit, bank_id, document_id).is_some()),
+        );
+        offset += count;
+        if count < MEMORY_PAGE_SIZE || offset >= page.total {
+            break true;
+        }
+    };
+    units.sort_by_key(|unit| unit_chunk_index(unit, bank_id, document_id));
+
+    Ok(DocumentDetail { document, chunks, units, units_complete })
+}
+
+/// Byte offset of each chunk in the original text, searching forward from the
+/// end of the previous chunk (`None` when a chunk isn't found verbatim)
+pub fn locate_chunks(text: &str, chunks: &[&str]) -> Vec<Option<usize>> {
+    let mut cursor = 0;
+    chunks
+        .iter()
+        .map(|chunk| {
+            let chunk = chunk.trim();
+            let offset = text[cursor..].find(chunk).map(|i| cursor + i)?;
+            cursor = offset + chunk.len();
+            Some(offset)
+        })
+        .collect()
+}
+
+/// A rendered line of the document text
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocLine {
+    Text(String),
+    /// Start of a chunk, with the number of memory units extracted from it
+    Chunk { index: i64, units: usize },
+}
+
+/// Split the original text into lines, inserting a marker line at each chunk
+/// boundary. Boundaries are `(byte offset, chunk index)` in ascending order.
+pub fn layout(text: &str, boundaries: &[(usize, i64)], unit_counts: &HashMap<i64, usize>) -> Vec<DocLine> {
+    let mut lines = Vec::new();
+    let push_text = |lines: &mut Vec<DocLine>, segment: &str| {
+        lines.extend(segment.lines().map(|line| DocLine::Text(line.to_string())));
+    };
+
+    let mut position = 0;
+    for &(offset, index) in boundaries {
+        push_text(&mut lines, &text[position..offset]);
+        lines.push(DocLine::Chunk { index, units: unit_counts.get(&index).copied().unwrap_or(0) });
+        position = offset;
+    }
+    push_text(&mut lines, &text[position..]);
+    lines
+}
+
+/// Byte ranges of `needle` in `line`, ignoring ASCII case
+pub fn find_matches(line: &str, needle: &str) -> Vec<(usize, usize)> {
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    // ASCII lowercasing keeps byte offsets valid for the original line
+    let haystack = line.to_ascii_lowercase();
+    let needle = needle.to_ascii_lowercase();
+    haystack
+        .match_indices(&needle)
+        .map(|(start, matched)| (start, start + matched.len()))
+        .collect()
+}
+
+/// Which side of the document view has focus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pane {
+    Text,
+    Units,
+}
+
+/// Scroll, search and selection state of an open document
+pub struct DocumentView {
+    pub detail: DocumentDetail,
+    pub lines: Vec<DocLine>,
+    /// First visible line
+    pub scroll: usize,
+    /// Lines visible in the text pane at the last render (used for paging)
+    pub page_height: usize,
+    pub pane: Pane,
+    pub units_state: ListState,
+    pub search: String,
+    /// Lines containing a search match
+    pub matches: Vec<usize>,
+    pub current_match: usize,
+    /// Chunks whose text couldn't be found in the original text
+    pub unlocated_chunks: usize,
+}
+
+impl DocumentView {
+    pub fn new(detail: DocumentDetail) -> Self {
+        let bank_id = &detail.document.bank_id;
+        let document_id = &detail.document.id;
+        let mut unit_counts: HashMap<i64, usize> = HashMap::new();
+        for unit in &detail.units {
+            if let Some(index) = unit_chunk_index(unit, bank_id, document_id) {
+                *unit_counts.entry(index).or_default() += 1;
+            }
+        }
+
+        let chunk_texts: Vec<&str> = detail.chunks.iter().map(|c| c.chunk_text.as_str()).collect();
+        let offsets = locate_chunks(&detail.document.original_text, &chunk_texts);
+        let boundaries: Vec<(usize, i64)> = offsets
+            .iter()
+            .zip(&detail.chunks)
+            .filter_map(|(offset, chunk)| offset.map(|o| (o, chunk.chunk_index)))
+            .collect();
+        let unlocated_chunks = offsets.len() - boundaries.len();
+        let lines = layout(&detail.document.original_text, &boundaries, &unit_counts);
+
+        let mut units_state = ListState::default();
+        if !detail.units.is_empty() {
+            units_state.select(Some(0));
+        }
+        Self {
+            detail,
+            lines,
+            scroll: 0,
+            page_height: 20,
+            pane: Pane::Text,
+            units_state,
+            search: String::new(),
+            matches: Vec::new(),
+            current_match: 0,
+            unlocated_chunks,
+        }
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(1)
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self.scroll.saturating_add_signed(delta).min(self.max_scroll());
+    }
+
+    pub fn page(&mut self, forward: bool) {
+        let page = self.page_height.max(1) as isize;
+        self.scroll_by(if forward { page } else { -page });
+    }
+
+    pub fn toggle_pane(&mut self) {
+        self.pane = match self.pane {
+            Pane::Text if !self.detail.units.is_empty() => Pane::Units,
+            _ => Pane::Text,
+        };
+    }
+
+    /// Move the memory unit selection and scroll the text to its chunk
+    pub fn select_unit(&mut self, delta: isize) {
+        let len = self.detail.units.len();
+        if len == 0 {
+            return;
+        }
+        let current = self.units_state.selected().unwrap_or(0) as isize;
+        let next = (current + delta).clamp(0, len as isize - 1) as usize;
+        self.units_state.select(Some(next));
+        if let Some(index) = self.selected_unit_chunk() {
+            self.jump_to_chunk(index);
+        }
+    }
+
+    pub fn selected_unit_chunk(&self) -> Option<i64> {
+        let unit = self.detail.units.get(self.units_state.selected()?)?;
+        unit_chunk_index(unit, &self.detail.document.bank_id, &self.detail.document.id)
+    }
+
+    pub fn jump_to_chunk(&mut self, index: i64) {
+        if let Some(line) = self
+            .lines
+            .iter()
+            .position(|l| matches!(l, DocLine::Chunk { index: i, .. } if *i == index))
+        {
+            self.scroll = line;
+        }
+    }
+
+    /// Update the search text and jump to the first match from the top
+    pub fn set_search(&mut self, search: String) {
+        self.search = search;
+        self.matches = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| matches!(line, DocLine::Text(text) if !find_matches(text, &self.search).is_empty()))
+            .map(|(i, _)| i)
+            .collect();
+        self.current_match = 0;
+        if let Some(&line) = self.matches.first() {
+            self.scroll = line;
+        }
+    }
+
+    /// Jump to the next (or previous) match, wrapping around
+    pub fn next_match(&mut self, forward: bool) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let len = self.matches.len();
+        self.current_match = if forward { (self.current_match + 1) % len } else { (self.current_match + len - 1) % len };
+        self.scroll = self.matches[self.current_match];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_index() {
+        assert_eq!(chunk_id("bank", "doc", 3), "bank_doc_3");
+        assert_eq!(chunk_index("bank_doc_3", "bank", "doc"), Some(3));
+        // Another document whose ID starts with this one
+        assert_eq!(chunk_index("bank_doc_2_0", "bank", "doc"), None);
+        assert_eq!(chunk_index("bank_doc_2_0", "bank", "doc_2"), Some(0));
+        assert_eq!(chunk_index("bank_doc_", "bank", "doc"), None);
+        assert_eq!(chunk_index("other_doc_1", "bank", "doc"), None);
+    }
+
+    #[test]
+    fn test_locate_chunks() {
+        let text = "First part.\nSecond part.\nThird part.";
+        assert_eq!(
+            locate_chunks(text, &["First part.\n", "Second part.", "missing", "Third part."]),
+            vec![Some(0), Some(12), None, Some(25)]
+        );
+    }
+
+    #[test]
+    fn test_layout_inserts_chunk_markers() {
+        let text = "one\ntwo\nthree four";
+        let counts = HashMap::from([(1, 2)]);
+        // The second chunk starts mid-line
+        let lines = layout(text, &[(0, 0), (14, 1)], &counts);
+        assert_eq!(
+            lines,
+            vec![
+                DocLine::Chunk { index: 0, units: 0 },
+                DocLine::Text("one".to_string()),
+                DocLine::Text("two".to_string()),
+                DocLine::Text("three ".to_string()),
+                DocLine::Chunk { index: 1, units: 2 },
+                DocLine::Text("four".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_ignores_case() {
+        assert_eq!(find_matches("Alice met alice", "ALICE"), vec![(0, 5), (10, 15)]);
+        assert_eq!(find_matches("Zoë met Zoë", "zoë"), vec![(0, 4), (9, 13)]);
+        assert!(find_matches("anything", "").is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+//! Exporting the current list or result set to a JSON or Markdown file.
+//!
+//! The App builds an [`Export`] for the current view (respecting the active
+//! filter); this module picks the file name and format and writes it.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Json,
+    Markdown,
+}
+
+impl Format {
+    /// Markdown for `.md`/`.markdown` files, JSON otherwise
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+            Some("md") | Some("markdown") => Format::Markdown,
+            _ => Format::Json,
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Markdown => "md",
+        }
+    }
+}
+
+/// Content of one export in both formats
+pub struct Export {
+    /// Short name of what is exported, used in the default file name
+    pub name: String,
+    pub count: usize,
+    pub json: Value,
+    pub markdown: String,
+}
+
+/// Where `:export [target]` writes: `json`/`md` (or nothing) pick a
+/// generated file name in the current directory, anything else is a path
+pub fn resolve_target(target: Option<&str>, name: &str, bank_id: Option<&str>) -> (PathBuf, Format) {
+    let generated = |format: Format| {
+        let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let stem = match bank_id {
+            Some(bank) => format!("hindsight-{}-{}-{}", name, bank, timestamp),
+            None => format!("hindsight-{}-{}", name, timestamp),
+        };
+        (PathBuf::from(format!("{}.{}", stem, format.extension())), format)
+    };
+    match target.map(|t| t.to_lowercase()).as_deref() {
+        None | Some("json") => generated(Format::Json),
+        Some("md") | Some("markdown") => generated(Format::Markdown),
+        Some(_) => {
+            let path = PathBuf::from(target.unwrap_or_default());
+            let format = Format::from_path(&path);
+            (path, format)
+        }
+    }
+}
+
+pub fn write(path: &Path, export: &Export, format: Format) -> Result<()> {
+    let content = match format {
+        Format::Json => serde_json::to_string_pretty(&export.json)?,
+        Format::Markdown => export.markdown.clone(),
+    };
+    fs::write(path, content).with_context(|| format!("Failed to write export: {}", path.display()))
+}
+
+/// Markdown table under a heading. Cells are escaped so pipes and newlines
+/// in memory text don't break the table.
+pub fn table(title: &str, columns: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = format!("# {}\n\n", title);
+    if rows.is_empty() {
+        out.push_str("_No items._\n");
+        return out;
+    }
+    out.push_str(&format!("| {} |\n", columns.join(" | ")));
+    out.push_str(&format!("|{}\n", " --- |".repeat(columns.len())));
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|cell| escape_cell(cell)).collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    out
+}
+
+fn escape_cell(cell: &str) -> String {
+    cell.trim().replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_table_escapes_cells() {
+        let rows = vec![vec!["1".to_string(), "a | b\nc".to_string()]];
+        assert_eq!(
+            table("Memories", &["ID", "Text"], &rows),
+            "# Memories\n\n| ID | Text |\n| --- | --- |\n| 1 | a \\| b<br>c |\n"
+        );
+        assert_eq!(table("Empty", &["ID"], &[]), "# Empty\n\n_No items._\n");
+    }
+
+    #[test]
+    fn test_resolve_target() {
+        let (path, format) = resolve_target(None, "memories", Some("demo"));
+        assert_eq!(format, Format::Json);
+        let name = path.to_string_lossy();
+        assert!(name.starts_with("hindsight-memories-demo-") && name.ends_with(".json"));
+
+        let (path, format) = resolve_target(Some("MD"), "recall", None);
+        assert_eq!(format, Format::Markdown);
+        assert!(path.to_string_lossy().ends_with(".md"));
+
+        assert_eq!(resolve_target(Some("out/notes.markdown"), "x", None), (PathBuf::from("out/notes.markdown"), Format::Markdown));
+        assert_eq!(resolve_target(Some("facts.txt"), "x", None).1, Format::Json);
+    }
+
+    #[test]
+    fn test_write_formats() {
+        let export = Export { name: "test".to_string(), count: 1, json: json!([{"id": "1"}]), markdown: "# Test\n".to_string() };
+        let dir = std::env::temp_dir();
+        let json_path = dir.join(format!("hindsight-export-{}.json", std::process::id()));
+        let md_path = dir.join(format!("hindsight-export-{}.md", std::process::id()));
+
+        write(&json_path, &export, Format::Json).unwrap();
+        write(&md_path, &export, Format::Markdown).unwrap();
+        let json: Value = serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+        let markdown = fs::read_to_string(&md_path).unwrap();
+        fs::remove_file(&json_path).unwrap();
+        fs::remove_file(&md_path).unwrap();
+
+        assert_eq!(json, export.json);
+        assert_eq!(markdown, "# Test\n");
+    }
+}
@@ -0,0 +1,149 @@
+//! Incremental fuzzy filtering for explorer lists.
+//!
+//! Matching is a case-insensitive subsequence search (like k9s / fzf): every
+//! character of the pattern must appear in order in the candidate. Matches are
+//! scored so that consecutive runs and word-boundary hits rank higher.
+
+/// Score awarded for every matched character
+const SCORE_MATCH: i64 = 16;
+/// Bonus when a match directly follows the previous match
+const BONUS_CONSECUTIVE: i64 = 24;
+/// Bonus when a match starts a word (after a separator or at the start)
+const BONUS_WORD_START: i64 = 20;
+/// Penalty per skipped character between matches
+const PENALTY_GAP: i64 = 1;
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '-' | '_' | '.' | '/' | ':' | ',' | '(' | ')' | '[' | ']')
+}
+
+/// Score `text` against `pattern`.
+///
+/// Returns `None` if the pattern is not a subsequence of the text. An empty
+/// (or whitespace-only) pattern matches everything with a score of 0.
+pub fn fuzzy_score(pattern: &str, text: &str) -> Option<i64> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if pattern.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0i64;
+    let mut pattern_idx = 0;
+    let mut prev_char: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+
+    for (i, c) in text.chars().enumerate() {
+        if pattern_idx == pattern.len() {
+            break;
+        }
+
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        if lower == pattern[pattern_idx] {
+            score += SCORE_MATCH;
+            match last_match {
+                Some(last) if last + 1 == i => score += BONUS_CONSECUTIVE,
+                Some(last) => score -= PENALTY_GAP * (i - last - 1) as i64,
+                None => score -= PENALTY_GAP * i as i64,
+            }
+            if prev_char.map(is_separator).unwrap_or(true) {
+                score += BONUS_WORD_START;
+            }
+            last_match = Some(i);
+            pattern_idx += 1;
+        }
+        prev_char = Some(c);
+    }
+
+    if pattern_idx == pattern.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+/// Filter a list of candidate strings, returning the indices of matching items.
+///
+/// Results are ordered by descending score; ties keep their original order so
+/// the list stays stable while the user types.
+pub fn filter_indices<'a, I>(pattern: &str, candidates: I) -> Vec<usize>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut scored: Vec<(usize, i64)> = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, text)| fuzzy_score(pattern, text).map(|score| (i, score)))
+        .collect();
+
+    if !pattern.trim().is_empty() {
+        scored.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    }
+
+    scored.into_iter().map(|(i, _)| i).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_pattern_matches_everything() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert_eq!(fuzzy_score("   ", "anything"), Some(0));
+        assert_eq!(filter_indices("", ["a", "b", "c"]), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_subsequence_match() {
+        assert!(fuzzy_score("alc", "Alice works at Google").is_some());
+        assert!(fuzzy_score("awg", "Alice works at Google").is_some());
+        assert!(fuzzy_score("xyz", "Alice works at Google").is_none());
+        // Order matters
+        assert!(fuzzy_score("ecila", "alice").is_none());
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert!(fuzzy_score("ALICE", "alice").is_some());
+        assert!(fuzzy_score("alice", "ALICE").is_some());
+    }
+
+    #[test]
+    fn test_whitespace_in_pattern_is_ignored() {
+        assert!(fuzzy_score("al ice", "alice").is_some());
+    }
+
+    #[test]
+    fn test_consecutive_beats_scattered() {
+        let consecutive = fuzzy_score("bank", "my-bank").unwrap();
+        let scattered = fuzzy_score("bank", "big apple network key").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn test_word_start_beats_mid_word() {
+        let word_start = fuzzy_score("g", "at google").unwrap();
+        let mid_word = fuzzy_score("g", "at bigco").unwrap();
+        assert!(word_start > mid_word);
+    }
+
+    #[test]
+    fn test_filter_indices_orders_by_score() {
+        let items = ["travel notes", "tnotes", "team-notes"];
+        // Consecutive match first, then the shorter gap
+        assert_eq!(filter_indices("tn", items), vec![1, 2, 0]);
+        // Equal scores keep the original order
+        assert_eq!(filter_indices("t", items), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_filter_indices_excludes_non_matches() {
+        let items = ["alice", "bob", "alicia"];
+        assert_eq!(filter_indices("ali", items), vec![0, 2]);
+        assert!(filter_indices("zzz", items).is_empty());
+    }
+}
@@ -0,0 +1,350 @@
+//! Per-bank query history and saved queries for the Query view.
+//!
+//! Everything is persisted to `~/.hindsight/queries.json` so history survives
+//! restarts. Saved queries keep the results of their last run, which lets a
+//! re-run show what changed (new and dropped recall results, or a changed
+//! reflect answer).
+
+use anyhow::{Context, Result};
+use hindsight_client::types::{Budget, RecallResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::QueryMode;
+use crate::config::Config;
+
+const STORE_FILE_NAME: &str = "queries.json";
+/// History entries kept per bank
+const MAX_HISTORY: usize = 100;
+
+/// Everything needed to re-run a query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuerySettings {
+    pub mode: QueryMode,
+    pub query: String,
+    pub budget: Budget,
+    pub max_tokens: i64,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Budget of the reflect side in Compare mode (`None` = same as `budget`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reflect_budget: Option<Budget>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    #[serde(flatten)]
+    pub settings: QuerySettings,
+    pub ran_at: String,
+}
+
+/// A recall result as stored for later comparison
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredFact {
+    pub id: String,
+    pub text: String,
+}
+
+/// Results of a saved query's last run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "lowercase")]
+pub enum StoredResults {
+    Recall { ran_at: String, facts: Vec<StoredFact> },
+    Reflect { ran_at: String, text: String },
+}
+
+impl StoredResults {
+    pub fn recall(results: &[RecallResult]) -> Self {
+        StoredResults::Recall {
+            ran_at: now(),
+            facts: results.iter().map(|r| StoredFact { id: r.id.clone(), text: r.text.clone() }).collect(),
+        }
+    }
+
+    pub fn reflect(text: &str) -> Self {
+        StoredResults::Reflect { ran_at: now(), text: text.to_string() }
+    }
+
+    pub fn ran_at(&self) -> &str {
+        match self {
+            StoredResults::Recall { ran_at, .. } | StoredResults::Reflect { ran_at, .. } => ran_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedQuery {
+    pub name: String,
+    #[serde(flatten)]
+    pub settings: QuerySettings,
+    #[serde(default)]
+    pub last_results: Option<StoredResults>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BankQueries {
+    #[serde(default)]
+    history: Vec<HistoryEntry>,
+    #[serde(default)]
+    saved: Vec<SavedQuery>,
+}
+
+/// Difference between a saved query's stored recall results and a new run
+#[derive(Debug, Default, PartialEq)]
+pub struct RecallDiff {
+    /// IDs of results that were not in the stored run
+    pub added: HashSet<String>,
+    /// Stored results missing from the new run
+    pub removed: Vec<StoredFact>,
+}
+
+/// How a re-run compares with the stored results of a saved query
+#[derive(Debug)]
+pub struct Comparison {
+    pub name: String,
+    /// Stored results the new run is compared against (`None` on first run)
+    pub previous: Option<StoredResults>,
+    pub recall_diff: Option<RecallDiff>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct QueryStore {
+    #[serde(default)]
+    banks: BTreeMap<String, BankQueries>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+impl QueryStore {
+    pub fn file_path() -> Option<PathBuf> {
+        Config::config_dir().map(|dir| dir.join(STORE_FILE_NAME))
+    }
+
+    /// Load the store from the config directory (empty if it doesn't exist yet)
+    pub fn load() -> Result<Self> {
+        match Self::file_path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let mut store = if path.exists() {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read query history: {}", path.display()))?;
+            serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse query history: {}", path.display()))?
+        } else {
+            Self::default()
+        };
+        store.path = Some(path.to_path_buf());
+        Ok(store)
+    }
+
+    /// Write the store back to the file it was loaded from
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create config directory: {}", dir.display()))?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content)
+            .with_context(|| format!("Failed to write query history: {}", path.display()))
+    }
+
+    /// History for a bank, oldest first
+    pub fn history(&self, bank_id: &str) -> &[HistoryEntry] {
+        self.banks.get(bank_id).map(|b| b.history.as_slice()).unwrap_or(&[])
+    }
+
+    /// Record a query run. Re-running the most recent query doesn't add a duplicate.
+    pub fn push_history(&mut self, bank_id: &str, settings: QuerySettings) {
+        let history = &mut self.banks.entry(bank_id.to_string()).or_default().history;
+        if history.last().map(|e| e.settings == settings).unwrap_or(false) {
+            history.pop();
+        }
+        history.push(HistoryEntry { settings, ran_at: now() });
+        if history.len() > MAX_HISTORY {
+            let excess = history.len() - MAX_HISTORY;
+            history.drain(..excess);
+        }
+    }
+
+    pub fn saved(&self, bank_id: &str) -> &[SavedQuery] {
+        self.banks.get(bank_id).map(|b| b.saved.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn get_saved(&self, bank_id: &str, name: &str) -> Option<&SavedQuery> {
+        self.saved(bank_id).iter().find(|q| q.name == name)
+    }
+
+    /// Save (or overwrite) a named query together with its current results
+    pub fn save_query(&mut self, bank_id: &str, name: &str, settings: QuerySettings, results: Option<StoredResults>) {
+        let saved = &mut self.banks.entry(bank_id.to_string()).or_default().saved;
+        let query = SavedQuery { name: name.to_string(), settings, last_results: results };
+        match saved.iter_mut().find(|q| q.name == name) {
+            Some(existing) => *existing = query,
+            None => saved.push(query),
+        }
+    }
+
+    pub fn remove_saved(&mut self, bank_id: &str, name: &str) -> bool {
+        match self.banks.get_mut(bank_id) {
+            Some(bank) => {
+                let before = bank.saved.len();
+                bank.saved.retain(|q| q.name != name);
+                bank.saved.len() != before
+            }
+            None => false,
+        }
+    }
+
+    /// Replace a saved query's stored results, returning the previous ones
+    pub fn update_results(&mut self, bank_id: &str, name: &str, results: StoredResults) -> Option<StoredResults> {
+        let query = self
+            .banks
+            .get_mut(bank_id)?
+            .saved
+            .iter_mut()
+            .find(|q| q.name == name)?;
+        query.last_results.replace(results)
+    }
+}
+
+/// Compare stored recall facts against a new set of results (by memory ID)
+pub fn diff_recall(previous: &[StoredFact], current: &[RecallResult]) -> RecallDiff {
+    let previous_ids: HashSet<&str> = previous.iter().map(|f| f.id.as_str()).collect();
+    let current_ids: HashSet<&str> = current.iter().map(|r| r.id.as_str()).collect();
+
+    RecallDiff {
+        added: current
+            .iter()
+            .filter(|r| !previous_ids.contains(r.id.as_str()))
+            .map(|r| r.id.clone())
+            .collect(),
+        removed: previous
+            .iter()
+            .filter(|f| !current_ids.contains(f.id.as_str()))
+            .cloned()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(query: &str) -> QuerySettings {
+        QuerySettings {
+            mode: QueryMode::Recall,
+            query: query.to_string(),
+            budget: Budget::Mid,
+            max_tokens: 4096,
+            tags: Vec::new(),
+            reflect_budget: None,
+        }
+    }
+
+    fn result(id: &str) -> RecallResult {
+        serde_json::from_value(serde_json::json!({ "id": id, "text": format!("fact {}", id) })).unwrap()
+    }
+
+    #[test]
+    fn test_history_skips_consecutive_duplicates() {
+        let mut store = QueryStore::default();
+        store.push_history("bank", settings("a"));
+        store.push_history("bank", settings("a"));
+        store.push_history("bank", settings("b"));
+        store.push_history("bank", settings("a"));
+
+        let queries: Vec<&str> = store.history("bank").iter().map(|e| e.settings.query.as_str()).collect();
+        assert_eq!(queries, vec!["a", "b", "a"]);
+        assert!(store.history("other").is_empty());
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut store = QueryStore::default();
+        for i in 0..MAX_HISTORY + 5 {
+            store.push_history("bank", settings(&i.to_string()));
+        }
+        let history = store.history("bank");
+        assert_eq!(history.len(), MAX_HISTORY);
+        assert_eq!(history[0].settings.query, "5");
+    }
+
+    #[test]
+    fn test_save_overwrite_and_remove() {
+        let mut store = QueryStore::default();
+        store.save_query("bank", "weekly", settings("a"), None);
+        store.save_query("bank", "weekly", settings("b"), None);
+        assert_eq!(store.saved("bank").len(), 1);
+        assert_eq!(store.get_saved("bank", "weekly").unwrap().settings.query, "b");
+
+        assert!(store.remove_saved("bank", "weekly"));
+        assert!(!store.remove_saved("bank", "weekly"));
+    }
+
+    #[test]
+    fn test_update_results_returns_previous() {
+        let mut store = QueryStore::default();
+        let first = StoredResults::reflect("first");
+        store.save_query("bank", "q", settings("a"), Some(first.clone()));
+
+        let previous = store.update_results("bank", "q", StoredResults::reflect("second"));
+        assert_eq!(previous, Some(first));
+        assert!(store.update_results("bank", "missing", StoredResults::reflect("x")).is_none());
+    }
+
+    #[test]
+    fn test_diff_recall() {
+        let previous = vec![
+            StoredFact { id: "1".to_string(), text: "one".to_string() },
+            StoredFact { id: "2".to_string(), text: "two".to_string() },
+        ];
+        let diff = diff_recall(&previous, &[result("2"), result("3")]);
+        assert_eq!(diff.added, HashSet::from(["3".to_string()]));
+        assert_eq!(diff.removed, vec![previous[0].clone()]);
+    }
+
+    #[test]
+    fn test_compare_settings_are_optional() {
+        let entry: HistoryEntry = serde_json::from_str(
+            r#"{"mode": "recall", "query": "q", "budget": "low", "max_tokens": 1024, "ran_at": "2025-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(entry.settings.reflect_budget, None);
+        assert!(entry.settings.tags.is_empty());
+
+        let compare = QuerySettings { mode: QueryMode::Compare, reflect_budget: Some(Budget::High), ..settings("q") };
+        let json = serde_json::to_value(&compare).unwrap();
+        assert_eq!(json["mode"], "compare");
+        assert_eq!(json["reflect_budget"], "high");
+    }
+
+    #[test]
+    fn test_round_trip_through_file() {
+        let path = std::env::temp_dir().join(format!("hindsight-queries-{}.json", std::process::id()));
+        let mut store = QueryStore::load_from(&path).unwrap();
+        store.push_history("bank", settings("a"));
+        store.save_query("bank", "q", settings("a"), Some(StoredResults::recall(&[result("1")])));
+        store.save().unwrap();
+
+        let loaded = QueryStore::load_from(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.history("bank").len(), 1);
+        match &loaded.get_saved("bank", "q").unwrap().last_results {
+            Some(StoredResults::Recall { facts, .. }) => assert_eq!(facts[0].id, "1"),
+            other => panic!("unexpected stored results: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,506 @@
+//! Key bindings for the explorer.
+//!
+//! Normal-mode keys are looked up through a [`Keymap`] instead of being
+//! matched directly, so users can pick a preset (`default`, `vim`, `emacs`)
+//! and override individual actions from `~/.hindsight/explore.toml`. The help
+//! overlay and the shortcut bar are rendered from the same keymap.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::fmt;
+
+/// Something a key can do in normal mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Down,
+    Up,
+    ScrollLeft,
+    ScrollRight,
+    Select,
+    Back,
+    Filter,
+    Command,
+    Refresh,
+    ToggleMode,
+    CycleBudget,
+    CycleReflectBudget,
+    MoreTokens,
+    FewerTokens,
+    Delete,
+    Compose,
+    Consolidate,
+    Trace,
+    Yank,
+    Export,
+    NextPage,
+    PrevPage,
+    Help,
+    Quit,
+}
+
+impl Action {
+    pub const ALL: [Action; 24] = [
+        Action::Down,
+        Action::Up,
+        Action::ScrollLeft,
+        Action::ScrollRight,
+        Action::Select,
+        Action::Back,
+        Action::Filter,
+        Action::Command,
+        Action::Refresh,
+        Action::ToggleMode,
+        Action::CycleBudget,
+        Action::CycleReflectBudget,
+        Action::MoreTokens,
+        Action::FewerTokens,
+        Action::Delete,
+        Action::Compose,
+        Action::Consolidate,
+        Action::Trace,
+        Action::Yank,
+        Action::Export,
+        Action::NextPage,
+        Action::PrevPage,
+        Action::Help,
+        Action::Quit,
+    ];
+
+    /// Name used in the `[keys]` section of the config file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Down => "down",
+            Action::Up => "up",
+            Action::ScrollLeft => "scroll_left",
+            Action::ScrollRight => "scroll_right",
+            Action::Select => "select",
+            Action::Back => "back",
+            Action::Filter => "filter",
+            Action::Command => "command",
+            Action::Refresh => "refresh",
+            Action::ToggleMode => "toggle_mode",
+            Action::CycleBudget => "cycle_budget",
+            Action::CycleReflectBudget => "cycle_reflect_budget",
+            Action::MoreTokens => "more_tokens",
+            Action::FewerTokens => "fewer_tokens",
+            Action::Delete => "delete",
+            Action::Compose => "compose",
+            Action::Consolidate => "consolidate",
+            Action::Trace => "trace",
+            Action::Yank => "yank",
+            Action::Export => "export",
+            Action::NextPage => "next_page",
+            Action::PrevPage => "prev_page",
+            Action::Help => "help",
+            Action::Quit => "quit",
+        }
+    }
+
+    /// One-line description for the help overlay
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Down => "Move down in lists",
+            Action::Up => "Move up in lists",
+            Action::ScrollLeft => "Scroll text left in tables",
+            Action::ScrollRight => "Scroll text right in tables",
+            Action::Select => "Select item / view details",
+            Action::Back => "Go back / close detail view / clear filter",
+            Action::Filter => "Fuzzy filter the list (edit the query in Query view)",
+            Action::Command => "Command palette (Tab completes commands and bank IDs)",
+            Action::Refresh => "Refresh current view",
+            Action::ToggleMode => "Cycle query mode (Recall → Reflect → Compare)",
+            Action::CycleBudget => "Cycle budget (Low → Mid → High)",
+            Action::CycleReflectBudget => "Cycle the reflect budget in Compare mode (same → Low → Mid → High)",
+            Action::MoreTokens => "Increase max tokens",
+            Action::FewerTokens => "Decrease max tokens",
+            Action::Delete => "Delete selected document",
+            Action::Compose => "Compose a memory to retain (edits the open document)",
+            Action::Consolidate => "Consolidate the bank (dashboard)",
+            Action::Trace => "Toggle the trace tree of the last query (traces later queries)",
+            Action::Yank => "Copy the selected item: then i = ID, t/y = text, j = JSON",
+            Action::Export => "Export the current list or results to a JSON file (:export md for Markdown)",
+            Action::NextPage => "Next page of memories",
+            Action::PrevPage => "Previous page of memories",
+            Action::Help => "Toggle this help screen",
+            Action::Quit => "Quit",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|a| a.name() == name)
+    }
+}
+
+/// A key with modifiers, e.g. `C-n`, `M-x`, `PageDown`, `j`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    fn plain(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    fn char(c: char) -> Self {
+        Self::plain(KeyCode::Char(c))
+    }
+
+    fn ctrl(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn alt(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    /// Parse a key description such as `j`, `Enter`, `C-n`, `ctrl-d`, `M-x`, `PageDown`
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut rest = input.trim();
+        if rest.is_empty() {
+            return Err("Empty key".to_string());
+        }
+
+        let mut modifiers = KeyModifiers::NONE;
+        loop {
+            let lower = rest.to_lowercase();
+            let prefix = [
+                ("ctrl-", KeyModifiers::CONTROL),
+                ("c-", KeyModifiers::CONTROL),
+                ("alt-", KeyModifiers::ALT),
+                ("meta-", KeyModifiers::ALT),
+                ("m-", KeyModifiers::ALT),
+                ("shift-", KeyModifiers::SHIFT),
+                ("s-", KeyModifiers::SHIFT),
+            ]
+            .into_iter()
+            // A trailing `-` is the key itself (`-`, `C--`), not a modifier
+            .find(|(p, _)| lower.starts_with(p) && rest.len() > p.len());
+            match prefix {
+                Some((p, modifier)) => {
+                    modifiers |= modifier;
+                    rest = &rest[p.len()..];
+                }
+                None => break,
+            }
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "enter" | "return" | "ret" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" | "bs" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "space" | "spc" => KeyCode::Char(' '),
+            lower => {
+                let mut chars = rest.chars();
+                match (chars.next(), chars.next()) {
+                    // Control combos are reported lowercase by the terminal
+                    (Some(c), None) if modifiers.contains(KeyModifiers::CONTROL) => KeyCode::Char(c.to_ascii_lowercase()),
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                        Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                        _ => return Err(format!("Unknown key: {}", input)),
+                    },
+                }
+            }
+        };
+
+        Ok(Self { code, modifiers })
+    }
+
+    /// Whether a terminal key event triggers this binding.
+    ///
+    /// Shift is ignored for characters since it is already part of the
+    /// character itself (`R` arrives as `Shift+R` on some terminals).
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        let relevant = |m: KeyModifiers| match self.code {
+            KeyCode::Char(_) => m & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+            _ => m & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT),
+        };
+        self.code == event.code && relevant(self.modifiers) == relevant(event.modifiers)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "C-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "M-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "S-")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::BackTab => write!(f, "BackTab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Insert => write!(f, "Ins"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// Ordered key → action bindings
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(KeyBinding, Action)>,
+}
+
+impl Keymap {
+    /// Look up a preset by name (`default`, `vim`, `emacs`)
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "default" => Some(Self::default_preset()),
+            "vim" => Some(Self::vim()),
+            "emacs" => Some(Self::emacs()),
+            _ => None,
+        }
+    }
+
+    fn from_pairs(pairs: Vec<(KeyBinding, Action)>) -> Self {
+        Self { bindings: pairs }
+    }
+
+    /// Arrow keys plus single-letter commands
+    pub fn default_preset() -> Self {
+        use Action::*;
+        Self::from_pairs(vec![
+            (KeyBinding::plain(KeyCode::Down), Down),
+            (KeyBinding::char('j'), Down),
+            (KeyBinding::plain(KeyCode::Up), Up),
+            (KeyBinding::char('k'), Up),
+            (KeyBinding::plain(KeyCode::Left), ScrollLeft),
+            (KeyBinding::char('h'), ScrollLeft),
+            (KeyBinding::plain(KeyCode::Right), ScrollRight),
+            (KeyBinding::char('l'), ScrollRight),
+            (KeyBinding::plain(KeyCode::Enter), Select),
+            (KeyBinding::plain(KeyCode::Esc), Back),
+            (KeyBinding::char('/'), Filter),
+            (KeyBinding::char(':'), Command),
+            (KeyBinding::char('R'), Refresh),
+            (KeyBinding::char('m'), ToggleMode),
+            (KeyBinding::char('b'), CycleBudget),
+            (KeyBinding::char('B'), CycleReflectBudget),
+            (KeyBinding::char('+'), MoreTokens),
+            (KeyBinding::char('='), MoreTokens),
+            (KeyBinding::char('-'), FewerTokens),
+            (KeyBinding::plain(KeyCode::Delete), Delete),
+            (KeyBinding::char('a'), Compose),
+            (KeyBinding::char('c'), Consolidate),
+            (KeyBinding::char('t'), Trace),
+            (KeyBinding::char('y'), Yank),
+            (KeyBinding::char('e'), Export),
+            (KeyBinding::char('n'), NextPage),
+            (KeyBinding::char('p'), PrevPage),
+            (KeyBinding::char('?'), Help),
+            (KeyBinding::char('q'), Quit),
+        ])
+    }
+
+    /// Default keys plus vim paging and motions
+    pub fn vim() -> Self {
+        use Action::*;
+        let mut keymap = Self::default_preset();
+        keymap.bindings.extend([
+            (KeyBinding::ctrl('f'), NextPage),
+            (KeyBinding::ctrl('b'), PrevPage),
+            (KeyBinding::ctrl('l'), Refresh),
+            (KeyBinding::char('x'), Delete),
+        ]);
+        keymap
+    }
+
+    /// Control/meta chords in the style of Emacs; letters only for query controls
+    pub fn emacs() -> Self {
+        use Action::*;
+        Self::from_pairs(vec![
+            (KeyBinding::ctrl('n'), Down),
+            (KeyBinding::plain(KeyCode::Down), Down),
+            (KeyBinding::ctrl('p'), Up),
+            (KeyBinding::plain(KeyCode::Up), Up),
+            (KeyBinding::ctrl('b'), ScrollLeft),
+            (KeyBinding::plain(KeyCode::Left), ScrollLeft),
+            (KeyBinding::ctrl('f'), ScrollRight),
+            (KeyBinding::plain(KeyCode::Right), ScrollRight),
+            (KeyBinding::plain(KeyCode::Enter), Select),
+            (KeyBinding::ctrl('g'), Back),
+            (KeyBinding::plain(KeyCode::Esc), Back),
+            (KeyBinding::ctrl('s'), Filter),
+            (KeyBinding::char('/'), Filter),
+            (KeyBinding::alt('x'), Command),
+            (KeyBinding::char(':'), Command),
+            (KeyBinding::char('g'), Refresh),
+            (KeyBinding::char('m'), ToggleMode),
+            (KeyBinding::char('b'), CycleBudget),
+            (KeyBinding::char('B'), CycleReflectBudget),
+            (KeyBinding::char('+'), MoreTokens),
+            (KeyBinding::char('-'), FewerTokens),
+            (KeyBinding::ctrl('d'), Delete),
+            (KeyBinding::plain(KeyCode::Delete), Delete),
+            (KeyBinding::char('a'), Compose),
+            (KeyBinding::char('c'), Consolidate),
+            (KeyBinding::char('t'), Trace),
+            (KeyBinding::char('y'), Yank),
+            (KeyBinding::char('e'), Export),
+            (KeyBinding::ctrl('v'), NextPage),
+            (KeyBinding::alt('v'), PrevPage),
+            (KeyBinding::ctrl('h'), Help),
+            (KeyBinding::char('?'), Help),
+            (KeyBinding::char('q'), Quit),
+        ])
+    }
+
+    /// Replace all keys of `action`. Keys taken from other actions are unbound there.
+    pub fn bind(&mut self, action: Action, keys: Vec<KeyBinding>) {
+        self.bindings
+            .retain(|(key, bound)| *bound != action && !keys.contains(key));
+        self.bindings.extend(keys.into_iter().map(|key| (key, action)));
+    }
+
+    pub fn action_for(&self, event: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(key, _)| key.matches(event))
+            .map(|(_, action)| *action)
+    }
+
+    pub fn keys_for(&self, action: Action) -> Vec<KeyBinding> {
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(key, _)| *key)
+            .collect()
+    }
+
+    /// Display label for an action's keys, e.g. `↓/j` (`-` if unbound)
+    pub fn label(&self, action: Action) -> String {
+        let keys = self.keys_for(action);
+        if keys.is_empty() {
+            return "-".to_string();
+        }
+        keys.iter().map(|k| k.to_string()).collect::<Vec<_>>().join("/")
+    }
+
+    /// Label of the first key only, for the compact shortcut bar
+    pub fn short_label(&self, action: Action) -> String {
+        self.keys_for(action)
+            .first()
+            .map(|k| k.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(KeyBinding::parse("j"), Ok(KeyBinding::char('j')));
+        assert_eq!(KeyBinding::parse("Enter"), Ok(KeyBinding::plain(KeyCode::Enter)));
+        assert_eq!(KeyBinding::parse("C-n"), Ok(KeyBinding::ctrl('n')));
+        assert_eq!(KeyBinding::parse("ctrl-N"), Ok(KeyBinding::ctrl('n')));
+        assert_eq!(KeyBinding::parse("M-x"), Ok(KeyBinding::alt('x')));
+        assert_eq!(KeyBinding::parse("PageDown"), Ok(KeyBinding::plain(KeyCode::PageDown)));
+        assert_eq!(KeyBinding::parse("F5"), Ok(KeyBinding::plain(KeyCode::F(5))));
+        assert_eq!(KeyBinding::parse("-"), Ok(KeyBinding::char('-')));
+        assert_eq!(KeyBinding::parse("C--"), Ok(KeyBinding::ctrl('-')));
+        assert!(KeyBinding::parse("").is_err());
+        assert!(KeyBinding::parse("nope").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for key in ["j", "C-n", "M-x", "Enter", "Del", "PgDn", "F5"] {
+            let parsed = KeyBinding::parse(key).unwrap();
+            assert_eq!(KeyBinding::parse(&parsed.to_string()), Ok(parsed), "{}", key);
+        }
+    }
+
+    #[test]
+    fn test_shift_is_ignored_for_characters() {
+        let keymap = Keymap::default_preset();
+        assert_eq!(keymap.action_for(&event(KeyCode::Char('R'), KeyModifiers::SHIFT)), Some(Action::Refresh));
+        assert_eq!(keymap.action_for(&event(KeyCode::Char('R'), KeyModifiers::NONE)), Some(Action::Refresh));
+        // Control still matters
+        assert_eq!(keymap.action_for(&event(KeyCode::Char('j'), KeyModifiers::CONTROL)), None);
+    }
+
+    #[test]
+    fn test_presets() {
+        let vim = Keymap::preset("vim").unwrap();
+        assert_eq!(vim.action_for(&event(KeyCode::Char('f'), KeyModifiers::CONTROL)), Some(Action::NextPage));
+        assert_eq!(vim.action_for(&event(KeyCode::Char('j'), KeyModifiers::NONE)), Some(Action::Down));
+
+        let emacs = Keymap::preset("emacs").unwrap();
+        assert_eq!(emacs.action_for(&event(KeyCode::Char('n'), KeyModifiers::CONTROL)), Some(Action::Down));
+        assert_eq!(emacs.action_for(&event(KeyCode::Char('x'), KeyModifiers::ALT)), Some(Action::Command));
+        assert_eq!(emacs.action_for(&event(KeyCode::Char('j'), KeyModifiers::NONE)), None);
+
+        assert!(Keymap::preset("nano").is_none());
+    }
+
+    #[test]
+    fn test_every_action_is_bound_in_presets() {
+        for name in ["default", "vim", "emacs"] {
+            let keymap = Keymap::preset(name).unwrap();
+            for action in Action::ALL {
+                assert!(!keymap.keys_for(action).is_empty(), "{} has no key for {}", name, action.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_bind_replaces_and_steals_keys() {
+        let mut keymap = Keymap::default_preset();
+        keymap.bind(Action::Quit, vec![KeyBinding::char('x'), KeyBinding::char('j')]);
+
+        assert_eq!(keymap.label(Action::Quit), "x/j");
+        assert_eq!(keymap.action_for(&event(KeyCode::Char('q'), KeyModifiers::NONE)), None);
+        // `j` moved from Down to Quit
+        assert_eq!(keymap.label(Action::Down), "↓");
+    }
+
+    #[test]
+    fn test_action_names_round_trip() {
+        for action in Action::ALL {
+            assert_eq!(Action::from_name(action.name()), Some(action));
+        }
+        assert_eq!(Action::from_name("fly"), None);
+    }
+}
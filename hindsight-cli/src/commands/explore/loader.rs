@@ -0,0 +1,410 @@
+//! Background data loading for the explorer.
+//!
+//! Every API call runs on its own thread and reports back over a single
+//! channel, so the UI thread never blocks on the network. Each request is
+//! tagged with an id; only the latest read per [`Resource`] is accepted,
+//! which lets navigation cancel in-flight loads simply by forgetting them.
+//! Mutations are tracked by id alone: every one of them is reported, however
+//! many run at once.
+//!
+//! Successful list loads are cached per key (bank id, page) so revisiting a
+//! view renders the last known data immediately while it is revalidated in the
+//! background (stale-while-revalidate).
+
+use anyhow::Result;
+use hindsight_client::types::{
+    BankListItem, ConsolidationResponse, EntityListItem, RecallResult, ReflectFact, ReflectTrace,
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+use super::document::DocumentDetail;
+use crate::api::{AgentStats, MemoryPutResult, Operation};
+
+/// What a background request loads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    Banks,
+    Memories,
+    Entities,
+    Documents,
+    Operations,
+    /// Bank statistics for the dashboard
+    Stats,
+    /// Full document for the detail view
+    Document,
+    /// Recall/reflect query
+    Query,
+    /// Reflect half of a Compare query, running alongside `Query`
+    Reflect,
+    /// Retaining a memory from the compose form
+    Retain,
+    /// Triggering consolidation of a bank
+    Consolidate,
+    /// Deleting a document
+    DeleteDocument,
+}
+
+impl Resource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Resource::Banks => "banks",
+            Resource::Memories => "memories",
+            Resource::Entities => "entities",
+            Resource::Documents => "documents",
+            Resource::Operations => "operations",
+            Resource::Stats => "stats",
+            Resource::Document => "document",
+            Resource::Query => "query",
+            Resource::Reflect => "reflect",
+            Resource::Retain => "retain",
+            Resource::Consolidate => "consolidate",
+            Resource::DeleteDocument => "delete",
+        }
+    }
+
+    /// List resources back a browsable view and are cached for revalidation
+    pub fn is_list(&self) -> bool {
+        matches!(
+            self,
+            Resource::Banks
+                | Resource::Memories
+                | Resource::Entities
+                | Resource::Documents
+                | Resource::Operations
+                | Resource::Stats
+        )
+    }
+
+    /// Mutations are never cancelled, superseded or deduplicated
+    pub fn is_mutation(&self) -> bool {
+        matches!(self, Resource::Retain | Resource::Consolidate | Resource::DeleteDocument)
+    }
+}
+
+/// Data returned by a background request
+#[derive(Debug, Clone)]
+pub enum Payload {
+    Banks(Vec<BankListItem>),
+    Memories(Vec<Map<String, Value>>),
+    Entities(Vec<EntityListItem>),
+    Documents(Vec<Map<String, Value>>),
+    Operations(Vec<Operation>),
+    Stats(AgentStats),
+    Document(Box<DocumentDetail>),
+    Recall { results: Vec<RecallResult>, trace: Option<Map<String, Value>> },
+    Reflect { text: String, based_on: Vec<ReflectFact>, trace: Option<ReflectTrace> },
+    DocumentDeleted(String),
+    Consolidated(ConsolidationResponse),
+    Retained { document_id: String, result: MemoryPutResult },
+}
+
+/// Completed request delivered to the UI thread
+pub struct Loaded {
+    /// Request id (as returned by [`Loader::mutate`])
+    pub id: u64,
+    pub resource: Resource,
+    pub key: String,
+    pub result: Result<Payload, String>,
+}
+
+struct Pending {
+    id: u64,
+    resource: Resource,
+    key: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+/// Cached payload and when it was fetched
+struct CacheEntry {
+    payload: Payload,
+    fetched_at: Instant,
+}
+
+pub struct Loader {
+    tx: Sender<Loaded>,
+    rx: Receiver<Loaded>,
+    next_id: u64,
+    /// Latest read per resource
+    pending: HashMap<Resource, Pending>,
+    /// Mutations in flight, by id
+    mutations: HashMap<u64, Pending>,
+    cache: HashMap<(Resource, String), CacheEntry>,
+}
+
+impl Loader {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel();
+        Self {
+            tx,
+            rx,
+            next_id: 0,
+            pending: HashMap::new(),
+            mutations: HashMap::new(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Run `fetch` on a background thread.
+    ///
+    /// A newer read of the same resource supersedes (cancels) the older one.
+    /// If an identical read is already in flight, this is a no-op so
+    /// auto-refresh never piles up requests behind a slow server.
+    pub fn spawn<F>(&mut self, resource: Resource, key: &str, fetch: F)
+    where
+        F: FnOnce() -> Result<Payload> + Send + 'static,
+    {
+        debug_assert!(!resource.is_mutation(), "mutations go through Loader::mutate");
+        if self.pending.get(&resource).map(|p| p.key == key).unwrap_or(false) {
+            return;
+        }
+        self.cancel(resource);
+
+        let pending = self.start(resource, key, fetch);
+        self.pending.insert(resource, pending);
+    }
+
+    /// Run a mutation on a background thread and return its request id.
+    ///
+    /// Unlike reads, mutations are never cancelled, superseded or
+    /// deduplicated: each one is reported, tagged with the returned id.
+    pub fn mutate<F>(&mut self, resource: Resource, key: &str, fetch: F) -> u64
+    where
+        F: FnOnce() -> Result<Payload> + Send + 'static,
+    {
+        debug_assert!(resource.is_mutation(), "reads go through Loader::spawn");
+        let pending = self.start(resource, key, fetch);
+        let id = pending.id;
+        self.mutations.insert(id, pending);
+        id
+    }
+
+    fn start<F>(&mut self, resource: Resource, key: &str, fetch: F) -> Pending
+    where
+        F: FnOnce() -> Result<Payload> + Send + 'static,
+    {
+        self.next_id += 1;
+        let id = self.next_id;
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let tx = self.tx.clone();
+        let flag = cancelled.clone();
+        let owned_key = key.to_string();
+        thread::spawn(move || {
+            let result = fetch().map_err(|e| e.to_string());
+            // The request can't be aborted mid-flight, but a cancelled one is never reported
+            if !flag.load(Ordering::Relaxed) {
+                let _ = tx.send(Loaded { id, resource, key: owned_key, result });
+            }
+        });
+        Pending { id, resource, key: key.to_string(), cancelled }
+    }
+
+    /// Forget the in-flight read of `resource`; its result will be dropped
+    pub fn cancel(&mut self, resource: Resource) {
+        if let Some(pending) = self.pending.remove(&resource) {
+            pending.cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Cancel every read except `keep` (used when navigating away)
+    pub fn cancel_all_except(&mut self, keep: &[Resource]) {
+        let resources: Vec<Resource> = self
+            .pending
+            .keys()
+            .copied()
+            .filter(|r| !keep.contains(r))
+            .collect();
+        for resource in resources {
+            self.cancel(resource);
+        }
+    }
+
+    pub fn is_loading(&self, resource: Resource) -> bool {
+        if resource.is_mutation() {
+            self.mutations.values().any(|p| p.resource == resource)
+        } else {
+            self.pending.contains_key(&resource)
+        }
+    }
+
+    /// True when no request is in flight
+    #[cfg(test)]
+    pub fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.mutations.is_empty()
+    }
+
+    /// Collect finished requests, discarding superseded or cancelled ones
+    pub fn poll(&mut self) -> Vec<Loaded> {
+        let mut done = Vec::new();
+        while let Ok(loaded) = self.rx.try_recv() {
+            if self.mutations.remove(&loaded.id).is_some() {
+                done.push(loaded);
+                continue;
+            }
+            let resource = loaded.resource;
+            let current = self.pending.get(&resource).map(|p| p.id == loaded.id).unwrap_or(false);
+            if !current {
+                continue;
+            }
+            self.pending.remove(&resource);
+
+            if resource.is_list() {
+                if let Ok(payload) = &loaded.result {
+                    self.cache.insert(
+                        (resource, loaded.key.clone()),
+                        CacheEntry { payload: payload.clone(), fetched_at: Instant::now() },
+                    );
+                }
+            }
+            done.push(loaded);
+        }
+        done
+    }
+
+    /// Last successful payload for `resource` and `key`, with its fetch time
+    pub fn cached(&self, resource: Resource, key: &str) -> Option<(&Payload, Instant)> {
+        self.cache
+            .get(&(resource, key.to_string()))
+            .map(|entry| (&entry.payload, entry.fetched_at))
+    }
+
+    /// Drop cached data for `resource` (e.g. after a mutation invalidates it)
+    pub fn invalidate(&mut self, resource: Resource) {
+        self.cache.retain(|(r, _), _| *r != resource);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// Poll until `count` results arrive (or give up after a second)
+    fn wait_for(loader: &mut Loader, count: usize) -> Vec<Loaded> {
+        let mut results = Vec::new();
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while results.len() < count && Instant::now() < deadline {
+            results.extend(loader.poll());
+            thread::sleep(Duration::from_millis(5));
+        }
+        results
+    }
+
+    #[test]
+    fn test_spawn_delivers_result() {
+        let mut loader = Loader::new();
+        loader.spawn(Resource::Banks, "", || Ok(Payload::Banks(Vec::new())));
+        assert!(loader.is_loading(Resource::Banks));
+
+        let results = wait_for(&mut loader, 1);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].resource, Resource::Banks);
+        assert!(matches!(results[0].result, Ok(Payload::Banks(_))));
+        assert!(!loader.is_loading(Resource::Banks));
+    }
+
+    #[test]
+    fn test_errors_are_reported_as_strings() {
+        let mut loader = Loader::new();
+        loader.spawn(Resource::Entities, "bank", || anyhow::bail!("boom"));
+        let results = wait_for(&mut loader, 1);
+        assert_eq!(results[0].result.as_ref().unwrap_err(), "boom");
+        assert!(loader.cached(Resource::Entities, "bank").is_none());
+    }
+
+    #[test]
+    fn test_newer_request_supersedes_older() {
+        let mut loader = Loader::new();
+        loader.spawn(Resource::Memories, "a", || {
+            thread::sleep(Duration::from_millis(50));
+            Ok(Payload::DocumentDeleted("old".to_string()))
+        });
+        loader.spawn(Resource::Memories, "b", || Ok(Payload::DocumentDeleted("new".to_string())));
+
+        let results = wait_for(&mut loader, 1);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key, "b");
+
+        // The superseded result never shows up
+        thread::sleep(Duration::from_millis(80));
+        assert!(loader.poll().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_request_is_not_respawned() {
+        let mut loader = Loader::new();
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        for _ in 0..3 {
+            let calls = calls.clone();
+            loader.spawn(Resource::Operations, "bank", move || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(20));
+                Ok(Payload::Operations(Vec::new()))
+            });
+        }
+        wait_for(&mut loader, 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_cancel_all_except_keeps_mutations() {
+        let mut loader = Loader::new();
+        let slow = || {
+            thread::sleep(Duration::from_millis(20));
+            Ok(Payload::DocumentDeleted("doc".to_string()))
+        };
+        loader.spawn(Resource::Documents, "bank", slow);
+        loader.spawn(Resource::Query, "bank", slow);
+        loader.spawn(Resource::Reflect, "bank", slow);
+        loader.mutate(Resource::DeleteDocument, "doc", slow);
+
+        loader.cancel_all_except(&[Resource::Query, Resource::Reflect]);
+        assert!(!loader.is_loading(Resource::Documents));
+        assert!(loader.is_loading(Resource::Query));
+        assert!(loader.is_loading(Resource::Reflect));
+        assert!(loader.is_loading(Resource::DeleteDocument));
+
+        let results = wait_for(&mut loader, 3);
+        let mut resources: Vec<Resource> = results.iter().map(|r| r.resource).collect();
+        resources.sort_by_key(|r| r.label());
+        assert_eq!(resources, vec![Resource::DeleteDocument, Resource::Query, Resource::Reflect]);
+    }
+
+    #[test]
+    fn test_mutations_are_never_superseded_or_deduplicated() {
+        let mut loader = Loader::new();
+        let first = loader.mutate(Resource::Retain, "doc", || Ok(Payload::DocumentDeleted("first".to_string())));
+        let second = loader.mutate(Resource::Retain, "doc", || Ok(Payload::DocumentDeleted("second".to_string())));
+        loader.mutate(Resource::Consolidate, "bank", || anyhow::bail!("boom"));
+        assert_ne!(first, second);
+
+        let results = wait_for(&mut loader, 3);
+        let mut ids: Vec<u64> = results.iter().filter(|r| r.resource == Resource::Retain).map(|r| r.id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![first, second]);
+        assert!(results.iter().any(|r| r.resource == Resource::Consolidate && r.result.is_err()));
+        assert!(loader.is_idle());
+    }
+
+    #[test]
+    fn test_successful_loads_are_cached_per_key() {
+        let mut loader = Loader::new();
+        loader.spawn(Resource::Documents, "bank-a", || Ok(Payload::Documents(vec![Map::new()])));
+        wait_for(&mut loader, 1);
+
+        match loader.cached(Resource::Documents, "bank-a") {
+            Some((Payload::Documents(docs), _)) => assert_eq!(docs.len(), 1),
+            _ => panic!("expected cached documents"),
+        }
+        assert!(loader.cached(Resource::Documents, "bank-b").is_none());
+
+        loader.invalidate(Resource::Documents);
+        assert!(loader.cached(Resource::Documents, "bank-a").is_none());
+    }
+}
@@ -0,0 +1,3728 @@
+mod backend;
+mod clipboard;
+mod compose;
+mod document;
+mod export;
+mod filter;
+mod history;
+mod keymap;
+mod loader;
+mod palette;
+mod settings;
+mod theme;
+mod trace_view;
+
+use crate::api::{AgentStats, ApiClient, Operation, RecallRequest, ReflectRequest};
+use crate::trace;
+use anyhow::Result;
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use hindsight_client::types::{
+    BankListItem, Budget, EntityListItem, FactsIncludeOptions, RecallResult, ReflectFact, ReflectIncludeOptions, ReflectTrace,
+    TagsMatch, ToolCallsIncludeOptions,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{BarChart, Block, Borders, Gauge, List, ListItem, ListState, Paragraph, Sparkline, Wrap},
+    Frame, Terminal,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use backend::ExploreApi;
+use clipboard::{Selection, YankPart};
+use compose::{ComposeForm, Draft, Field};
+use document::{DocLine, DocumentView, Pane};
+use export::Export;
+use history::{Comparison, QuerySettings, QueryStore, StoredResults};
+use keymap::{Action, Keymap};
+use loader::{Loaded, Loader, Payload, Resource};
+use palette::PaletteCommand;
+use settings::Settings;
+use theme::Theme;
+use trace_view::TraceTree;
+
+/// Main view types (like k9s contexts)
+#[derive(Debug, Clone, PartialEq)]
+enum View {
+    Banks,
+    Dashboard(String), // bank_id - stats overview
+    Memories(String),  // bank_id
+    Entities(String),  // bank_id
+    Documents(String), // bank_id
+    Operations(String), // bank_id
+    Query(String),     // bank_id - combines recall and reflect
+    Saved(String),     // bank_id - saved queries
+}
+
+impl View {
+    fn title(&self) -> &str {
+        match self {
+            View::Banks => "Banks",
+            View::Dashboard(_) => "Dashboard",
+            View::Memories(_) => "Memories",
+            View::Entities(_) => "Entities",
+            View::Documents(_) => "Documents",
+            View::Operations(_) => "Operations",
+            View::Query(_) => "Query",
+            View::Saved(_) => "Saved Queries",
+        }
+    }
+
+    fn bank_id(&self) -> Option<&str> {
+        match self {
+            View::Banks => None,
+            View::Dashboard(id)
+            | View::Memories(id)
+            | View::Entities(id)
+            | View::Documents(id)
+            | View::Operations(id)
+            | View::Query(id)
+            | View::Saved(id) => Some(id),
+        }
+    }
+}
+
+/// Views reachable from a bank's dashboard, in display order
+const DASHBOARD_LINKS: [&str; 6] = ["Memories", "Entities", "Documents", "Operations", "Query", "Saved Queries"];
+
+/// Pending-operation samples kept per bank for the dashboard sparkline
+const MAX_PENDING_SAMPLES: usize = 60;
+
+fn dashboard_link(index: usize, bank_id: String) -> Option<View> {
+    match index {
+        0 => Some(View::Memories(bank_id)),
+        1 => Some(View::Entities(bank_id)),
+        2 => Some(View::Documents(bank_id)),
+        3 => Some(View::Operations(bank_id)),
+        4 => Some(View::Query(bank_id)),
+        5 => Some(View::Saved(bank_id)),
+        _ => None,
+    }
+}
+
+/// Query mode for the Query view
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum QueryMode {
+    Recall,
+    Reflect,
+    Compare, // recall and reflect side by side
+}
+
+impl QueryMode {
+    fn label(&self) -> &'static str {
+        match self {
+            QueryMode::Recall => "Recall",
+            QueryMode::Reflect => "Reflect",
+            QueryMode::Compare => "Compare",
+        }
+    }
+}
+
+/// Input mode for recall/reflect queries
+#[derive(Debug, Clone, PartialEq)]
+enum InputMode {
+    Normal,
+    Query,
+    Filter,  // `/` incremental fuzzy filter over the current list
+    Command, // `:` command palette
+    Compose, // retain form
+    Search,  // `/` search inside an open document
+    Yank,    // waiting for which part of the selection to copy
+}
+
+/// What the event loop does after a key press
+#[derive(Debug, PartialEq)]
+enum KeyOutcome {
+    Continue,
+    Quit,
+    /// Suspend the TUI and open the compose draft in an external editor
+    EditExternally,
+}
+
+/// Application state
+struct App {
+    client: Arc<dyn ExploreApi>,
+    view: View,
+    view_history: Vec<View>,
+
+    // List states
+    banks: Vec<BankListItem>,
+    banks_state: ListState,
+    selected_bank_id: Option<String>,
+
+    // Dashboard: stats of the current bank and pending operations at each refresh
+    stats: Option<AgentStats>,
+    dashboard_state: ListState,
+    pending_samples: HashMap<String, VecDeque<u64>>,
+
+    memories: Vec<Map<String, Value>>,
+    memories_state: ListState,
+    viewing_memory: Option<Map<String, Value>>,
+    memories_limit: i64,
+    memories_offset: i64,
+    horizontal_scroll: usize,
+
+    entities: Vec<EntityListItem>,
+    entities_state: ListState,
+    viewing_entity: Option<EntityListItem>,
+
+    documents: Vec<Map<String, Value>>,
+    documents_state: ListState,
+    viewing_document: Option<DocumentView>,
+
+    operations: Vec<Operation>,
+    operations_state: ListState,
+
+    // Fuzzy filter applied to the current list (cleared on view change)
+    filter_text: String,
+
+    // Command palette input and Tab-completion cycle
+    command_text: String,
+    command_completions: Vec<String>,
+    command_completion_index: usize,
+
+    // Query state (unified recall/reflect)
+    query_mode: QueryMode,
+    query_text: String,
+    query_budget: Budget,
+    query_max_tokens: i64,
+    query_results: Vec<RecallResult>,
+    query_results_state: ListState,
+    query_response: String,
+    viewing_recall_result: Option<RecallResult>,
+    query_tags: Vec<String>,
+    reflect_budget: Option<Budget>,    // reflect side in Compare mode; None = same as query_budget
+    reflect_based_on: Vec<ReflectFact>, // memories the Compare reflect answer cites
+    // Trace tree of the last query; queries only ask for traces while it is shown
+    show_trace: bool,
+    recall_trace: Option<Map<String, Value>>,
+    reflect_trace: Option<ReflectTrace>,
+    trace_tree: TraceTree,
+
+    // Text to copy, written to the terminal as OSC 52 by the event loop
+    clipboard: Option<String>,
+
+    // Query history / saved queries (~/.hindsight/queries.json)
+    query_store: QueryStore,
+    history_index: Option<usize>, // position while browsing history with ↑/↓
+    history_draft: String,        // query being typed before browsing history
+    saved_state: ListState,
+    last_run: Option<QuerySettings>,   // settings that produced the current results
+    running_saved: Option<String>,     // saved query whose run is in flight
+    comparison: Option<Comparison>,    // re-run of a saved query vs its stored results
+
+    // Retain form, submitted forms kept (by request id) until their retain succeeds,
+    // and drafts of failed retains waiting for the open form to close
+    compose: Option<ComposeForm>,
+    compose_pending: HashMap<u64, ComposeForm>,
+    compose_failed: Vec<ComposeForm>,
+    focus_operation: Option<String>, // operation to select once the Operations list loads
+
+    // Input mode
+    input_mode: InputMode,
+
+    // Status messages
+    status_message: String,
+    error_message: String,
+
+    // Help visibility
+    show_help: bool,
+
+    // User keymap and colours (~/.hindsight/explore.toml)
+    keymap: Keymap,
+    theme: Theme,
+
+    // Background loads, and the key (bank, page) of the data each list currently shows
+    loader: Loader,
+    loaded_keys: HashMap<Resource, String>,
+
+    // Auto-refresh
+    auto_refresh_enabled: bool,
+    last_refresh: Instant,
+    refresh_interval: Duration,
+}
+
+impl App {
+    fn new(client: Arc<dyn ExploreApi>, settings: Settings) -> Self {
+        let mut app = Self {
+            client,
+            view: View::Banks,
+            view_history: Vec::new(),
+
+            banks: Vec::new(),
+            banks_state: ListState::default(),
+            selected_bank_id: None,
+
+            stats: None,
+            dashboard_state: ListState::default(),
+            pending_samples: HashMap::new(),
+
+            memories: Vec::new(),
+            memories_state: ListState::default(),
+            viewing_memory: None,
+            memories_limit: 500,
+            memories_offset: 0,
+            horizontal_scroll: 0,
+
+            entities: Vec::new(),
+            entities_state: ListState::default(),
+            viewing_entity: None,
+
+            documents: Vec::new(),
+            documents_state: ListState::default(),
+            viewing_document: None,
+
+            operations: Vec::new(),
+            operations_state: ListState::default(),
+
+            filter_text: String::new(),
+
+            command_text: String::new(),
+            command_completions: Vec::new(),
+            command_completion_index: 0,
+
+            query_mode: QueryMode::Recall,
+            query_text: String::new(),
+            query_budget: Budget::Mid,
+            query_max_tokens: 4096,
+            query_results: Vec::new(),
+            query_results_state: ListState::default(),
+            query_response: String::new(),
+            viewing_recall_result: None,
+            query_tags: Vec::new(),
+            reflect_budget: None,
+            reflect_based_on: Vec::new(),
+            show_trace: false,
+            recall_trace: None,
+            reflect_trace: None,
+            trace_tree: TraceTree::default(),
+            clipboard: None,
+
+            query_store: QueryStore::default(),
+            history_index: None,
+            history_draft: String::new(),
+            saved_state: ListState::default(),
+            last_run: None,
+            running_saved: None,
+            comparison: None,
+
+            compose: None,
+            compose_pending: HashMap::new(),
+            compose_failed: Vec::new(),
+            focus_operation: None,
+
+            input_mode: InputMode::Normal,
+            status_message: String::from("Select a bank to start. Press / to filter, : for commands, ? for help"),
+            error_message: String::new(),
+            show_help: false,
+
+            keymap: settings.keymap,
+            theme: settings.theme,
+
+            loader: Loader::new(),
+            loaded_keys: HashMap::new(),
+
+            auto_refresh_enabled: true,
+            last_refresh: Instant::now(),
+            refresh_interval: Duration::from_secs(5),
+        };
+
+        // Select first item by default
+        app.banks_state.select(Some(0));
+        app.dashboard_state.select(Some(0));
+        app.memories_state.select(Some(0));
+        app.entities_state.select(Some(0));
+        app.documents_state.select(Some(0));
+        app.operations_state.select(Some(0));
+        app.query_results_state.select(Some(0));
+        app.saved_state.select(Some(0));
+
+        app
+    }
+
+    /// List resource and cache key backing the current view (`None` for Query)
+    fn view_resource(&self) -> Option<(Resource, String)> {
+        match &self.view {
+            View::Banks => Some((Resource::Banks, String::new())),
+            View::Dashboard(bank_id) => Some((Resource::Stats, bank_id.clone())),
+            View::Memories(bank_id) => Some((Resource::Memories, format!("{}@{}", bank_id, self.memories_offset))),
+            View::Entities(bank_id) => Some((Resource::Entities, bank_id.clone())),
+            View::Documents(bank_id) => Some((Resource::Documents, bank_id.clone())),
+            View::Operations(bank_id) => Some((Resource::Operations, bank_id.clone())),
+            View::Query(_) | View::Saved(_) => None,
+        }
+    }
+
+    /// Whether the current list shows data for the current view (possibly stale)
+    fn has_view_data(&self) -> bool {
+        match self.view_resource() {
+            Some((resource, key)) => self.loaded_keys.get(&resource) == Some(&key),
+            None => true,
+        }
+    }
+
+    /// True while the current list is loading with nothing (not even stale data) to show
+    fn awaiting_first_load(&self) -> bool {
+        match self.view_resource() {
+            Some((resource, _)) => self.loader.is_loading(resource) && !self.has_view_data(),
+            None => false,
+        }
+    }
+
+    /// Resource whose load the footer should report, if any.
+    ///
+    /// Revalidating a list that is already on screen is only shown in its title.
+    fn active_load(&self) -> Option<Resource> {
+        let mut candidates: Vec<Resource> = match &self.view {
+            View::Query(_) => vec![Resource::Query, Resource::Reflect],
+            _ if self.awaiting_first_load() => self.view_resource().map(|(resource, _)| resource).into_iter().collect(),
+            _ => Vec::new(),
+        };
+        candidates.extend([Resource::Document, Resource::Retain, Resource::Consolidate, Resource::DeleteDocument]);
+        candidates.into_iter().find(|resource| self.loader.is_loading(*resource))
+    }
+
+    /// Start a background load of the current view.
+    ///
+    /// Data already on screen stays visible until the new data arrives.
+    fn refresh(&mut self) {
+        self.error_message.clear();
+
+        let Some((resource, key)) = self.view_resource() else {
+            return; // Query is query-driven
+        };
+        let client = self.client.clone();
+
+        match self.view.clone() {
+            View::Banks => self.loader.spawn(resource, &key, move || {
+                Ok(Payload::Banks(client.list_banks()?))
+            }),
+            View::Dashboard(bank_id) => self.loader.spawn(resource, &key, move || {
+                Ok(Payload::Stats(client.get_stats(&bank_id)?))
+            }),
+            View::Memories(bank_id) => {
+                let (limit, offset) = (self.memories_limit, self.memories_offset);
+                self.loader.spawn(resource, &key, move || {
+                    let response = client.list_memories(&bank_id, limit, offset)?;
+                    Ok(Payload::Memories(response.items))
+                })
+            }
+            View::Entities(bank_id) => self.loader.spawn(resource, &key, move || {
+                let response = client.list_entities(&bank_id, 100)?;
+                Ok(Payload::Entities(response.items))
+            }),
+            View::Documents(bank_id) => self.loader.spawn(resource, &key, move || {
+                let response = client.list_documents(&bank_id, 100, 0)?;
+                Ok(Payload::Documents(response.items))
+            }),
+            View::Operations(bank_id) => self.loader.spawn(resource, &key, move || {
+                let response = client.list_operations(&bank_id)?;
+                Ok(Payload::Operations(response.operations))
+            }),
+            View::Query(_) | View::Saved(_) => {}
+        }
+    }
+
+    /// Called after `self.view` changes: cancel loads for the old view, show
+    /// cached data for the new one (if any) and revalidate it.
+    fn on_view_changed(&mut self) {
+        let keep: Vec<Resource> = match &self.view {
+            View::Query(_) => vec![Resource::Query, Resource::Reflect],
+            _ => self.view_resource().map(|(resource, _)| resource).into_iter().collect(),
+        };
+        self.loader.cancel_all_except(&keep);
+        self.filter_text.clear();
+        self.restore_view_data();
+        self.refresh();
+    }
+
+    /// Swap in cached data for the current view, or clear data that belongs
+    /// to another bank/page so it is never shown under the wrong title.
+    fn restore_view_data(&mut self) {
+        let Some((resource, key)) = self.view_resource() else {
+            return;
+        };
+        if self.loaded_keys.get(&resource) == Some(&key) {
+            return;
+        }
+
+        match self.loader.cached(resource, &key).map(|(payload, _)| payload.clone()) {
+            Some(payload) => self.apply_list(key, payload),
+            None => {
+                match resource {
+                    Resource::Banks => self.banks.clear(),
+                    Resource::Stats => self.stats = None,
+                    Resource::Memories => self.memories.clear(),
+                    Resource::Entities => self.entities.clear(),
+                    Resource::Documents => self.documents.clear(),
+                    Resource::Operations => self.operations.clear(),
+                    _ => {}
+                }
+                self.loaded_keys.remove(&resource);
+            }
+        }
+        self.current_list_state().select(Some(0));
+    }
+
+    /// Store a list payload as the data for `key`
+    fn apply_list(&mut self, key: String, payload: Payload) {
+        let (resource, len) = match payload {
+            Payload::Banks(banks) => {
+                self.banks = banks;
+                (Resource::Banks, self.banks.len())
+            }
+            Payload::Memories(memories) => {
+                self.memories = memories;
+                (Resource::Memories, self.memories.len())
+            }
+            Payload::Entities(entities) => {
+                self.entities = entities;
+                (Resource::Entities, self.entities.len())
+            }
+            Payload::Documents(documents) => {
+                self.documents = documents;
+                (Resource::Documents, self.documents.len())
+            }
+            Payload::Operations(operations) => {
+                self.operations = operations;
+                (Resource::Operations, self.operations.len())
+            }
+            Payload::Stats(stats) => {
+                self.stats = Some(stats);
+                (Resource::Stats, DASHBOARD_LINKS.len())
+            }
+            _ => return,
+        };
+        self.loaded_keys.insert(resource, key);
+
+        let state = match resource {
+            Resource::Banks => &mut self.banks_state,
+            Resource::Stats => &mut self.dashboard_state,
+            Resource::Memories => &mut self.memories_state,
+            Resource::Entities => &mut self.entities_state,
+            Resource::Documents => &mut self.documents_state,
+            _ => &mut self.operations_state,
+        };
+        if len > 0 && state.selected().is_none() {
+            state.select(Some(0));
+        }
+
+        // Point at the operation a retain just queued
+        if resource == Resource::Operations {
+            if let Some(operation_id) = self.focus_operation.take() {
+                match self.operations.iter().position(|op| op.id == operation_id) {
+                    Some(i) => self.operations_state.select(Some(i)),
+                    None => self.focus_operation = Some(operation_id),
+                }
+            }
+        }
+    }
+
+    /// Apply results of finished background loads
+    fn poll_loads(&mut self) {
+        for Loaded { id, resource, key, result } in self.loader.poll() {
+            let payload = match result {
+                Ok(payload) => payload,
+                Err(e) => {
+                    if resource == Resource::Query {
+                        self.running_saved = None;
+                    }
+                    // Give the draft back so nothing typed is lost
+                    if let Some(form) = self.compose_pending.remove(&id) {
+                        self.compose_failed.push(form);
+                        self.restore_failed_compose();
+                    }
+                    self.error_message = match resource {
+                        Resource::Query if key.starts_with("reflect:") => format!("Reflect failed: {}", e),
+                        Resource::Query => format!("Recall failed: {}", e),
+                        Resource::Reflect => format!("Reflect failed: {}", e),
+                        Resource::Document => format!("Failed to load document: {}", e),
+                        Resource::Consolidate => format!("Consolidation failed: {}", e),
+                        Resource::Retain => format!("Retain failed for document {}: {}", key, e),
+                        Resource::DeleteDocument => format!("Failed to delete document {}: {}", key, e),
+                        _ => format!("Error: {}", e),
+                    };
+                    continue;
+                }
+            };
+
+            match payload {
+                Payload::Recall { results, trace } => {
+                    self.query_results = results;
+                    self.recall_trace = trace;
+                    self.rebuild_trace_tree();
+                    if !self.query_results.is_empty() {
+                        self.query_results_state.select(Some(0));
+                    }
+                    self.status_message = format!("Found {} results", self.query_results.len());
+                    self.finish_saved_run(StoredResults::recall(&self.query_results));
+                    self.report_compare();
+                }
+                Payload::Reflect { text, based_on, trace } => {
+                    self.query_response = text;
+                    self.reflect_based_on = based_on;
+                    self.reflect_trace = trace;
+                    self.rebuild_trace_tree();
+                    self.status_message = "Reflection complete".to_string();
+                    // Saved Compare queries are tracked by their recall side
+                    if resource == Resource::Query {
+                        self.finish_saved_run(StoredResults::reflect(&self.query_response));
+                    }
+                    self.report_compare();
+                }
+                Payload::Document(detail) => {
+                    self.status_message = format!(
+                        "Viewing document {}: {} chunks, {} memory units",
+                        detail.document.id,
+                        detail.chunks.len(),
+                        detail.units.len()
+                    );
+                    self.viewing_document = Some(DocumentView::new(*detail));
+                }
+                Payload::DocumentDeleted(doc_id) => {
+                    self.status_message = format!("Deleted document: {}", doc_id);
+                    self.loader.invalidate(Resource::Documents);
+                    if matches!(self.view, View::Documents(_)) {
+                        // Don't let an in-flight load that predates the delete win
+                        self.loader.cancel(Resource::Documents);
+                        self.refresh();
+                    }
+                }
+                Payload::Retained { document_id, result } => {
+                    self.compose_pending.remove(&id);
+                    for resource in [Resource::Memories, Resource::Documents, Resource::Stats, Resource::Operations] {
+                        self.loader.invalidate(resource);
+                    }
+                    match (result.is_async, result.operation_id) {
+                        (true, Some(operation_id)) => {
+                            // Show the queued operation
+                            self.status_message = format!("Retain queued as operation {} (document {})", operation_id, document_id);
+                            if let Some(bank_id) = self.view.bank_id().map(str::to_string) {
+                                self.focus_operation = Some(operation_id);
+                                self.switch_to_view(View::Operations(bank_id));
+                                self.refresh();
+                            }
+                        }
+                        _ => {
+                            self.status_message = format!("Retained {} memory units into document {}", result.items_count, document_id);
+                            self.refresh();
+                        }
+                    }
+                }
+                Payload::Consolidated(response) => {
+                    self.status_message = if response.deduplicated {
+                        format!("Consolidation already pending (operation {})", response.operation_id)
+                    } else {
+                        format!("Consolidation started (operation {})", response.operation_id)
+                    };
+                    self.loader.invalidate(Resource::Operations);
+                    if matches!(self.view, View::Dashboard(_) | View::Operations(_)) {
+                        self.refresh();
+                    }
+                }
+                list => {
+                    // Only fresh loads (not cached revisits) add a sparkline sample
+                    if let Payload::Stats(stats) = &list {
+                        self.record_pending_sample(&key, stats.pending_operations);
+                    }
+                    // Cancellation guarantees list results belong to the current view
+                    self.apply_list(key, list);
+                    self.status_message = match resource {
+                        Resource::Memories => format!("Loaded {} memories (limit: {}, offset: {})",
+                            self.memories.len(), self.memories_limit, self.memories_offset),
+                        Resource::Banks => format!("Loaded {} banks", self.banks.len()),
+                        Resource::Stats => match &self.stats {
+                            Some(stats) => format!("Loaded stats for {}", stats.bank_id),
+                            None => "Loaded stats".to_string(),
+                        },
+                        Resource::Entities => format!("Loaded {} entities", self.entities.len()),
+                        Resource::Documents => format!("Loaded {} documents", self.documents.len()),
+                        _ => format!("Loaded {} operations", self.operations.len()),
+                    };
+                }
+            }
+        }
+    }
+
+    fn record_pending_sample(&mut self, bank_id: &str, pending: i32) {
+        let samples = self.pending_samples.entry(bank_id.to_string()).or_default();
+        samples.push_back(pending.max(0) as u64);
+        if samples.len() > MAX_PENDING_SAMPLES {
+            samples.pop_front();
+        }
+    }
+
+    /// Open the retain form. While a document is open, the form edits it:
+    /// retaining with the same document ID replaces the document.
+    fn open_compose(&mut self, bank_id: Option<String>) {
+        let Some(bank_id) = self.resolve_bank(bank_id) else {
+            return;
+        };
+        let draft = match (&self.view, &self.viewing_document) {
+            (View::Documents(_), Some(view)) => {
+                let draft = Draft::from_document(&view.detail.document);
+                self.status_message = format!("Editing document {} - retaining replaces it", draft.document_id);
+                draft
+            }
+            _ => {
+                self.status_message = format!("Composing a memory for {}", bank_id);
+                Draft::default()
+            }
+        };
+        self.error_message.clear();
+        self.compose = Some(ComposeForm::new(bank_id, draft));
+        self.input_mode = InputMode::Compose;
+    }
+
+    fn close_compose(&mut self) {
+        self.compose = None;
+        self.input_mode = InputMode::Normal;
+        self.status_message = "Compose cancelled".to_string();
+        self.restore_failed_compose();
+    }
+
+    /// Reopen the draft of a failed retain so nothing typed is lost, once no
+    /// other form is open
+    fn restore_failed_compose(&mut self) {
+        if self.compose.is_some() || self.compose_failed.is_empty() {
+            return;
+        }
+        self.compose = Some(self.compose_failed.remove(0));
+        self.input_mode = InputMode::Compose;
+    }
+
+    /// Validate the form and retain it in the background
+    fn submit_compose(&mut self) {
+        let Some(form) = self.compose.take() else {
+            return;
+        };
+        let (request, document_id) = match form.draft.to_request() {
+            Ok(built) => built,
+            Err(e) => {
+                self.error_message = e;
+                self.compose = Some(form);
+                return;
+            }
+        };
+
+        let client = self.client.clone();
+        let bank_id = form.bank_id.clone();
+        let async_mode = form.draft.async_mode;
+        self.error_message.clear();
+        self.status_message = format!("Retaining into document {}", document_id);
+        self.input_mode = InputMode::Normal;
+        let id = self.loader.mutate(Resource::Retain, &document_id.clone(), move || {
+            let result = client.retain(&bank_id, &request, async_mode)?;
+            Ok(Payload::Retained { document_id, result })
+        });
+        self.compose_pending.insert(id, form);
+        self.restore_failed_compose();
+    }
+
+    /// Trigger consolidation of the current bank in the background
+    fn consolidate(&mut self) {
+        let Some(bank_id) = self.resolve_bank(None) else {
+            return;
+        };
+        let client = self.client.clone();
+        self.status_message = format!("Triggering consolidation for {}", bank_id);
+        self.loader.mutate(Resource::Consolidate, &bank_id.clone(), move || {
+            Ok(Payload::Consolidated(client.trigger_consolidation(&bank_id)?))
+        });
+    }
+
+    fn toggle_auto_refresh(&mut self) {
+        self.auto_refresh_enabled = !self.auto_refresh_enabled;
+        if self.auto_refresh_enabled {
+            self.status_message = "Auto-refresh enabled (5s)".to_string();
+            self.last_refresh = Instant::now();
+        } else {
+            self.status_message = "Auto-refresh disabled".to_string();
+        }
+    }
+
+    fn should_refresh(&self) -> bool {
+        self.auto_refresh_enabled && self.last_refresh.elapsed() >= self.refresh_interval
+    }
+
+    fn do_auto_refresh(&mut self) {
+        if self.should_refresh() {
+            self.last_refresh = Instant::now();
+            self.refresh();
+        }
+    }
+
+    fn load_more_memories(&mut self) {
+        if matches!(self.view, View::Memories(_)) {
+            self.memories_offset += self.memories_limit;
+            self.restore_view_data();
+            self.refresh();
+        }
+    }
+
+    fn load_prev_memories(&mut self) {
+        if matches!(self.view, View::Memories(_)) {
+            self.memories_offset = (self.memories_offset - self.memories_limit).max(0);
+            self.restore_view_data();
+            self.refresh();
+        }
+    }
+
+    /// Settings of the query currently in the editor
+    fn query_settings(&self) -> QuerySettings {
+        QuerySettings {
+            mode: self.query_mode.clone(),
+            query: self.query_text.clone(),
+            budget: self.query_budget,
+            max_tokens: self.query_max_tokens,
+            tags: self.query_tags.clone(),
+            reflect_budget: self.reflect_budget,
+        }
+    }
+
+    fn apply_query_settings(&mut self, settings: &QuerySettings) {
+        self.query_mode = settings.mode.clone();
+        self.query_text = settings.query.clone();
+        self.query_budget = settings.budget;
+        self.query_max_tokens = settings.max_tokens;
+        self.query_tags = settings.tags.clone();
+        self.reflect_budget = settings.reflect_budget;
+    }
+
+    fn save_query_store(&mut self) {
+        if let Err(e) = self.query_store.save() {
+            self.error_message = format!("Failed to save query history: {}", e);
+        }
+    }
+
+    fn execute_query(&mut self) {
+        if let View::Query(bank_id) = &self.view {
+            if self.query_text.is_empty() {
+                self.error_message = "Query cannot be empty".to_string();
+                return;
+            }
+
+            self.error_message.clear();
+            self.input_mode = InputMode::Normal;
+            self.history_index = None;
+            self.running_saved = None;
+            self.comparison = None;
+            self.recall_trace = None;
+            self.reflect_trace = None;
+            self.trace_tree = TraceTree::default();
+
+            let settings = self.query_settings();
+            self.query_store.push_history(bank_id, settings.clone());
+            self.last_run = Some(settings);
+
+            let bank_id = bank_id.clone();
+            match self.query_mode {
+                QueryMode::Recall => self.spawn_recall(&bank_id),
+                QueryMode::Reflect => self.spawn_reflect(&bank_id, Resource::Query, self.query_budget),
+                QueryMode::Compare => {
+                    // Both halves run in parallel; each pane fills in as its result arrives
+                    self.spawn_recall(&bank_id);
+                    let budget = self.reflect_budget.unwrap_or(self.query_budget);
+                    self.spawn_reflect(&bank_id, Resource::Reflect, budget);
+                }
+            }
+            self.save_query_store();
+        }
+    }
+
+    fn query_tags_filter(&self) -> Option<Vec<String>> {
+        if self.query_tags.is_empty() { None } else { Some(self.query_tags.clone()) }
+    }
+
+    fn spawn_recall(&mut self, bank_id: &str) {
+        // Clone data for the background thread
+        let client = self.client.clone();
+        let bank_id = bank_id.to_string();
+        let query_text = self.query_text.clone();
+        let query_budget = self.query_budget;
+        let query_max_tokens = self.query_max_tokens;
+        let tags = self.query_tags_filter();
+        let trace = self.show_trace;
+
+        let key = format!("recall:{}:{:?}:{}:{:?}:{}:{}", bank_id, query_budget, query_max_tokens, tags, trace, query_text);
+        self.loader.spawn(Resource::Query, &key, move || {
+            let request = RecallRequest {
+                query: query_text,
+                types: None,
+                budget: Some(query_budget),
+                max_tokens: query_max_tokens,
+                trace,
+                query_timestamp: None,
+                include: None,
+                tags,
+                tags_match: TagsMatch::Any,
+            };
+            let response = client.recall(&bank_id, &request)?;
+            Ok(Payload::Recall { results: response.results, trace: response.trace })
+        });
+    }
+
+    /// Run reflect as `resource` (`Query` on its own, `Reflect` in Compare mode).
+    /// Compare mode also asks for the facts the answer is based on, and the
+    /// tool calls are traced while the trace tree is shown.
+    fn spawn_reflect(&mut self, bank_id: &str, resource: Resource, budget: Budget) {
+        let client = self.client.clone();
+        let bank_id = bank_id.to_string();
+        let query_text = self.query_text.clone();
+        let tags = self.query_tags_filter();
+        let compare = resource == Resource::Reflect;
+        let trace = self.show_trace;
+        let include = (compare || trace).then(|| ReflectIncludeOptions {
+            facts: compare.then(|| FactsIncludeOptions(Map::new())),
+            tool_calls: trace.then_some(ToolCallsIncludeOptions { output: false }),
+        });
+        if resource == Resource::Reflect {
+            self.reflect_based_on.clear();
+        }
+
+        let key = format!("reflect:{}:{:?}:{:?}:{}:{}", bank_id, budget, tags, trace, query_text);
+        self.loader.spawn(resource, &key, move || {
+            let request = ReflectRequest {
+                query: query_text,
+                budget: Some(budget),
+                context: None,
+                max_tokens: 4096,
+                include,
+                response_schema: None,
+                tags,
+                tags_match: TagsMatch::Any,
+            };
+            let response = client.reflect(&bank_id, &request)?;
+            let based_on = response.based_on.map(|b| b.memories).unwrap_or_default();
+            Ok(Payload::Reflect { text: response.text, based_on, trace: response.trace })
+        });
+    }
+
+    fn rebuild_trace_tree(&mut self) {
+        let roots = self
+            .recall_trace
+            .iter()
+            .map(trace::recall_tree)
+            .chain(self.reflect_trace.iter().map(trace::reflect_tree))
+            .collect();
+        self.trace_tree = TraceTree::new(roots);
+    }
+
+    fn toggle_trace(&mut self) {
+        self.show_trace = !self.show_trace;
+        self.status_message = if !self.show_trace {
+            "Trace hidden - queries are no longer traced".to_string()
+        } else if self.trace_tree.is_empty() {
+            "Tracing on - run the query again to capture a trace".to_string()
+        } else {
+            "Showing trace".to_string()
+        };
+    }
+
+    /// Keys of the trace tree: move, expand and collapse nodes. Returns false
+    /// for keys the tree leaves to the normal handling.
+    fn trace_action(&mut self, action: Action) -> bool {
+        if !self.show_trace || !matches!(self.view, View::Query(_)) {
+            return false;
+        }
+        match action {
+            Action::Down => self.trace_tree.move_by(1),
+            Action::Up => self.trace_tree.move_by(-1),
+            Action::ScrollRight => self.trace_tree.expand(),
+            Action::ScrollLeft => self.trace_tree.collapse(),
+            Action::Select => self.trace_tree.toggle(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// The item a yank copies from: an open detail view, the reflect answer,
+    /// or the selected row of the current list
+    fn selection(&mut self) -> Option<Selection> {
+        let memory = |memory: &Map<String, Value>| Selection {
+            id: memory.get("id").and_then(|v| v.as_str()).map(String::from),
+            text: memory.get("text").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            json: Value::Object(memory.clone()),
+        };
+        let recall = |result: &RecallResult| Selection {
+            id: Some(result.id.clone()),
+            text: result.text.clone(),
+            json: serde_json::to_value(result).unwrap_or(Value::Null),
+        };
+
+        match &self.view {
+            View::Documents(_) if self.viewing_document.is_some() => {
+                let doc = &self.viewing_document.as_ref()?.detail.document;
+                return Some(Selection {
+                    id: Some(doc.id.clone()),
+                    text: doc.original_text.clone(),
+                    json: serde_json::to_value(doc).ok()?,
+                });
+            }
+            View::Memories(_) if self.viewing_memory.is_some() => return self.viewing_memory.as_ref().map(memory),
+            View::Entities(_) if self.viewing_entity.is_some() => {
+                let entity = self.viewing_entity.as_ref()?;
+                return Some(Selection {
+                    id: Some(entity.id.clone()),
+                    text: entity.canonical_name.clone(),
+                    json: serde_json::to_value(entity).ok()?,
+                });
+            }
+            View::Query(_) if self.viewing_recall_result.is_some() => return self.viewing_recall_result.as_ref().map(recall),
+            View::Query(_) if self.query_mode == QueryMode::Reflect => {
+                if self.query_response.is_empty() {
+                    return None;
+                }
+                return Some(Selection {
+                    id: None,
+                    text: self.query_response.clone(),
+                    json: serde_json::json!({
+                        "query": self.query_text,
+                        "text": self.query_response,
+                        "based_on": self.reflect_based_on,
+                    }),
+                });
+            }
+            _ => {}
+        }
+
+        let i = self.selected_index()?;
+        match &self.view {
+            View::Banks => {
+                let bank = self.banks.get(i)?;
+                Some(Selection {
+                    id: Some(bank.bank_id.clone()),
+                    text: bank.name.clone().unwrap_or_else(|| bank.bank_id.clone()),
+                    json: serde_json::to_value(bank).ok()?,
+                })
+            }
+            View::Memories(_) => self.memories.get(i).map(memory),
+            View::Entities(_) => {
+                let entity = self.entities.get(i)?;
+                Some(Selection {
+                    id: Some(entity.id.clone()),
+                    text: entity.canonical_name.clone(),
+                    json: serde_json::to_value(entity).ok()?,
+                })
+            }
+            View::Documents(_) => {
+                let doc = self.documents.get(i)?;
+                let id = doc.get("id").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                Some(Selection { id: Some(id.clone()), text: id, json: Value::Object(doc.clone()) })
+            }
+            View::Operations(_) => {
+                let op = self.operations.get(i)?;
+                Some(Selection {
+                    id: Some(op.id.clone()),
+                    text: op.error_message.clone().unwrap_or_else(|| format!("{} {}", op.task_type, op.status)),
+                    json: serde_json::to_value(op).ok()?,
+                })
+            }
+            View::Query(_) => self.query_results.get(i).map(recall),
+            View::Saved(bank_id) => {
+                let query = self.query_store.saved(bank_id).get(i)?;
+                Some(Selection {
+                    id: Some(query.name.clone()),
+                    text: query.settings.query.clone(),
+                    json: serde_json::to_value(query).ok()?,
+                })
+            }
+            View::Dashboard(_) => None,
+        }
+    }
+
+    fn yank(&mut self, part: YankPart) {
+        let Some(selection) = self.selection() else {
+            self.error_message = "Nothing selected to copy".to_string();
+            return;
+        };
+        match selection.part(part) {
+            Some(text) => {
+                self.status_message = format!("Copied {} to the clipboard ({} chars)", part.label(), text.chars().count());
+                self.clipboard = Some(text);
+            }
+            None => self.error_message = format!("The selected item has no {}", part.label()),
+        }
+    }
+
+    /// The current list or result set (respecting the filter) in both export formats
+    fn export_data(&mut self) -> Option<Export> {
+        let bank = self.view.bank_id().unwrap_or("").to_string();
+        let visible = self.visible_indices();
+        let field = |map: &Map<String, Value>, key: &str| match map.get(key) {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Null) | None => String::new(),
+            Some(other) => other.to_string(),
+        };
+        let list = |name: &str, title: String, columns: &[&str], rows: Vec<Vec<String>>, items: Vec<Value>| Export {
+            name: name.to_string(),
+            count: items.len(),
+            markdown: export::table(&title, columns, &rows),
+            json: Value::Array(items),
+        };
+
+        if let (View::Documents(_), Some(view)) = (&self.view, &self.viewing_document) {
+            let detail = &view.detail;
+            let mut markdown = format!("# Document {}
+
+{}
+
+", detail.document.id, detail.document.original_text);
+            let rows: Vec<Vec<String>> = detail
+                .units
+                .iter()
+                .map(|unit| {
+                    let chunk = document::unit_chunk_index(unit, &detail.document.bank_id, &detail.document.id);
+                    vec![chunk.map(|c| c.to_string()).unwrap_or_default(), field(unit, "fact_type"), field(unit, "text")]
+                })
+                .collect();
+            markdown.push_str(&export::table("Memory units", &["Chunk", "Type", "Text"], &rows).replacen("# ", "## ", 1));
+            return Some(Export {
+                name: "document".to_string(),
+                count: 1,
+                json: serde_json::json!({
+                    "document": detail.document,
+                    "chunks": detail.chunks,
+                    "memory_units": detail.units,
+                }),
+                markdown,
+            });
+        }
+
+        let export = match &self.view {
+            View::Dashboard(_) => return None,
+            View::Banks => {
+                let banks: Vec<&BankListItem> = visible.iter().map(|&i| &self.banks[i]).collect();
+                list(
+                    "banks",
+                    "Banks".to_string(),
+                    &["ID", "Name", "Mission"],
+                    banks
+                        .iter()
+                        .map(|b| vec![b.bank_id.clone(), b.name.clone().unwrap_or_default(), b.mission.clone().unwrap_or_default()])
+                        .collect(),
+                    banks.iter().filter_map(|b| serde_json::to_value(b).ok()).collect(),
+                )
+            }
+            View::Memories(_) => {
+                let memories: Vec<&Map<String, Value>> = visible.iter().map(|&i| &self.memories[i]).collect();
+                list(
+                    "memories",
+                    format!("Memories in {}", bank),
+                    &["ID", "Type", "Date", "Text", "Context"],
+                    memories
+                        .iter()
+                        .map(|m| vec![field(m, "id"), field(m, "fact_type"), field(m, "date"), field(m, "text"), field(m, "context")])
+                        .collect(),
+                    memories.iter().map(|m| Value::Object((*m).clone())).collect(),
+                )
+            }
+            View::Entities(_) => {
+                let entities: Vec<&EntityListItem> = visible.iter().map(|&i| &self.entities[i]).collect();
+                list(
+                    "entities",
+                    format!("Entities in {}", bank),
+                    &["ID", "Name", "Mentions", "Last seen"],
+                    entities
+                        .iter()
+                        .map(|e| {
+                            vec![e.id.clone(), e.canonical_name.clone(), e.mention_count.to_string(), e.last_seen.clone().unwrap_or_default()]
+                        })
+                        .collect(),
+                    entities.iter().filter_map(|e| serde_json::to_value(e).ok()).collect(),
+                )
+            }
+            View::Documents(_) => {
+                let docs: Vec<&Map<String, Value>> = visible.iter().map(|&i| &self.documents[i]).collect();
+                list(
+                    "documents",
+                    format!("Documents in {}", bank),
+                    &["ID", "Created", "Memory units", "Text length"],
+                    docs.iter()
+                        .map(|d| vec![field(d, "id"), field(d, "created_at"), field(d, "memory_unit_count"), field(d, "text_length")])
+                        .collect(),
+                    docs.iter().map(|d| Value::Object((*d).clone())).collect(),
+                )
+            }
+            View::Operations(_) => {
+                let ops: Vec<&Operation> = visible.iter().map(|&i| &self.operations[i]).collect();
+                list(
+                    "operations",
+                    format!("Operations in {}", bank),
+                    &["ID", "Type", "Status", "Items", "Created", "Error"],
+                    ops.iter()
+                        .map(|op| {
+                            vec![
+                                op.id.clone(),
+                                op.task_type.clone(),
+                                op.status.clone(),
+                                op.items_count.to_string(),
+                                op.created_at.clone(),
+                                op.error_message.clone().unwrap_or_default(),
+                            ]
+                        })
+                        .collect(),
+                    ops.iter().filter_map(|op| serde_json::to_value(op).ok()).collect(),
+                )
+            }
+            View::Saved(bank_id) => {
+                let saved = self.query_store.saved(bank_id);
+                let queries: Vec<_> = visible.iter().filter_map(|&i| saved.get(i)).collect();
+                list(
+                    "saved-queries",
+                    format!("Saved queries in {}", bank),
+                    &["Name", "Mode", "Query", "Last run"],
+                    queries
+                        .iter()
+                        .map(|q| {
+                            vec![
+                                q.name.clone(),
+                                q.settings.mode.label().to_string(),
+                                q.settings.query.clone(),
+                                q.last_results.as_ref().map(|r| r.ran_at().to_string()).unwrap_or_default(),
+                            ]
+                        })
+                        .collect(),
+                    queries.iter().filter_map(|q| serde_json::to_value(q).ok()).collect(),
+                )
+            }
+            View::Query(_) => {
+                let recall_rows: Vec<Vec<String>> = self
+                    .query_results
+                    .iter()
+                    .map(|r| {
+                        vec![
+                            r.id.clone(),
+                            r.type_.clone().unwrap_or_default(),
+                            r.occurred_start.clone().unwrap_or_default(),
+                            r.text.clone(),
+                        ]
+                    })
+                    .collect();
+                let recall_table = |title: String| export::table(&title, &["ID", "Type", "Occurred", "Text"], &recall_rows);
+                let answer = || {
+                    let mut markdown = format!("# Reflect: {}
+
+{}
+", self.query_text, self.query_response);
+                    if !self.reflect_based_on.is_empty() {
+                        markdown.push_str("
+## Based on
+
+");
+                        for fact in &self.reflect_based_on {
+                            markdown.push_str(&format!("- {}
+", fact.text));
+                        }
+                    }
+                    markdown
+                };
+                match self.query_mode {
+                    QueryMode::Recall => Export {
+                        name: "recall".to_string(),
+                        count: self.query_results.len(),
+                        json: serde_json::json!({ "query": self.query_text, "results": self.query_results }),
+                        markdown: recall_table(format!("Recall: {}", self.query_text)),
+                    },
+                    QueryMode::Reflect => Export {
+                        name: "reflect".to_string(),
+                        count: 1,
+                        json: serde_json::json!({
+                            "query": self.query_text,
+                            "text": self.query_response,
+                            "based_on": self.reflect_based_on,
+                        }),
+                        markdown: answer(),
+                    },
+                    QueryMode::Compare => Export {
+                        name: "compare".to_string(),
+                        count: self.query_results.len() + 1,
+                        json: serde_json::json!({
+                            "query": self.query_text,
+                            "recall": { "results": self.query_results },
+                            "reflect": { "text": self.query_response, "based_on": self.reflect_based_on },
+                        }),
+                        markdown: format!("{}
+{}", answer(), recall_table(format!("Recall: {}", self.query_text)).replacen("# ", "## ", 1)),
+                    },
+                }
+            }
+        };
+        Some(export)
+    }
+
+    /// Write the current list to `target` (`json`, `md` or a file path)
+    fn export(&mut self, target: Option<String>) {
+        let Some(data) = self.export_data() else {
+            self.error_message = "Nothing to export in this view".to_string();
+            return;
+        };
+        let (path, format) = export::resolve_target(target.as_deref(), &data.name, self.view.bank_id());
+        match export::write(&path, &data, format) {
+            Ok(()) => {
+                self.error_message.clear();
+                self.status_message = format!("Exported {} {} to {}", data.count, data.name, path.display());
+            }
+            Err(e) => self.error_message = format!("{:#}", e),
+        }
+    }
+
+    /// Once both halves of a Compare query are in, summarize how they overlap
+    fn report_compare(&mut self) {
+        if self.query_mode != QueryMode::Compare
+            || self.loader.is_loading(Resource::Query)
+            || self.loader.is_loading(Resource::Reflect)
+        {
+            return;
+        }
+        let recalled: HashSet<&str> = self.query_results.iter().map(|r| r.id.as_str()).collect();
+        let cited_in_recall = self
+            .reflect_based_on
+            .iter()
+            .filter(|fact| fact.id.as_deref().is_some_and(|id| recalled.contains(id)))
+            .count();
+        self.status_message = format!(
+            "Recall found {} results; reflect cited {} memories ({} of them recalled)",
+            self.query_results.len(),
+            self.reflect_based_on.len(),
+            cited_in_recall
+        );
+    }
+
+    /// Step through this bank's query history while editing (`older` = ↑)
+    fn browse_history(&mut self, older: bool) {
+        let Some(bank_id) = self.view.bank_id() else {
+            return;
+        };
+        let history_len = self.query_store.history(bank_id).len();
+        if history_len == 0 {
+            return;
+        }
+
+        let index = match (self.history_index, older) {
+            (None, true) => {
+                self.history_draft = self.query_text.clone();
+                Some(history_len - 1)
+            }
+            (None, false) => None,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) if i + 1 < history_len => Some(i + 1),
+            // Past the newest entry: back to what was being typed
+            (Some(_), false) => None,
+        };
+
+        match index {
+            Some(i) => {
+                let entry = self.query_store.history(bank_id)[i].clone();
+                self.apply_query_settings(&entry.settings);
+                self.status_message = format!("History {}/{} ({})", i + 1, history_len, entry.ran_at);
+            }
+            None => {
+                self.query_text = std::mem::take(&mut self.history_draft);
+                self.status_message.clear();
+            }
+        }
+        self.history_index = index;
+    }
+
+    /// Save the query in the editor under `name`, with its results if they are current
+    fn save_query(&mut self, name: &str) {
+        let View::Query(bank_id) = self.view.clone() else {
+            self.error_message = "Open the Query view (:query) to save a query".to_string();
+            return;
+        };
+        if self.query_text.is_empty() {
+            self.error_message = "Query cannot be empty".to_string();
+            return;
+        }
+
+        let settings = self.query_settings();
+        let results = if self.last_run.as_ref() == Some(&settings) {
+            match self.query_mode {
+                QueryMode::Recall | QueryMode::Compare => Some(StoredResults::recall(&self.query_results)),
+                QueryMode::Reflect if !self.query_response.is_empty() => Some(StoredResults::reflect(&self.query_response)),
+                QueryMode::Reflect => None,
+            }
+        } else {
+            None
+        };
+        self.query_store.save_query(&bank_id, name, settings, results);
+        self.save_query_store();
+        self.status_message = format!("Saved query '{}'", name);
+    }
+
+    /// Load a saved query into the editor and run it, comparing against its stored results
+    fn run_saved_query(&mut self, name: &str) {
+        let Some(bank_id) = self.view.bank_id().map(str::to_string) else {
+            return;
+        };
+        let Some(saved) = self.query_store.get_saved(&bank_id, name).cloned() else {
+            self.error_message = format!("No saved query named '{}'", name);
+            return;
+        };
+
+        self.apply_query_settings(&saved.settings);
+        self.switch_to_view(View::Query(bank_id));
+        self.execute_query();
+        self.running_saved = Some(saved.name);
+    }
+
+    /// Store the results of a saved query's run and compare them with the previous run
+    fn finish_saved_run(&mut self, results: StoredResults) {
+        let Some(name) = self.running_saved.take() else {
+            return;
+        };
+        let Some(bank_id) = self.view.bank_id().map(str::to_string) else {
+            return;
+        };
+
+        let previous = self.query_store.update_results(&bank_id, &name, results);
+        let recall_diff = match &previous {
+            Some(StoredResults::Recall { facts, .. }) if self.query_mode != QueryMode::Reflect => {
+                Some(history::diff_recall(facts, &self.query_results))
+            }
+            _ => None,
+        };
+        if let Some(diff) = &recall_diff {
+            self.status_message = format!("'{}': {} new, {} gone since last run", name, diff.added.len(), diff.removed.len());
+        }
+        self.comparison = Some(Comparison { name, previous, recall_diff });
+        self.save_query_store();
+    }
+
+    fn delete_selected_saved_query(&mut self) {
+        let selected = self.selected_index();
+        if let View::Saved(bank_id) = self.view.clone() {
+            let name = selected
+                .and_then(|i| self.query_store.saved(&bank_id).get(i))
+                .map(|q| q.name.clone());
+            if let Some(name) = name {
+                self.query_store.remove_saved(&bank_id, &name);
+                self.save_query_store();
+                self.status_message = format!("Deleted saved query '{}'", name);
+            }
+        }
+    }
+
+    fn toggle_query_mode(&mut self) {
+        self.query_mode = match self.query_mode {
+            QueryMode::Recall => QueryMode::Reflect,
+            QueryMode::Reflect => QueryMode::Compare,
+            QueryMode::Compare => QueryMode::Recall,
+        };
+        self.status_message = format!("Switched to {} mode", self.query_mode.label());
+    }
+
+    fn cycle_budget(&mut self) {
+        self.query_budget = match self.query_budget {
+            Budget::Low => Budget::Mid,
+            Budget::Mid => Budget::High,
+            Budget::High => Budget::Low,
+        };
+        self.status_message = format!("Budget: {:?}", self.query_budget);
+    }
+
+    /// Compare mode can run reflect at a different budget than recall
+    fn cycle_reflect_budget(&mut self) {
+        self.reflect_budget = match self.reflect_budget {
+            None => Some(Budget::Low),
+            Some(Budget::Low) => Some(Budget::Mid),
+            Some(Budget::Mid) => Some(Budget::High),
+            Some(Budget::High) => None,
+        };
+        self.status_message = match self.reflect_budget {
+            Some(budget) => format!("Reflect budget: {:?}", budget),
+            None => "Reflect budget: same as recall".to_string(),
+        };
+    }
+
+    fn adjust_max_tokens(&mut self, increase: bool) {
+        if increase {
+            self.query_max_tokens = (self.query_max_tokens + 1024).min(16384);
+        } else {
+            self.query_max_tokens = (self.query_max_tokens - 1024).max(512);
+        }
+        self.status_message = format!("Max tokens: {}", self.query_max_tokens);
+    }
+
+    fn scroll_left(&mut self) {
+        self.horizontal_scroll = self.horizontal_scroll.saturating_sub(10);
+    }
+
+    fn scroll_right(&mut self) {
+        self.horizontal_scroll = (self.horizontal_scroll + 10).min(200);
+    }
+
+    fn reset_horizontal_scroll(&mut self) {
+        self.horizontal_scroll = 0;
+    }
+
+    /// Searchable text for each item of the current list, used by the `/` filter
+    fn filter_candidates(&self) -> Vec<String> {
+        match &self.view {
+            View::Banks => self
+                .banks
+                .iter()
+                .map(|bank| format!("{} {}", bank.bank_id, bank.name.as_deref().unwrap_or("")))
+                .collect(),
+            View::Dashboard(_) => DASHBOARD_LINKS.iter().map(|link| link.to_string()).collect(),
+            View::Memories(_) => self
+                .memories
+                .iter()
+                .map(|memory| {
+                    let field = |key: &str| memory.get(key).and_then(|v| v.as_str()).unwrap_or("");
+                    format!("{} {}", field("fact_type"), field("text"))
+                })
+                .collect(),
+            View::Entities(_) => self
+                .entities
+                .iter()
+                .map(|entity| {
+                    let entity_type = entity.metadata.as_ref()
+                        .and_then(|m| m.get("type"))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("");
+                    format!("{} {}", entity.canonical_name, entity_type)
+                })
+                .collect(),
+            View::Documents(_) => self
+                .documents
+                .iter()
+                .map(|doc| doc.get("id").and_then(|v| v.as_str()).unwrap_or("").to_string())
+                .collect(),
+            View::Operations(_) => self
+                .operations
+                .iter()
+                .map(|op| format!("{} {} {}", op.id, op.task_type, op.status))
+                .collect(),
+            View::Query(_) => self.query_results.iter().map(|r| r.text.clone()).collect(),
+            View::Saved(bank_id) => self
+                .query_store
+                .saved(bank_id)
+                .iter()
+                .map(|q| format!("{} {}", q.name, q.settings.query))
+                .collect(),
+        }
+    }
+
+    /// Indices into the current list that pass the active filter, in display order
+    fn visible_indices(&self) -> Vec<usize> {
+        let candidates = self.filter_candidates();
+        // Query results are not filtered: `/` edits the query there
+        let pattern = if matches!(self.view, View::Query(_)) { "" } else { self.filter_text.as_str() };
+        filter::filter_indices(pattern, candidates.iter().map(String::as_str))
+    }
+
+    fn current_list_state(&mut self) -> &mut ListState {
+        match &self.view {
+            View::Banks => &mut self.banks_state,
+            View::Dashboard(_) => &mut self.dashboard_state,
+            View::Memories(_) => &mut self.memories_state,
+            View::Entities(_) => &mut self.entities_state,
+            View::Documents(_) => &mut self.documents_state,
+            View::Operations(_) => &mut self.operations_state,
+            View::Query(_) => &mut self.query_results_state,
+            View::Saved(_) => &mut self.saved_state,
+        }
+    }
+
+    /// Index into the underlying list of the selected (visible) row
+    fn selected_index(&mut self) -> Option<usize> {
+        let visible = self.visible_indices();
+        self.current_list_state()
+            .selected()
+            .and_then(|pos| visible.get(pos).copied())
+    }
+
+    fn set_filter(&mut self, text: String) {
+        self.filter_text = text;
+        self.current_list_state().select(Some(0));
+    }
+
+    fn clear_filter(&mut self) {
+        if !self.filter_text.is_empty() {
+            self.set_filter(String::new());
+        }
+    }
+
+    fn next_item(&mut self) {
+        if matches!(self.view, View::Query(_)) && self.query_mode == QueryMode::Reflect {
+            return;
+        }
+        let len = self.visible_indices().len();
+        let state = self.current_list_state();
+        let i = match state.selected() {
+            Some(i) => {
+                if i >= len.saturating_sub(1) {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        state.select(Some(i));
+    }
+
+    fn previous_item(&mut self) {
+        if matches!(self.view, View::Query(_)) && self.query_mode == QueryMode::Reflect {
+            return;
+        }
+        let len = self.visible_indices().len();
+        let state = self.current_list_state();
+        let i = match state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    len.saturating_sub(1)
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        state.select(Some(i));
+    }
+
+    fn enter_view(&mut self) {
+        let selected = self.selected_index();
+        match &self.view {
+            View::Banks => {
+                if let Some(i) = selected {
+                    if let Some(bank) = self.banks.get(i) {
+                        let bank_id = bank.bank_id.clone();
+                        self.selected_bank_id = Some(bank_id.clone());
+                        self.view_history.push(self.view.clone());
+                        self.view = View::Dashboard(bank_id);
+                        self.on_view_changed();
+                    }
+                }
+            }
+            View::Dashboard(bank_id) => {
+                if let Some(view) = selected.and_then(|i| dashboard_link(i, bank_id.clone())) {
+                    let is_query = matches!(view, View::Query(_));
+                    self.switch_to_view(view);
+                    if is_query {
+                        self.input_mode = InputMode::Query;
+                    }
+                }
+            }
+            View::Memories(_) => {
+                if let Some(i) = selected {
+                    if let Some(memory) = self.memories.get(i) {
+                        self.viewing_memory = Some(memory.clone());
+                        self.status_message = "Viewing memory details (Esc to close)".to_string();
+                    }
+                }
+            }
+            View::Entities(_) => {
+                if let Some(i) = selected {
+                    if let Some(entity) = self.entities.get(i).cloned() {
+                        self.viewing_entity = Some(entity);
+                        self.status_message = "Viewing entity details (Esc to close)".to_string();
+                    }
+                }
+            }
+            View::Documents(bank_id) => {
+                if let Some(i) = selected {
+                    if let Some(doc) = self.documents.get(i) {
+                        // Fetch full document content
+                        let doc_id = doc.get("id")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+
+                        if !doc_id.is_empty() {
+                            let client = self.client.clone();
+                            let bank_id = bank_id.clone();
+                            let doc_id = doc_id.to_string();
+                            self.status_message = format!("Loading document: {}", doc_id);
+                            self.loader.spawn(Resource::Document, &doc_id.clone(), move || {
+                                let detail = document::load(client.as_ref(), &bank_id, &doc_id)?;
+                                Ok(Payload::Document(Box::new(detail)))
+                            });
+                        }
+                    }
+                }
+            }
+            View::Operations(_) => {
+                if let Some(op) = selected.and_then(|i| self.operations.get(i)) {
+                    match &op.error_message {
+                        Some(error) => self.error_message = format!("Operation {} {}: {}", op.id, op.status, error),
+                        None => self.status_message = format!("Operation {} is {}", op.id, op.status),
+                    }
+                }
+            }
+            View::Saved(bank_id) => {
+                let name = selected
+                    .and_then(|i| self.query_store.saved(bank_id).get(i))
+                    .map(|q| q.name.clone());
+                if let Some(name) = name {
+                    self.run_saved_query(&name);
+                }
+            }
+            View::Query(_) => {
+                // View recall result details if recall results are shown
+                if self.query_mode != QueryMode::Reflect {
+                    if let Some(i) = selected {
+                        if let Some(result) = self.query_results.get(i).cloned() {
+                            self.viewing_recall_result = Some(result);
+                            self.status_message = "Viewing recall result (Esc to close)".to_string();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Keys of the open document view: scroll or move through derived memory
+    /// units, switch panes, search and step through matches. Returns false for
+    /// keys the document view leaves to the normal handling.
+    fn document_action(&mut self, action: Action) -> bool {
+        if !matches!(self.view, View::Documents(_)) {
+            return false;
+        }
+        let Some(view) = self.viewing_document.as_mut() else {
+            return false;
+        };
+        match action {
+            Action::Down | Action::Up => {
+                let delta = if action == Action::Down { 1 } else { -1 };
+                match view.pane {
+                    Pane::Text => view.scroll_by(delta),
+                    Pane::Units => view.select_unit(delta),
+                }
+            }
+            Action::ScrollLeft | Action::ScrollRight => view.toggle_pane(),
+            Action::NextPage | Action::PrevPage => {
+                let forward = action == Action::NextPage;
+                if view.matches.is_empty() {
+                    view.page(forward);
+                } else {
+                    view.next_match(forward);
+                }
+            }
+            Action::Filter => {
+                self.error_message.clear();
+                self.input_mode = InputMode::Search;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn set_document_search(&mut self, search: String) {
+        if let Some(view) = self.viewing_document.as_mut() {
+            view.set_search(search);
+            self.status_message = if view.search.is_empty() {
+                String::new()
+            } else {
+                format!("{} matching lines", view.matches.len())
+            };
+        }
+    }
+
+    fn go_back(&mut self) {
+        // Esc first cancels a document load or query that is still running
+        let running: Vec<Resource> = [Resource::Document, Resource::Query, Resource::Reflect]
+            .into_iter()
+            .filter(|resource| self.loader.is_loading(*resource))
+            .collect();
+        if !running.is_empty() {
+            for resource in &running {
+                self.loader.cancel(*resource);
+            }
+            let labels: Vec<&str> = running.iter().map(|r| r.label()).collect();
+            self.status_message = format!("Cancelled {} load", labels.join(" and "));
+            return;
+        }
+
+
+        // If viewing a detail view, close it first
+        if self.viewing_memory.is_some() {
+            self.viewing_memory = None;
+            self.status_message = "Closed memory view".to_string();
+            return;
+        }
+        if self.viewing_entity.is_some() {
+            self.viewing_entity = None;
+            self.status_message = "Closed entity view".to_string();
+            return;
+        }
+        if self.viewing_document.is_some() {
+            self.viewing_document = None;
+            self.status_message = "Closed document view".to_string();
+            return;
+        }
+        if self.viewing_recall_result.is_some() {
+            self.viewing_recall_result = None;
+            self.status_message = "Closed recall result view".to_string();
+            return;
+        }
+
+        // Then drop an active filter before leaving the view
+        if !self.filter_text.is_empty() {
+            self.clear_filter();
+            self.status_message = "Filter cleared".to_string();
+            return;
+        }
+
+        // Otherwise go back to previous view
+        if let Some(prev_view) = self.view_history.pop() {
+            self.view = prev_view;
+            self.on_view_changed();
+        }
+    }
+
+    fn switch_to_view(&mut self, new_view: View) {
+        if self.view != new_view {
+            self.view_history.push(self.view.clone());
+            self.view = new_view;
+            self.on_view_changed();
+        }
+    }
+
+    /// Resolve the bank for a palette command: explicit argument, else the current bank
+    fn resolve_bank(&mut self, bank_id: Option<String>) -> Option<String> {
+        let bank_id = bank_id.or_else(|| self.selected_bank_id.clone());
+        match &bank_id {
+            Some(id) => self.selected_bank_id = Some(id.clone()),
+            None => self.error_message = "No bank selected (use :<command> <bank-id>)".to_string(),
+        }
+        bank_id
+    }
+
+    /// Execute a `:` palette command. Returns `true` if the explorer should quit.
+    fn run_command(&mut self, command: PaletteCommand) -> bool {
+        match command {
+            PaletteCommand::Banks => self.switch_to_view(View::Banks),
+            PaletteCommand::Dashboard(bank_id) => {
+                if let Some(bank_id) = self.resolve_bank(bank_id) {
+                    self.switch_to_view(View::Dashboard(bank_id));
+                }
+            }
+            PaletteCommand::Memories(bank_id) => {
+                if let Some(bank_id) = self.resolve_bank(bank_id) {
+                    self.switch_to_view(View::Memories(bank_id));
+                }
+            }
+            PaletteCommand::Entities(bank_id) => {
+                if let Some(bank_id) = self.resolve_bank(bank_id) {
+                    self.switch_to_view(View::Entities(bank_id));
+                }
+            }
+            PaletteCommand::Documents(bank_id) => {
+                if let Some(bank_id) = self.resolve_bank(bank_id) {
+                    self.switch_to_view(View::Documents(bank_id));
+                }
+            }
+            PaletteCommand::Operations(bank_id) => {
+                if let Some(bank_id) = self.resolve_bank(bank_id) {
+                    self.switch_to_view(View::Operations(bank_id));
+                }
+            }
+            PaletteCommand::Query(bank_id) => {
+                if let Some(bank_id) = self.resolve_bank(bank_id) {
+                    self.switch_to_view(View::Query(bank_id));
+                    self.input_mode = InputMode::Query;
+                }
+            }
+            PaletteCommand::Saved(bank_id) => {
+                if let Some(bank_id) = self.resolve_bank(bank_id) {
+                    self.switch_to_view(View::Saved(bank_id));
+                }
+            }
+            PaletteCommand::Save(name) => self.save_query(&name),
+            PaletteCommand::Run(name) => {
+                if let Some(bank_id) = self.resolve_bank(None) {
+                    self.switch_to_view(View::Query(bank_id));
+                    self.run_saved_query(&name);
+                }
+            }
+            PaletteCommand::Tags(tags) => {
+                self.status_message = if tags.is_empty() {
+                    "Query tags cleared".to_string()
+                } else {
+                    format!("Query tags: {}", tags.join(", "))
+                };
+                self.query_tags = tags;
+            }
+            PaletteCommand::Retain(bank_id) => self.open_compose(bank_id),
+            PaletteCommand::Consolidate => self.consolidate(),
+            PaletteCommand::Export(target) => self.export(target),
+            PaletteCommand::Refresh => self.refresh(),
+            PaletteCommand::AutoRefresh => self.toggle_auto_refresh(),
+            PaletteCommand::Help => self.show_help = !self.show_help,
+            PaletteCommand::Quit => return true,
+        }
+        false
+    }
+
+    /// Cycle through Tab completions for the palette input
+    fn complete_command(&mut self) {
+        if self.command_completions.is_empty() {
+            let bank_ids: Vec<String> = self.banks.iter().map(|b| b.bank_id.clone()).collect();
+            let saved_names: Vec<String> = self
+                .selected_bank_id
+                .as_deref()
+                .map(|bank_id| self.query_store.saved(bank_id).iter().map(|q| q.name.clone()).collect())
+                .unwrap_or_default();
+            self.command_completions = palette::complete(&self.command_text, &bank_ids, &saved_names);
+            self.command_completion_index = 0;
+        } else {
+            self.command_completion_index = (self.command_completion_index + 1) % self.command_completions.len();
+        }
+
+        if let Some(candidate) = self.command_completions.get(self.command_completion_index) {
+            self.command_text = candidate.clone();
+        }
+    }
+
+    fn reset_command(&mut self) {
+        self.command_text.clear();
+        self.command_completions.clear();
+        self.command_completion_index = 0;
+    }
+
+    fn delete_selected_document(&mut self) {
+        let selected = self.selected_index();
+        if let View::Documents(bank_id) = &self.view {
+            if let Some(i) = selected {
+                if let Some(doc) = self.documents.get(i) {
+                    let doc_id = doc.get("id")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("");
+
+                    if !doc_id.is_empty() {
+                        let client = self.client.clone();
+                        let bank_id = bank_id.clone();
+                        let doc_id = doc_id.to_string();
+                        self.status_message = format!("Deleting document: {}", doc_id);
+                        self.loader.mutate(Resource::DeleteDocument, &doc_id.clone(), move || {
+                            client.delete_document(&bank_id, &doc_id)?;
+                            Ok(Payload::DocumentDeleted(doc_id))
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Apply a key press to the current input mode
+    fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome {
+        // Handle Ctrl+C to exit
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return KeyOutcome::Quit;
+        }
+
+        match self.input_mode {
+            InputMode::Normal => {
+                match self.keymap.action_for(&key) {
+                    Some(action) if self.document_action(action) || self.trace_action(action) => {}
+                    Some(Action::Quit) => return KeyOutcome::Quit,
+                    Some(Action::Help) => self.show_help = !self.show_help,
+
+                    // Navigation
+                    Some(Action::Down) => self.next_item(),
+                    Some(Action::Up) => self.previous_item(),
+                    Some(Action::ScrollLeft) => self.scroll_left(),
+                    Some(Action::ScrollRight) => self.scroll_right(),
+                    Some(Action::Select) => {
+                        self.reset_horizontal_scroll();
+                        self.enter_view();
+                    }
+                    Some(Action::Back) => {
+                        self.reset_horizontal_scroll();
+                        self.go_back();
+                    }
+
+                    // Refresh
+                    Some(Action::Refresh) => self.refresh(),
+
+                    // Filter the current list, or edit the query in the Query view
+                    Some(Action::Filter) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.input_mode = InputMode::Query;
+                        } else {
+                            self.error_message.clear();
+                            self.input_mode = InputMode::Filter;
+                        }
+                    }
+
+                    // Command palette
+                    Some(Action::Command) => {
+                        self.error_message.clear();
+                        self.reset_command();
+                        self.input_mode = InputMode::Command;
+                    }
+
+                    // Query view controls
+                    Some(Action::ToggleMode) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.toggle_query_mode();
+                        }
+                    }
+                    Some(Action::CycleBudget) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.cycle_budget();
+                        }
+                    }
+                    Some(Action::CycleReflectBudget) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.cycle_reflect_budget();
+                        }
+                    }
+                    Some(Action::MoreTokens) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.adjust_max_tokens(true);
+                        }
+                    }
+                    Some(Action::FewerTokens) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.adjust_max_tokens(false);
+                        }
+                    }
+
+                    Some(Action::Trace) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.toggle_trace();
+                        }
+                    }
+
+                    Some(Action::Compose) => self.open_compose(None),
+
+                    Some(Action::Yank) => {
+                        self.error_message.clear();
+                        self.input_mode = InputMode::Yank;
+                    }
+                    Some(Action::Export) => self.export(None),
+
+                    Some(Action::Consolidate) => {
+                        if matches!(self.view, View::Dashboard(_)) {
+                            self.consolidate();
+                        }
+                    }
+
+                    // Delete document
+                    Some(Action::Delete) => match self.view {
+                        View::Documents(_) => self.delete_selected_document(),
+                        View::Saved(_) => self.delete_selected_saved_query(),
+                        _ => {}
+                    },
+
+                    // Pagination for memories
+                    Some(Action::NextPage) => {
+                        if matches!(self.view, View::Memories(_)) {
+                            self.load_more_memories();
+                        }
+                    }
+                    Some(Action::PrevPage) => {
+                        if matches!(self.view, View::Memories(_)) {
+                            self.load_prev_memories();
+                        }
+                    }
+
+                    None => {}
+                }
+            }
+            InputMode::Query => {
+                match key.code {
+                    KeyCode::Enter => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.execute_query();
+                        }
+                    }
+                    KeyCode::Esc => {
+                        self.history_index = None;
+                        self.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char(c) => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.history_index = None;
+                            self.query_text.push(c);
+                        }
+                    }
+                    KeyCode::Backspace => {
+                        if matches!(self.view, View::Query(_)) {
+                            self.history_index = None;
+                            self.query_text.pop();
+                        }
+                    }
+                    // Shell-style history of this bank's queries
+                    KeyCode::Up => self.browse_history(true),
+                    KeyCode::Down => self.browse_history(false),
+                    _ => {}
+                }
+            }
+            InputMode::Compose => {
+                let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+                match key.code {
+                    KeyCode::Esc => self.close_compose(),
+                    KeyCode::Char('s') if ctrl => self.submit_compose(),
+                    KeyCode::Char('e') if ctrl => return KeyOutcome::EditExternally,
+                    KeyCode::Tab => {
+                        if let Some(form) = self.compose.as_mut() {
+                            form.next_field();
+                        }
+                    }
+                    KeyCode::BackTab => {
+                        if let Some(form) = self.compose.as_mut() {
+                            form.prev_field();
+                        }
+                    }
+                    KeyCode::Enter => {
+                        if let Some(form) = self.compose.as_mut() {
+                            form.enter();
+                        }
+                    }
+                    KeyCode::Backspace => {
+                        if let Some(form) = self.compose.as_mut() {
+                            form.backspace();
+                        }
+                    }
+                    KeyCode::Char(c) if !ctrl => {
+                        if let Some(form) = self.compose.as_mut() {
+                            form.insert_char(c);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            InputMode::Filter => {
+                match key.code {
+                    KeyCode::Enter => {
+                        self.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Esc => {
+                        self.clear_filter();
+                        self.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char(c) => {
+                        let mut text = self.filter_text.clone();
+                        text.push(c);
+                        self.set_filter(text);
+                    }
+                    KeyCode::Backspace => {
+                        let mut text = self.filter_text.clone();
+                        text.pop();
+                        self.set_filter(text);
+                    }
+                    // Navigate the filtered list without leaving filter mode
+                    KeyCode::Down => self.next_item(),
+                    KeyCode::Up => self.previous_item(),
+                    _ => {}
+                }
+            }
+            InputMode::Yank => {
+                self.input_mode = InputMode::Normal;
+                if let KeyCode::Char(c) = key.code {
+                    match YankPart::from_key(c) {
+                        Some(part) => self.yank(part),
+                        None => self.status_message = "Copy cancelled".to_string(),
+                    }
+                }
+            }
+            InputMode::Search => {
+                let search = self.viewing_document.as_ref().map(|v| v.search.clone()).unwrap_or_default();
+                match key.code {
+                    KeyCode::Enter => self.input_mode = InputMode::Normal,
+                    KeyCode::Esc => {
+                        self.set_document_search(String::new());
+                        self.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char(c) => self.set_document_search(format!("{}{}", search, c)),
+                    KeyCode::Backspace => {
+                        let mut search = search;
+                        search.pop();
+                        self.set_document_search(search);
+                    }
+                    _ => {}
+                }
+            }
+            InputMode::Command => {
+                match key.code {
+                    KeyCode::Enter => {
+                        let input = self.command_text.clone();
+                        self.reset_command();
+                        self.input_mode = InputMode::Normal;
+                        match palette::parse(&input) {
+                            Ok(command) => {
+                                if self.run_command(command) {
+                                    return KeyOutcome::Quit;
+                                }
+                            }
+                            Err(e) => self.error_message = e,
+                        }
+                    }
+                    KeyCode::Esc => {
+                        self.reset_command();
+                        self.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Tab => self.complete_command(),
+                    KeyCode::Char(c) => {
+                        self.command_completions.clear();
+                        self.command_text.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        self.command_completions.clear();
+                        if self.command_text.pop().is_none() {
+                            self.input_mode = InputMode::Normal;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        KeyOutcome::Continue
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),  // Shortcuts bar (context + shortcuts, max 3 rows + 2 border)
+            Constraint::Length(3),  // Header
+            Constraint::Min(0),     // Main content
+            Constraint::Length(1),  // Footer/status only (no border)
+        ])
+        .split(f.area());
+
+    // Control bar
+    render_control_bar(f, app, chunks[0]);
+
+    // Header
+    render_header(f, app, chunks[1]);
+
+    // Main content
+    if app.show_help {
+        render_help(f, app, chunks[2]);
+    } else if let Some(form) = &app.compose {
+        render_compose(f, &app.theme, form, chunks[2]);
+    } else {
+        match &app.view {
+            View::Banks => render_banks(f, app, chunks[2]),
+            View::Dashboard(_) => render_dashboard(f, app, chunks[2]),
+            View::Memories(_) => render_memories(f, app, chunks[2]),
+            View::Entities(_) => render_entities(f, app, chunks[2]),
+            View::Documents(_) => render_documents(f, app, chunks[2]),
+            View::Operations(_) => render_operations(f, app, chunks[2]),
+            View::Query(_) => render_query(f, app, chunks[2]),
+            View::Saved(_) => render_saved(f, app, chunks[2]),
+        }
+    }
+
+    // Footer
+    render_footer(f, app, chunks[3]);
+}
+
+fn render_control_bar(f: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme;
+    // Build contextual shortcuts based on view and input mode; keys come from the active keymap
+    let key = |action: Action| app.keymap.short_label(action);
+    let scroll = format!("{}{}", key(Action::ScrollLeft), key(Action::ScrollRight));
+    let shortcuts: Vec<(String, &str, Color)> = match (&app.view, &app.input_mode) {
+        (View::Banks, InputMode::Normal) => vec![
+            (key(Action::Select), "Select", theme.primary),
+            (key(Action::Filter), "Filter", theme.accent),
+            (key(Action::Command), "Command", theme.accent),
+            (key(Action::Refresh), "Refresh", theme.accent),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Dashboard(_), InputMode::Normal) => vec![
+            (key(Action::Select), "Open", theme.primary),
+            (key(Action::Filter), "Filter", theme.accent),
+            (key(Action::Command), "Command", theme.accent),
+            (key(Action::Compose), "Retain", theme.accent),
+            (key(Action::Consolidate), "Consolidate", theme.accent),
+            (key(Action::Back), "Back", theme.secondary),
+            (key(Action::Refresh), "Refresh", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Memories(_), InputMode::Normal) => vec![
+            (key(Action::Select), "View", theme.primary),
+            (key(Action::Filter), "Filter", theme.accent),
+            (key(Action::Command), "Command", theme.accent),
+            (scroll, "Scroll", theme.primary),
+            (key(Action::Compose), "Retain", theme.accent),
+            (key(Action::NextPage), "Next", theme.accent),
+            (key(Action::PrevPage), "Prev", theme.accent),
+            (key(Action::Back), "Back", theme.secondary),
+            (key(Action::Refresh), "Refresh", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Entities(_), InputMode::Normal) => vec![
+            (key(Action::Select), "View", theme.primary),
+            (key(Action::Filter), "Filter", theme.accent),
+            (key(Action::Command), "Command", theme.accent),
+            (scroll, "Scroll", theme.primary),
+            (key(Action::Back), "Back", theme.secondary),
+            (key(Action::Refresh), "Refresh", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Documents(_), InputMode::Normal) if app.viewing_document.is_some() => vec![
+            (format!("{}{}", key(Action::Down), key(Action::Up)), "Scroll", theme.primary),
+            (scroll, "Text/Units", theme.primary),
+            (key(Action::Filter), "Search", theme.accent),
+            (format!("{}/{}", key(Action::NextPage), key(Action::PrevPage)), "Match/Page", theme.accent),
+            (key(Action::Compose), "Edit", theme.accent),
+            (key(Action::Back), "Close", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Documents(_), InputMode::Normal) => vec![
+            (key(Action::Select), "View", theme.primary),
+            (key(Action::Filter), "Filter", theme.accent),
+            (key(Action::Command), "Command", theme.accent),
+            (scroll, "Scroll", theme.primary),
+            (key(Action::Compose), "Retain/Edit", theme.accent),
+            (key(Action::Delete), "Delete", theme.error),
+            (key(Action::Back), "Back", theme.secondary),
+            (key(Action::Refresh), "Refresh", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Saved(_), InputMode::Normal) => vec![
+            (key(Action::Select), "Run", theme.primary),
+            (key(Action::Filter), "Filter", theme.accent),
+            (key(Action::Command), "Command", theme.accent),
+            (key(Action::Delete), "Delete", theme.error),
+            (key(Action::Back), "Back", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Operations(_), InputMode::Normal) => vec![
+            (key(Action::Select), "Details", theme.primary),
+            (key(Action::Filter), "Filter", theme.accent),
+            (key(Action::Command), "Command", theme.accent),
+            (key(Action::Back), "Back", theme.secondary),
+            (key(Action::Refresh), "Refresh", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Query(_), InputMode::Normal) if app.show_trace => vec![
+            (key(Action::Filter), "Query", theme.accent),
+            (format!("{}{}", key(Action::Down), key(Action::Up)), "Move", theme.primary),
+            (format!("{}{}", key(Action::ScrollLeft), key(Action::ScrollRight)), "Collapse/Expand", theme.primary),
+            (key(Action::Select), "Toggle", theme.primary),
+            (key(Action::Trace), "Hide trace", theme.secondary),
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+        (View::Query(_), InputMode::Normal) => {
+            let mut shortcuts = vec![
+                (key(Action::Filter), "Query", theme.accent),
+                (key(Action::Command), "Command", theme.accent),
+                (key(Action::ToggleMode), "Mode", theme.primary),
+            ];
+            if app.query_mode != QueryMode::Reflect {
+                shortcuts.push((scroll, "Scroll", theme.primary));
+            }
+            shortcuts.push((key(Action::CycleBudget), "Budget", theme.secondary));
+            if app.query_mode == QueryMode::Compare {
+                shortcuts.push((key(Action::CycleReflectBudget), "R-Budget", theme.secondary));
+            }
+            shortcuts.extend([
+                (format!("{}/{}", key(Action::MoreTokens), key(Action::FewerTokens)), "Tokens", theme.secondary),
+                (key(Action::Trace), "Trace", theme.secondary),
+                (key(Action::Back), "Back", theme.secondary),
+                (key(Action::Help), "Help", theme.secondary),
+                (key(Action::Quit), "Quit", theme.error),
+            ]);
+            shortcuts
+        },
+        (View::Query(_), InputMode::Query) => vec![
+            ("Enter".to_string(), "Execute", theme.accent),
+            ("↑↓".to_string(), "History", theme.primary),
+            ("Esc".to_string(), "Cancel", theme.error),
+        ],
+        (_, InputMode::Filter) => vec![
+            ("Enter".to_string(), "Apply", theme.accent),
+            ("Esc".to_string(), "Clear", theme.error),
+        ],
+        (_, InputMode::Yank) => vec![
+            ("i".to_string(), "ID", theme.accent),
+            ("t/y".to_string(), "Text", theme.accent),
+            ("j".to_string(), "JSON", theme.accent),
+            ("Esc".to_string(), "Cancel", theme.error),
+        ],
+        (_, InputMode::Search) => vec![
+            ("Enter".to_string(), "Keep", theme.accent),
+            ("Esc".to_string(), "Clear", theme.error),
+        ],
+        (_, InputMode::Compose) => vec![
+            ("Tab".to_string(), "Next field", theme.primary),
+            ("C-s".to_string(), "Retain", theme.accent),
+            ("C-e".to_string(), "$EDITOR", theme.accent),
+            ("Esc".to_string(), "Cancel", theme.error),
+        ],
+        (_, InputMode::Command) => vec![
+            ("Tab".to_string(), "Complete", theme.primary),
+            ("Enter".to_string(), "Run", theme.accent),
+            ("Esc".to_string(), "Cancel", theme.error),
+        ],
+        _ => vec![
+            (key(Action::Help), "Help", theme.secondary),
+            (key(Action::Quit), "Quit", theme.error),
+        ],
+    };
+
+    // Split into left (context) and right (shortcuts) sections
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(30),  // Context on left
+            Constraint::Percentage(70),  // Shortcuts on right
+        ])
+        .split(area);
+
+    // Left: Context info
+    let context_info = match &app.view {
+        View::Banks => "Context: Banks List".to_string(),
+        View::Dashboard(bank_id) => format!("Context: Dashboard\nBank: {}", bank_id),
+        View::Memories(bank_id) => format!("Context: Memories\nBank: {}", bank_id),
+        View::Entities(bank_id) => format!("Context: Entities\nBank: {}", bank_id),
+        View::Documents(bank_id) => format!("Context: Documents\nBank: {}", bank_id),
+        View::Operations(bank_id) => format!("Context: Operations\nBank: {}", bank_id),
+        View::Query(_bank_id) => {
+            let budget = match (&app.query_mode, app.reflect_budget) {
+                (QueryMode::Compare, Some(reflect)) => format!("{:?} vs {:?}", app.query_budget, reflect),
+                _ => format!("{:?}", app.query_budget),
+            };
+            let mut info = format!("Mode: {}\nBudget: {} | Tokens: {}", app.query_mode.label(), budget, app.query_max_tokens);
+            if !app.query_tags.is_empty() {
+                info.push_str(&format!("\nTags: {}", app.query_tags.join(", ")));
+            }
+            info
+        }
+        View::Saved(bank_id) => format!("Context: Saved Queries\nBank: {}", bank_id),
+    };
+
+    let context_widget = Paragraph::new(context_info)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary))
+            .title(" Context "))
+        .style(Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Left);
+    f.render_widget(context_widget, columns[0]);
+
+    // Right: Shortcuts in columns if many
+    // Calculate shortcuts per column (max 3 lines of shortcuts)
+    let max_shortcuts_per_col = 3;
+    let num_cols = shortcuts.len().div_ceil(max_shortcuts_per_col);
+
+    let mut shortcut_lines = vec![];
+    for row in 0..max_shortcuts_per_col {
+        let mut line_spans = vec![];
+
+        for col in 0..num_cols {
+            let idx = col * max_shortcuts_per_col + row;
+            if idx < shortcuts.len() {
+                let (key, desc, color) = &shortcuts[idx];
+
+                // Each shortcut with proper alignment
+                let shortcut_text = format!("<{}> {:<10}", key, desc);
+
+                line_spans.push(Span::styled(
+                    shortcut_text,
+                    Style::default().fg(*color).add_modifier(Modifier::BOLD)
+                ));
+            }
+        }
+
+        if !line_spans.is_empty() {
+            shortcut_lines.push(Line::from(line_spans));
+        }
+    }
+
+    let shortcuts_widget = Paragraph::new(shortcut_lines)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary))
+            .title(" Shortcuts "))
+        .alignment(Alignment::Left);
+
+    f.render_widget(shortcuts_widget, columns[1]);
+}
+
+fn render_header(f: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme;
+    let bank_info = if let Some(bank_id) = app.view.bank_id() {
+        format!(" [{}]", bank_id)
+    } else {
+        String::new()
+    };
+
+    let title = format!("Hindsight Explorer - {}{}", app.view.title(), bank_info);
+
+    let header = Paragraph::new(title)
+        .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+
+    f.render_widget(header, area);
+}
+
+fn render_footer(f: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme;
+    // Simple status line only (shortcuts are now at the top, no border)
+    let status_line = if app.input_mode == InputMode::Filter {
+        Line::from(vec![
+            Span::styled(" /", Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", app.filter_text), Style::default().fg(theme.text)),
+        ])
+    } else if app.input_mode == InputMode::Search {
+        let search = app.viewing_document.as_ref().map(|v| v.search.as_str()).unwrap_or("");
+        Line::from(vec![
+            Span::styled(" search /", Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", search), Style::default().fg(theme.text)),
+        ])
+    } else if app.input_mode == InputMode::Command {
+        // Show pending completions, or every command while the input is empty
+        let hints = if !app.command_completions.is_empty() {
+            app.command_completions.iter().map(|c| c.trim_end().to_string()).collect::<Vec<_>>().join("  ")
+        } else if app.command_text.is_empty() {
+            palette::command_hints().iter().map(|(name, _)| *name).collect::<Vec<_>>().join("  ")
+        } else {
+            String::new()
+        };
+        Line::from(vec![
+            Span::styled(" :", Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}█", app.command_text), Style::default().fg(theme.text)),
+            Span::styled(format!("   {}", hints), Style::default().fg(theme.muted)),
+        ])
+    } else if !app.error_message.is_empty() {
+        Line::from(vec![
+            Span::styled(" Error: ", Style::default().fg(theme.error).add_modifier(Modifier::BOLD)),
+            Span::raw(&app.error_message),
+        ])
+    } else if let Some(resource) = app.active_load() {
+        Line::from(Span::styled(format!(" Loading {}...", resource.label()), Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD)))
+    } else if !app.status_message.is_empty() {
+        Line::from(vec![
+            Span::raw(" "),
+            Span::styled(&app.status_message, Style::default().fg(theme.accent)),
+        ])
+    } else {
+        Line::from("")
+    };
+
+    let footer = Paragraph::new(status_line).alignment(Alignment::Left);
+    f.render_widget(footer, area);
+}
+
+/// Title suffix while the list on screen is being revalidated: " ⟳ refreshing (12s old)"
+fn refresh_label(app: &App) -> String {
+    match app.view_resource() {
+        Some((resource, key)) if app.loader.is_loading(resource) => {
+            match app.loader.cached(resource, &key) {
+                Some((_, fetched_at)) => format!(" ⟳ refreshing ({}s old)", fetched_at.elapsed().as_secs()),
+                None => " ⟳ refreshing".to_string(),
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+/// Placeholder with animated dots shown while a load has nothing to display yet
+fn render_loading(f: &mut Frame, theme: &Theme, area: Rect, title: &str, text: &str) {
+    // Create animated dots based on time
+    let dots = ".".repeat(((std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() / 500) % 4) as usize);
+
+    let loading_lines = vec![
+        Line::from(""),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("    ", Style::default()),
+            Span::styled(format!("{}{}", text, dots), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("    Press Esc to go back", Style::default().fg(theme.muted))),
+    ];
+
+    let loading_widget = Paragraph::new(loading_lines)
+        .block(Block::default().borders(Borders::ALL).title(title.to_string()))
+        .alignment(Alignment::Left);
+
+    f.render_widget(loading_widget, area);
+}
+
+/// Item count for list titles, including the active filter: "(12/500) [/alice]"
+fn count_label(shown: usize, total: usize, filter_text: &str) -> String {
+    if filter_text.is_empty() {
+        format!("({})", total)
+    } else {
+        format!("({}/{}) [/{}]", shown, total, filter_text)
+    }
+}
+
+fn render_banks(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    if app.awaiting_first_load() {
+        render_loading(f, &theme, area, "Banks", "Loading banks");
+        return;
+    }
+
+    let visible = app.visible_indices();
+    let items: Vec<ListItem> = visible
+        .iter()
+        .map(|&i| &app.banks[i])
+        .map(|bank| {
+            let name = bank.name.as_deref().filter(|s| !s.is_empty()).unwrap_or("Unnamed");
+            let content = format!("{} - {}", bank.bank_id, name);
+            ListItem::new(content).style(Style::default().fg(theme.text))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!("Banks {}{}", count_label(visible.len(), app.banks.len(), &app.filter_text), refresh_label(app))))
+        .highlight_style(theme.highlight)
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(list, area, &mut app.banks_state);
+}
+
+fn render_dashboard(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    let Some(bank_id) = app.view.bank_id().map(str::to_string) else {
+        return;
+    };
+    let Some(stats) = app.stats.clone() else {
+        if app.awaiting_first_load() {
+            render_loading(f, &theme, area, "Dashboard", "Loading bank statistics");
+        } else {
+            let placeholder = Paragraph::new(format!(
+                "No statistics loaded. Press {} to retry.",
+                app.keymap.short_label(Action::Refresh)
+            ))
+            .style(Style::default().fg(theme.muted))
+            .block(Block::default().borders(Borders::ALL).title(format!("Dashboard: {}", bank_id)));
+            f.render_widget(placeholder, area);
+        }
+        return;
+    };
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(36), Constraint::Min(0)])
+        .split(area);
+
+    // Left: where to go next, with counts
+    let saved_count = app.query_store.saved(&bank_id).len();
+    let visible = app.visible_indices();
+    let items: Vec<ListItem> = visible
+        .iter()
+        .map(|&i| {
+            let (detail, color) = match i {
+                0 => (format!("{} units", stats.total_nodes), theme.text),
+                2 => (format!("{} docs", stats.total_documents), theme.text),
+                3 if stats.failed_operations > 0 => (
+                    format!("{} pending, {} failed", stats.pending_operations, stats.failed_operations),
+                    theme.error,
+                ),
+                3 => (format!("{} pending", stats.pending_operations), theme.text),
+                5 => (format!("{} saved", saved_count), theme.text),
+                _ => (String::new(), theme.text),
+            };
+            ListItem::new(format!("{:<14} {}", DASHBOARD_LINKS[i], detail)).style(Style::default().fg(color))
+        })
+        .collect();
+    let links = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!("Open{}", refresh_label(app))))
+        .highlight_style(theme.highlight)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(links, columns[0], &mut app.dashboard_state);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(6), Constraint::Min(0)])
+        .split(columns[1]);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(rows[0]);
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[1]);
+
+    // Totals
+    let failed_style = if stats.failed_operations > 0 {
+        Style::default().fg(theme.error).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.text)
+    };
+    let totals = Paragraph::new(vec![
+        Line::from(format!("Memory units: {}", stats.total_nodes)),
+        Line::from(format!("Links:        {}", stats.total_links)),
+        Line::from(format!("Documents:    {}", stats.total_documents)),
+        Line::from(vec![
+            Span::raw(format!("Operations:   {} pending, ", stats.pending_operations)),
+            Span::styled(format!("{} failed", stats.failed_operations), failed_style),
+        ]),
+    ])
+    .style(Style::default().fg(theme.text))
+    .block(Block::default().borders(Borders::ALL).title(format!("Bank: {}", bank_id)));
+    f.render_widget(totals, top[0]);
+
+    // Pending operations over the last refreshes
+    let samples: Vec<u64> = app
+        .pending_samples
+        .get(&bank_id)
+        .map(|s| s.iter().copied().collect())
+        .unwrap_or_default();
+    let sparkline_title = if app.auto_refresh_enabled {
+        format!("Pending operations (last {} refreshes, every {}s)", samples.len(), app.refresh_interval.as_secs())
+    } else {
+        format!("Pending operations (last {} loads, auto-refresh off)", samples.len())
+    };
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(sparkline_title))
+        .data(&samples)
+        .style(Style::default().fg(theme.secondary));
+    f.render_widget(sparkline, top[1]);
+
+    // Fact type distribution, one gauge per type
+    let fact_block = Block::default().borders(Borders::ALL).title("Memory Units by Fact Type");
+    let fact_area = fact_block.inner(bottom[0]);
+    f.render_widget(fact_block, bottom[0]);
+
+    let mut fact_types: Vec<(&String, &i32)> = stats.nodes_by_fact_type.iter().collect();
+    fact_types.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    let gauge_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            fact_types
+                .iter()
+                .map(|_| Constraint::Length(1))
+                .chain(std::iter::once(Constraint::Min(0))),
+        )
+        .split(fact_area);
+    let total_nodes = stats.total_nodes.max(1) as f64;
+    for ((fact_type, count), row) in fact_types.iter().zip(gauge_rows.iter()) {
+        let ratio = (**count as f64 / total_nodes).clamp(0.0, 1.0);
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(theme.primary))
+            .ratio(ratio)
+            .label(format!("{} {} ({:.0}%)", fact_type, count, ratio * 100.0));
+        f.render_widget(gauge, *row);
+    }
+
+    // Links by type
+    let mut link_types: Vec<(&str, u64)> = stats
+        .links_by_link_type
+        .iter()
+        .map(|(link_type, count)| (link_type.as_str(), (*count).max(0) as u64))
+        .collect();
+    link_types.sort_by(|a, b| a.0.cmp(b.0));
+    let bar_width = (bottom[1].width.saturating_sub(2) / link_types.len().max(1) as u16)
+        .saturating_sub(1)
+        .clamp(3, 12);
+    let links_chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(format!("Links by Type ({})", stats.total_links)))
+        .data(&link_types)
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(theme.secondary))
+        .value_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .label_style(Style::default().fg(theme.muted));
+    f.render_widget(links_chart, bottom[1]);
+}
+
+fn render_memories(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    // If viewing a memory, show its details
+    if let Some(memory) = &app.viewing_memory {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(7),  // Memory metadata
+                Constraint::Min(0),     // Full text content
+            ])
+            .split(area);
+
+        // Metadata section
+        let mem_type = memory.get("fact_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        let mentioned_at = memory.get("mentioned_at")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        let occurred_start = memory.get("occurred_start")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        let occurred_end = memory.get("occurred_end")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+
+        let metadata_text = format!(
+            "Type: {}\nMentioned At: {}\nOccurred: {} to {}",
+            mem_type, mentioned_at, occurred_start, occurred_end
+        );
+
+        let metadata = Paragraph::new(metadata_text)
+            .block(Block::default().borders(Borders::ALL).title("Memory Metadata"))
+            .style(Style::default().fg(theme.primary));
+
+        f.render_widget(metadata, chunks[0]);
+
+        // Full text content
+        let text = memory.get("text").and_then(|v| v.as_str()).unwrap_or("No text available");
+
+        let content_widget = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title("Full Text (Esc to close)"))
+            .wrap(Wrap { trim: false })
+            .style(Style::default().fg(theme.text));
+
+        f.render_widget(content_widget, chunks[1]);
+    } else if app.awaiting_first_load() {
+        render_loading(f, &theme, area, "Memories", "Loading memories");
+    } else {
+        let visible = app.visible_indices();
+        let fact_type = |memory: &Map<String, Value>| -> String {
+            memory.get("fact_type").and_then(|v| v.as_str()).unwrap_or("unknown").to_string()
+        };
+        // The type column fits the longest fact type shown
+        let type_width = visible
+            .iter()
+            .map(|&i| fact_type(&app.memories[i]).chars().count())
+            .chain(std::iter::once("TYPE".len()))
+            .max()
+            .unwrap_or(0);
+
+        // Show memory list as table
+        let mut items = vec![
+            // Header row
+            ListItem::new(format!("{:<tw$} {:<18} {:<18} {}", "TYPE", "MENTIONED AT", "OCCURRED AT", "TEXT", tw = type_width))
+                .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+        ];
+
+        // Data rows
+        for memory in visible.iter().map(|&i| &app.memories[i]) {
+            let mem_type = fact_type(memory);
+            let mentioned = memory.get("mentioned_at")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.split('T').next())
+                .unwrap_or("-");
+            let occurred = memory.get("occurred_start")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.split('T').next())
+                .unwrap_or("-");
+            let text = memory.get("text").and_then(|v| v.as_str()).unwrap_or("");
+
+            // Apply horizontal scroll
+            let scrolled_text: String = text.chars().skip(app.horizontal_scroll).take(80).collect();
+
+            let content = format!("{:<tw$} {:<18} {:<18} {}", mem_type, mentioned, occurred, scrolled_text, tw = type_width);
+            items.push(ListItem::new(content).style(Style::default().fg(theme.text)));
+        }
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!("Memories {} - Press Enter to view full text{}", count_label(visible.len(), app.memories.len(), &app.filter_text), refresh_label(app))))
+            .highlight_style(theme.highlight)
+            .highlight_symbol(">> ");
+
+        f.render_stateful_widget(list, area, &mut app.memories_state);
+    }
+}
+
+fn render_entities(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    // If viewing an entity, show its details
+    if let Some(entity) = &app.viewing_entity {
+        let entity_type = entity.metadata.as_ref()
+            .and_then(|m| m.get("type"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        let metadata_text = format!(
+            "Name: {}\nType: {}\nMentions: {}\nFirst Seen: {}\nLast Seen: {}",
+            entity.canonical_name,
+            entity_type,
+            entity.mention_count,
+            entity.first_seen.as_deref().unwrap_or("unknown"),
+            entity.last_seen.as_deref().unwrap_or("unknown")
+        );
+
+        let metadata = Paragraph::new(metadata_text)
+            .block(Block::default().borders(Borders::ALL).title("Entity Details (Esc to close)"))
+            .style(Style::default().fg(theme.primary))
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(metadata, area);
+    } else if app.awaiting_first_load() {
+        render_loading(f, &theme, area, "Entities", "Loading entities");
+    } else {
+        // Show entity list as table
+        let mut items = vec![
+            // Header row
+            ListItem::new(format!("{:<40} {:<15} {:<10}", "NAME", "TYPE", "MENTIONS"))
+                .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+        ];
+
+        // Data rows
+        let visible = app.visible_indices();
+        for entity in visible.iter().map(|&i| &app.entities[i]) {
+            let name = &entity.canonical_name;
+            // Apply horizontal scroll to name
+            let scrolled_name: String = name.chars().skip(app.horizontal_scroll).take(40).collect();
+            let entity_type = entity.metadata.as_ref()
+                .and_then(|m| m.get("type"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown");
+            let mentions = entity.mention_count;
+
+            let content = format!("{:<40} {:<15} {:<10}", scrolled_name, entity_type, mentions);
+            items.push(ListItem::new(content).style(Style::default().fg(theme.text)));
+        }
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!("Entities {} - Press Enter to view details{}", count_label(visible.len(), app.entities.len(), &app.filter_text), refresh_label(app))))
+            .highlight_style(theme.highlight)
+            .highlight_symbol(">> ");
+
+        f.render_stateful_widget(list, area, &mut app.entities_state);
+    }
+}
+
+fn render_documents(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    // If viewing a document, show its text, chunks and derived memories
+    if let Some(view) = app.viewing_document.as_mut() {
+        render_document(f, &theme, view, area);
+    } else if app.awaiting_first_load() {
+        render_loading(f, &theme, area, "Documents", "Loading documents");
+    } else {
+        // Show document list as table
+        let mut items = vec![
+            // Header row
+            ListItem::new(format!("{:<40} {:<20} {}", "ID", "TYPE", "CREATED"))
+                .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+        ];
+
+        // Data rows
+        let visible = app.visible_indices();
+        for doc in visible.iter().map(|&i| &app.documents[i]) {
+            let id = doc.get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown");
+            // Apply horizontal scroll to id
+            let scrolled_id: String = id.chars().skip(app.horizontal_scroll).take(40).collect();
+            let content_type = doc.get("content_type")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown");
+            let created = doc.get("created_at")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.split('T').next())
+                .unwrap_or("unknown");
+
+            let content = format!("{:<40} {:<20} {}", scrolled_id, content_type, created);
+            items.push(ListItem::new(content).style(Style::default().fg(theme.text)));
+        }
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!("Documents {} - Press Enter to view content{}", count_label(visible.len(), app.documents.len(), &app.filter_text), refresh_label(app))))
+            .highlight_style(theme.highlight)
+            .highlight_symbol(">> ");
+
+        f.render_stateful_widget(list, area, &mut app.documents_state);
+    }
+}
+
+fn render_document(f: &mut Frame, theme: &Theme, view: &mut DocumentView, area: Rect) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Document metadata
+            Constraint::Min(0),    // Text and memory units
+        ])
+        .split(area);
+
+    let detail = &view.detail;
+    let doc = &detail.document;
+    let tags = if doc.tags.is_empty() { "-".to_string() } else { doc.tags.join(", ") };
+    let units = if detail.units_complete {
+        format!("{} of {}", detail.units.len(), doc.memory_unit_count)
+    } else {
+        format!("{} of {} (scan stopped early)", detail.units.len(), doc.memory_unit_count)
+    };
+    let metadata = Paragraph::new(format!(
+        "ID: {}   Created: {}   Updated: {}\nTags: {}   Chunks: {}   Memory units: {}",
+        doc.id, doc.created_at, doc.updated_at, tags, detail.chunks.len(), units
+    ))
+    .block(Block::default().borders(Borders::ALL).title("Document"))
+    .style(Style::default().fg(theme.primary));
+    f.render_widget(metadata, rows[0]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(rows[1]);
+    let border = |pane: Pane| {
+        if view.pane == pane { Style::default().fg(theme.secondary) } else { Style::default().fg(theme.primary) }
+    };
+
+    // Original text, starting at the scroll position so chunk jumps land on the first line
+    let height = columns[0].height.saturating_sub(2) as usize;
+    view.page_height = height.max(1);
+    let lines: Vec<Line> = view
+        .lines
+        .iter()
+        .skip(view.scroll)
+        .take(height)
+        .map(|line| document_line(theme, line, &view.search))
+        .collect();
+    let mut title = format!("Original text [{}/{}]", (view.scroll + 1).min(view.lines.len()), view.lines.len());
+    if !view.search.is_empty() {
+        let current = if view.matches.is_empty() { 0 } else { view.current_match + 1 };
+        title.push_str(&format!(" - /{} ({}/{})", view.search, current, view.matches.len()));
+    }
+    if view.unlocated_chunks > 0 {
+        title.push_str(&format!(" - {} chunks not found in text", view.unlocated_chunks));
+    }
+    let text = Paragraph::new(lines)
+        .style(Style::default().fg(theme.text))
+        .block(Block::default().borders(Borders::ALL).border_style(border(Pane::Text)).title(title))
+        .wrap(Wrap { trim: false });
+    f.render_widget(text, columns[0]);
+
+    // Memory units extracted from this document, tagged with their chunk
+    let items: Vec<ListItem> = view
+        .detail
+        .units
+        .iter()
+        .map(|unit| {
+            let chunk = document::unit_chunk_index(unit, &doc.bank_id, &doc.id)
+                .map(|i| format!("#{}", i))
+                .unwrap_or_default();
+            let fact_type = unit.get("fact_type").and_then(|v| v.as_str()).unwrap_or("");
+            let text = unit.get("text").and_then(|v| v.as_str()).unwrap_or("");
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<4} ", chunk), Style::default().fg(theme.secondary)),
+                Span::styled(format!("{:<12} ", fact_type), Style::default().fg(theme.muted)),
+                Span::styled(text.to_string(), Style::default().fg(theme.text)),
+            ]))
+        })
+        .collect();
+    let units_title = if view.detail.units.is_empty() {
+        "Memory units (none found)".to_string()
+    } else {
+        format!("Memory units ({})", view.detail.units.len())
+    };
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).border_style(border(Pane::Units)).title(units_title))
+        .highlight_style(theme.highlight)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(list, columns[1], &mut view.units_state);
+}
+
+/// One line of document text with search matches highlighted, or a chunk marker
+fn document_line<'a>(theme: &Theme, line: &'a DocLine, search: &str) -> Line<'a> {
+    match line {
+        DocLine::Chunk { index, units } => Line::from(Span::styled(
+            format!("── chunk {} · {} memory unit{} ──", index, units, if *units == 1 { "" } else { "s" }),
+            Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD),
+        )),
+        DocLine::Text(text) => {
+            let mut spans = Vec::new();
+            let mut position = 0;
+            for (start, end) in document::find_matches(text, search) {
+                spans.push(Span::raw(&text[position..start]));
+                spans.push(Span::styled(&text[start..end], theme.highlight));
+                position = end;
+            }
+            spans.push(Span::raw(&text[position..]));
+            Line::from(spans)
+        }
+    }
+}
+
+fn render_operations(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    if app.awaiting_first_load() {
+        render_loading(f, &theme, area, "Operations", "Loading operations");
+        return;
+    }
+
+    let mut items = vec![
+        // Header row
+        ListItem::new(format!("{:<38} {:<14} {:<10} {:<6} {}", "ID", "TYPE", "STATUS", "ITEMS", "CREATED"))
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+    ];
+
+    // Data rows
+    let visible = app.visible_indices();
+    for op in visible.iter().map(|&i| &app.operations[i]) {
+        let created = op.created_at.split('.').next().unwrap_or(&op.created_at);
+        let color = match op.status.as_str() {
+            "failed" => theme.error,
+            "pending" => theme.secondary,
+            _ => theme.text,
+        };
+        let content = format!("{:<38} {:<14} {:<10} {:<6} {}", op.id, op.task_type, op.status, op.items_count, created);
+        items.push(ListItem::new(content).style(Style::default().fg(color)));
+    }
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!("Operations {} - Press Enter for status{}", count_label(visible.len(), app.operations.len(), &app.filter_text), refresh_label(app))))
+        .highlight_style(theme.highlight)
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(list, area, &mut app.operations_state);
+}
+
+fn render_query(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Query input
+            Constraint::Min(0),     // Results or Response
+        ])
+        .split(area);
+
+    // Query input
+    let query_style = if app.input_mode == InputMode::Query {
+        Style::default().fg(theme.secondary)
+    } else {
+        Style::default()
+    };
+
+    let mode_label = app.query_mode.label();
+    let title = match app.history_index {
+        Some(i) => format!("{} Query - history {} (↑/↓ to browse)", mode_label, i + 1),
+        None => format!("{} Query (press / to edit, m to toggle mode)", mode_label),
+    };
+
+    let query = Paragraph::new(app.query_text.as_str())
+        .style(query_style)
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(query, chunks[0]);
+
+    if app.show_trace {
+        render_trace(f, app, chunks[1]);
+        return;
+    }
+
+    // Compare mode shows loading per pane
+    if app.query_mode == QueryMode::Compare {
+        render_compare(f, app, chunks[1]);
+        return;
+    }
+
+    // Show loading indicator while the query runs
+    if app.loader.is_loading(Resource::Query) {
+        let loading_text = match app.query_mode {
+            QueryMode::Reflect => "Reflecting on memories",
+            _ => "Searching memories",
+        };
+        render_loading(f, &theme, chunks[1], &format!("{} in progress", mode_label), loading_text);
+        return;
+    }
+
+    // Results or Response based on mode
+    match app.query_mode {
+        QueryMode::Recall => {
+            // If viewing a recall result, show its details
+            if let Some(result) = &app.viewing_recall_result {
+                render_recall_result(f, &theme, result, chunks[1]);
+            } else {
+                // Show results as a table like memories
+                let mut items = vec![
+                    // Header row
+                    ListItem::new(format!("{:<10} {:<18} {:<18} {}", "TYPE", "OCCURRED START", "OCCURRED END", "TEXT"))
+                        .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+                ];
+
+                // When re-running a saved query, mark results that are new since its last run
+                let diff = app.comparison.as_ref().and_then(|c| c.recall_diff.as_ref());
+
+                // Data rows
+                for result in &app.query_results {
+                    let mem_type = result.type_.as_deref().unwrap_or("unknown");
+                    let occurred_start = result.occurred_start.as_deref()
+                        .and_then(|s| s.split('T').next())
+                        .unwrap_or("-");
+                    let occurred_end = result.occurred_end.as_deref()
+                        .and_then(|s| s.split('T').next())
+                        .unwrap_or("-");
+                    let text = &result.text;
+
+                    // Apply horizontal scroll
+                    let scrolled_text: String = text.chars().skip(app.horizontal_scroll).take(80).collect();
+
+                    let content = format!("{:<10} {:<18} {:<18} {}", mem_type, occurred_start, occurred_end, scrolled_text);
+                    match diff {
+                        Some(diff) if diff.added.contains(&result.id) => {
+                            items.push(ListItem::new(format!("+ {}", content)).style(Style::default().fg(theme.secondary)));
+                        }
+                        Some(_) => items.push(ListItem::new(format!("  {}", content)).style(Style::default().fg(theme.text))),
+                        None => items.push(ListItem::new(content).style(Style::default().fg(theme.text))),
+                    }
+                }
+
+                // Results from the last run that are gone now (not selectable)
+                if let Some(diff) = diff {
+                    for fact in &diff.removed {
+                        let text: String = fact.text.chars().skip(app.horizontal_scroll).take(80).collect();
+                        items.push(ListItem::new(format!("- {:<10} {:<18} {:<18} {}", "gone", "-", "-", text))
+                            .style(Style::default().fg(theme.muted).add_modifier(Modifier::CROSSED_OUT)));
+                    }
+                }
+
+                let title = match (&app.comparison, diff) {
+                    (Some(comparison), Some(diff)) => format!(
+                        "Recall Results ({}) - '{}' vs {}: +{} new, -{} gone",
+                        app.query_results.len(),
+                        comparison.name,
+                        comparison.previous.as_ref().map(|p| p.ran_at()).unwrap_or("-"),
+                        diff.added.len(),
+                        diff.removed.len()
+                    ),
+                    (Some(comparison), None) => format!(
+                        "Recall Results ({}) - '{}' first run, results stored",
+                        app.query_results.len(),
+                        comparison.name
+                    ),
+                    _ => format!("Recall Results ({}) - Press Enter to view full text", app.query_results.len()),
+                };
+
+                let list = List::new(items)
+                    .block(Block::default().borders(Borders::ALL).title(title))
+                    .highlight_style(theme.highlight)
+                    .highlight_symbol(">> ");
+
+                f.render_stateful_widget(list, chunks[1], &mut app.query_results_state);
+            }
+        }
+        QueryMode::Compare => {}
+        QueryMode::Reflect => {
+            let response_text = if app.query_response.is_empty() {
+                "No response yet. Enter a query and press Enter to get a reflection."
+            } else {
+                app.query_response.as_str()
+            };
+
+            // Re-running a saved reflect query shows the previous answer underneath
+            let previous = match app.comparison.as_ref() {
+                Some(Comparison { name, previous: Some(StoredResults::Reflect { ran_at, text }), .. }) => {
+                    Some((name.as_str(), ran_at.as_str(), text.as_str()))
+                }
+                _ => None,
+            };
+
+            let (response_area, title) = match previous {
+                Some((name, ran_at, previous_text)) => {
+                    let halves = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                        .split(chunks[1]);
+                    let previous_widget = Paragraph::new(previous_text)
+                        .style(Style::default().fg(theme.muted))
+                        .block(Block::default().borders(Borders::ALL).title(format!("Previous answer ({})", ran_at)))
+                        .wrap(Wrap { trim: false });
+                    f.render_widget(previous_widget, halves[1]);
+
+                    let change = if previous_text == app.query_response { "unchanged" } else { "changed" };
+                    (halves[0], format!("Reflect Response - '{}' {} since last run", name, change))
+                }
+                None => (chunks[1], "Reflect Response".to_string()),
+            };
+
+            let response = Paragraph::new(response_text)
+                .style(Style::default().fg(theme.text))
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .wrap(Wrap { trim: false });
+
+            f.render_widget(response, response_area);
+        }
+    }
+}
+
+fn render_recall_result(f: &mut Frame, theme: &Theme, result: &RecallResult, area: Rect) {
+    let recall_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7),  // Metadata
+            Constraint::Min(0),     // Full text
+        ])
+        .split(area);
+
+    // Metadata section
+    let mem_type = result.type_.as_deref().unwrap_or("unknown");
+    let occurred_start = result.occurred_start.as_deref().unwrap_or("unknown");
+    let occurred_end = result.occurred_end.as_deref().unwrap_or("unknown");
+    let mentioned_at = result.mentioned_at.as_deref().unwrap_or("unknown");
+
+    let metadata_text = format!(
+        "Type: {}\nMentioned At: {}\nOccurred: {} to {}",
+        mem_type, mentioned_at, occurred_start, occurred_end
+    );
+
+    let metadata = Paragraph::new(metadata_text)
+        .block(Block::default().borders(Borders::ALL).title("Recall Result Metadata"))
+        .style(Style::default().fg(theme.primary));
+
+    f.render_widget(metadata, recall_chunks[0]);
+
+    // Full text content
+    let content_widget = Paragraph::new(result.text.as_str())
+        .block(Block::default().borders(Borders::ALL).title("Full Text (Esc to close)"))
+        .wrap(Wrap { trim: false })
+        .style(Style::default().fg(theme.text));
+
+    f.render_widget(content_widget, recall_chunks[1]);
+}
+
+/// Recall results on the left, the reflect answer and the memories it is
+/// based on on the right. Recall results the answer cites are marked `●`.
+fn render_compare(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    if let Some(result) = &app.viewing_recall_result {
+        render_recall_result(f, &theme, result, area);
+        return;
+    }
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let reflect_budget = app.reflect_budget.unwrap_or(app.query_budget);
+
+    // Left: recall results
+    if app.loader.is_loading(Resource::Query) {
+        render_loading(f, &theme, columns[0], &format!("Recall ({:?}) in progress", app.query_budget), "Searching memories");
+    } else {
+        let cited: HashSet<&str> = app.reflect_based_on.iter().filter_map(|fact| fact.id.as_deref()).collect();
+        let mut items = vec![
+            // Header row
+            ListItem::new(format!("  {:<10} {}", "TYPE", "TEXT"))
+                .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+        ];
+        let mut cited_count = 0;
+        for result in &app.query_results {
+            let mem_type = result.type_.as_deref().unwrap_or("unknown");
+            let scrolled_text: String = result.text.chars().skip(app.horizontal_scroll).take(80).collect();
+            if cited.contains(result.id.as_str()) {
+                cited_count += 1;
+                items.push(ListItem::new(format!("● {:<10} {}", mem_type, scrolled_text))
+                    .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)));
+            } else {
+                items.push(ListItem::new(format!("  {:<10} {}", mem_type, scrolled_text))
+                    .style(Style::default().fg(theme.text)));
+            }
+        }
+
+        let title = format!(
+            "Recall ({:?}) - {} results, {} cited by reflect",
+            app.query_budget,
+            app.query_results.len(),
+            cited_count
+        );
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(theme.highlight)
+            .highlight_symbol(">> ");
+        f.render_stateful_widget(list, columns[0], &mut app.query_results_state);
+    }
+
+    // Right: reflect answer and its citations
+    if app.loader.is_loading(Resource::Reflect) {
+        render_loading(f, &theme, columns[1], &format!("Reflect ({:?}) in progress", reflect_budget), "Reflecting on memories");
+        return;
+    }
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(columns[1]);
+
+    let response_text = if app.query_response.is_empty() {
+        "No response yet. Enter a query and press Enter to run recall and reflect side by side."
+    } else {
+        app.query_response.as_str()
+    };
+    let response = Paragraph::new(response_text)
+        .style(Style::default().fg(theme.text))
+        .block(Block::default().borders(Borders::ALL).title(format!("Reflect ({:?})", reflect_budget)))
+        .wrap(Wrap { trim: false });
+    f.render_widget(response, right[0]);
+
+    // Citations, marked when recall found the same memory
+    let recalled: HashSet<&str> = app.query_results.iter().map(|r| r.id.as_str()).collect();
+    let width = right[1].width.saturating_sub(16) as usize;
+    let items: Vec<ListItem> = app
+        .reflect_based_on
+        .iter()
+        .map(|fact| {
+            let fact_type = fact.type_.as_deref().unwrap_or("unknown");
+            let text: String = fact.text.chars().take(width).collect();
+            if fact.id.as_deref().is_some_and(|id| recalled.contains(id)) {
+                ListItem::new(format!("● {:<10} {}", fact_type, text)).style(Style::default().fg(theme.accent))
+            } else {
+                ListItem::new(format!("  {:<10} {}", fact_type, text)).style(Style::default().fg(theme.muted))
+            }
+        })
+        .collect();
+    let based_on = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Based on ({}) - ● also recalled", app.reflect_based_on.len())),
+    );
+    f.render_widget(based_on, right[1]);
+}
+
+fn render_saved(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    let Some(bank_id) = app.view.bank_id().map(str::to_string) else {
+        return;
+    };
+
+    let mut items = vec![
+        // Header row
+        ListItem::new(format!("{:<20} {:<8} {:<6} {:<7} {:<20} {}", "NAME", "MODE", "BUDGET", "TOKENS", "LAST RUN", "QUERY"))
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+    ];
+
+    // Data rows
+    let visible = app.visible_indices();
+    let saved = app.query_store.saved(&bank_id);
+    for query in visible.iter().map(|&i| &saved[i]) {
+        let settings = &query.settings;
+        let mode = settings.mode.label().to_lowercase();
+        let last_run = query
+            .last_results
+            .as_ref()
+            .map(|r| r.ran_at().split('.').next().unwrap_or("").replace('T', " "))
+            .unwrap_or_else(|| "never".to_string());
+        let mut text = settings.query.clone();
+        if !settings.tags.is_empty() {
+            text.push_str(&format!(" [tags: {}]", settings.tags.join(", ")));
+        }
+        let scrolled_text: String = text.chars().skip(app.horizontal_scroll).take(80).collect();
+
+        let content = format!(
+            "{:<20} {:<8} {:<6} {:<7} {:<20} {}",
+            query.name, mode, format!("{:?}", settings.budget), settings.max_tokens, last_run, scrolled_text
+        );
+        items.push(ListItem::new(content).style(Style::default().fg(theme.text)));
+    }
+
+    let title = format!(
+        "Saved Queries {} - Enter re-runs and compares with the last run",
+        count_label(visible.len(), saved.len(), &app.filter_text)
+    );
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(theme.highlight)
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(list, area, &mut app.saved_state);
+}
+
+/// Help overlay lines, generated from the active keymap
+fn help_lines(keymap: &Keymap, theme: &Theme) -> Vec<Line<'static>> {
+    let heading = |text: &'static str| {
+        Line::from(Span::styled(text, Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD)))
+    };
+    let binding = |action: Action| Line::from(format!("  {:<12}- {}", keymap.label(action), action.description()));
+
+    let mut lines = vec![
+        Line::from(Span::styled("Hindsight Explorer - Keyboard Shortcuts", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        heading("Navigation Flow"),
+        Line::from(format!("  1. Start by selecting a bank ({}) to open its dashboard", keymap.short_label(Action::Select))),
+        Line::from(format!("  2. Open memories, entities, documents and operations from the dashboard or with {}", keymap.short_label(Action::Command))),
+        Line::from("  3. Use :query to recall/reflect against the bank"),
+        Line::from(""),
+        heading("Basic Navigation"),
+    ];
+    lines.extend([Action::Down, Action::Up, Action::ScrollLeft, Action::ScrollRight, Action::Select, Action::Back].map(binding));
+    lines.extend([Line::from(""), heading("Filter & Commands")]);
+    lines.extend([Action::Filter, Action::Command].map(binding));
+    lines.extend([
+        Line::from("  :dash [bank], :mem [bank], :ent [bank], :doc [bank], :ops [bank], :query [bank]"),
+        Line::from("  :banks, :retain [bank], :consolidate, :refresh, :autorefresh, :help, :quit"),
+        Line::from("  :save <name>, :run <name>, :saved [bank], :tags [tag,...]"),
+        Line::from(""),
+        heading("Query View"),
+    ]);
+    lines.extend(
+        [Action::ToggleMode, Action::CycleBudget, Action::CycleReflectBudget, Action::MoreTokens, Action::FewerTokens, Action::Trace]
+            .map(binding),
+    );
+    lines.push(Line::from(format!("  {:<12}- {}", "↑/↓", "Browse this bank's query history (while editing)")));
+    lines.extend([Line::from(""), heading("Retain")]);
+    lines.extend([
+        binding(Action::Compose),
+        Line::from("  Tab/S-Tab   - Next / previous field (Enter adds a line to the content)"),
+        Line::from("  C-s         - Retain (sync, or async to follow the queued operation)"),
+        Line::from("  C-e         - Edit the whole draft in $VISUAL / $EDITOR"),
+    ]);
+    lines.extend([Line::from(""), heading("Document Viewer")]);
+    lines.extend([
+        Line::from(format!("  {:<12}- {}", format!("{}/{}", keymap.short_label(Action::Down), keymap.short_label(Action::Up)), "Scroll the text, or move through derived memory units")),
+        Line::from(format!("  {:<12}- {}", format!("{}/{}", keymap.short_label(Action::ScrollLeft), keymap.short_label(Action::ScrollRight)), "Switch between text and memory units")),
+        Line::from(format!("  {:<12}- {}", keymap.short_label(Action::Filter), "Search the text")),
+        Line::from(format!("  {:<12}- {}", format!("{}/{}", keymap.short_label(Action::NextPage), keymap.short_label(Action::PrevPage)), "Next / previous match (page down / up without a search)")),
+    ]);
+    lines.extend([Line::from(""), heading("Dashboard")]);
+    lines.push(binding(Action::Consolidate));
+    lines.extend([Line::from(""), heading("Lists")]);
+    lines.extend([Action::NextPage, Action::PrevPage, Action::Delete].map(binding));
+    lines.extend([Line::from(""), heading("Copy & Export")]);
+    lines.extend([
+        binding(Action::Yank),
+        Line::from("              Copies via OSC 52, so it works over SSH (tmux needs allow-passthrough on)"),
+        binding(Action::Export),
+        Line::from("  :export [json|md|path] - Export to a generated file name or the given path"),
+    ]);
+    lines.extend([Line::from(""), heading("General")]);
+    lines.extend([Action::Refresh, Action::Help, Action::Quit].map(binding));
+    lines.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Press {} to close help. Customize keys and colors in ~/.hindsight/explore.toml", keymap.short_label(Action::Help)),
+            Style::default().fg(theme.muted),
+        )),
+    ]);
+    lines
+}
+
+fn render_trace(f: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.theme;
+    if app.trace_tree.is_empty() {
+        if app.loader.is_loading(Resource::Query) || app.loader.is_loading(Resource::Reflect) {
+            render_loading(f, &theme, area, "Trace", "Waiting for the traced query");
+        } else {
+            let hint = Paragraph::new("No trace yet. Run the query (Enter after editing) to capture one.")
+                .style(Style::default().fg(theme.muted))
+                .block(Block::default().borders(Borders::ALL).title("Trace"));
+            f.render_widget(hint, area);
+        }
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .trace_tree
+        .rows()
+        .iter()
+        .map(|row| {
+            let marker = match (row.node.children.is_empty(), row.expanded) {
+                (true, _) => "  ",
+                (false, true) => "▾ ",
+                (false, false) => "▸ ",
+            };
+            let label_style = if row.depth() == 0 {
+                Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{}{}", "  ".repeat(row.depth()), marker)),
+                Span::styled(row.node.label.clone(), label_style),
+                Span::styled(format!("  {}", row.node.detail), Style::default().fg(theme.muted)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Trace (←/→ collapse/expand)"))
+        .highlight_style(theme.highlight)
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(list, area, &mut app.trace_tree.state);
+}
+
+fn render_compose(f: &mut Frame, theme: &Theme, form: &ComposeForm, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),     // Content
+            Constraint::Length(7),  // Other fields
+        ])
+        .split(area);
+
+    let active = |field: Field| form.field == field;
+    let border = |field: Field| {
+        if active(field) { Style::default().fg(theme.secondary) } else { Style::default().fg(theme.primary) }
+    };
+
+    // Content, with a block cursor while it has focus
+    let mut content = form.draft.content.clone();
+    if active(Field::Content) {
+        content.push('█');
+    }
+    let title = if form.draft.document_id.is_empty() {
+        format!("Retain into {} - Content", form.bank_id)
+    } else {
+        format!("Retain into {} - Content (document {})", form.bank_id, form.draft.document_id)
+    };
+    let content_widget = Paragraph::new(content)
+        .style(Style::default().fg(theme.text))
+        .block(Block::default().borders(Borders::ALL).border_style(border(Field::Content)).title(title))
+        .wrap(Wrap { trim: false });
+    f.render_widget(content_widget, chunks[0]);
+
+    let hint = |field: Field| match field {
+        Field::Tags => "comma separated",
+        Field::Timestamp => "RFC 3339 or YYYY-MM-DD",
+        Field::DocumentId => "empty = generated; existing ID replaces that document",
+        Field::Mode => "space toggles sync / async",
+        _ => "",
+    };
+    let lines: Vec<Line> = Field::ALL[1..]
+        .iter()
+        .map(|&field| {
+            let value = form.draft.field(field);
+            let mut spans = vec![Span::styled(
+                format!("{:<12}", field.label()),
+                Style::default().fg(theme.primary).add_modifier(Modifier::BOLD),
+            )];
+            if active(field) {
+                spans.push(Span::styled(format!("{}█", value), Style::default().fg(theme.secondary)));
+                spans.push(Span::styled(format!("  {}", hint(field)), Style::default().fg(theme.muted)));
+            } else {
+                spans.push(Span::styled(value.to_string(), Style::default().fg(theme.text)));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    let details_border = if active(Field::Content) { theme.primary } else { theme.secondary };
+    let fields = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(details_border))
+            .title("Details"),
+    );
+    f.render_widget(fields, chunks[1]);
+}
+
+fn render_help(f: &mut Frame, app: &App, area: Rect) {
+    let help = Paragraph::new(help_lines(&app.keymap, &app.theme))
+        .block(Block::default().borders(Borders::ALL).title("Help"))
+        .alignment(Alignment::Left);
+
+    f.render_widget(help, area);
+}
+
+/// Suspend the TUI and edit the compose draft in `$VISUAL`/`$EDITOR`
+fn edit_compose_externally<B: Backend + io::Write>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
+    let Some(form) = app.compose.as_mut() else {
+        return Ok(());
+    };
+    let path = std::env::temp_dir().join(format!("hindsight-retain-{}.md", std::process::id()));
+    std::fs::write(&path, form.editor_text())?;
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
+    let (program, args) = compose::editor_command();
+    let status = std::process::Command::new(&program).args(&args).arg(&path).status();
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+    match status {
+        Ok(status) if status.success() => match form.apply_editor_text(edited?) {
+            Ok(()) => {
+                app.error_message.clear();
+                app.status_message = "Draft updated from editor - Ctrl+S to retain".to_string();
+            }
+            Err(e) => app.error_message = format!("Invalid draft ({}); Ctrl+E to fix it", e),
+        },
+        Ok(status) => app.error_message = format!("{} exited with {}; draft unchanged", program, status),
+        Err(e) => app.error_message = format!("Failed to start editor '{}': {}", program, e),
+    }
+    Ok(())
+}
+
+fn run_app<B: Backend + io::Write>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
+    // Initial load
+    app.refresh();
+
+    loop {
+        terminal.draw(|f| ui(f, &mut app))?;
+
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                match app.handle_key(key) {
+                    KeyOutcome::Continue => {}
+                    KeyOutcome::Quit => return Ok(()),
+                    KeyOutcome::EditExternally => edit_compose_externally(terminal, &mut app)?,
+                }
+            }
+        }
+
+        // OSC 52 goes straight to the terminal, which sets the system clipboard
+        if let Some(text) = app.clipboard.take() {
+            let backend = terminal.backend_mut();
+            write!(backend, "{}", clipboard::osc52(&text, clipboard::in_tmux()))?;
+            io::Write::flush(backend)?;
+        }
+
+        // Apply results from background loads
+        app.poll_loads();
+
+        // Auto-refresh check
+        app.do_auto_refresh();
+    }
+}
+
+/// Run the explorer; `no_color` (from `--no-color`) forces the monochrome theme
+pub fn run(client: &ApiClient, no_color: bool) -> Result<()> {
+    // Load keymap/theme before touching the terminal so errors print normally
+    let settings = Settings::load(no_color)?;
+
+    // Setup terminal
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    // Create app and run it
+    let mut app = App::new(Arc::new(client.clone()), settings);
+    // A broken history file is reported but never overwritten
+    match QueryStore::load() {
+        Ok(store) => app.query_store = store,
+        Err(e) => app.error_message = format!("Query history disabled: {}", e),
+    }
+    let res = run_app(&mut terminal, app);
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    if let Err(err) = res {
+        println!("Error: {:?}", err);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,271 @@
+//! `:` command palette for the explorer (k9s-style).
+//!
+//! Commands jump between views (`:dash`, `:mem`, `:ent bank-x`, `:ops`) or run
+//! actions (`:refresh`, `:retain`, `:consolidate`, `:export md`, `:save weekly-summary`, `:quit`). Command names accept
+//! unambiguous prefixes and aliases, and Tab completes both the command and
+//! its bank argument.
+
+/// A parsed palette command
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteCommand {
+    Banks,
+    Dashboard(Option<String>),
+    Memories(Option<String>),
+    Entities(Option<String>),
+    Documents(Option<String>),
+    Operations(Option<String>),
+    Query(Option<String>),
+    Saved(Option<String>),
+    /// Save the current query under a name
+    Save(String),
+    /// Re-run a saved query and compare with its stored results
+    Run(String),
+    /// Set the query tags (empty clears them)
+    Tags(Vec<String>),
+    /// Open the compose form to retain a memory
+    Retain(Option<String>),
+    /// Trigger consolidation for the current bank
+    Consolidate,
+    /// Export the current list: `json`/`md` or a file path
+    Export(Option<String>),
+    Refresh,
+    AutoRefresh,
+    Help,
+    Quit,
+}
+
+/// What (if anything) follows the command word
+#[derive(Clone, Copy, PartialEq)]
+enum Arg {
+    None,
+    /// Optional bank ID, defaulting to the current bank
+    Bank,
+    /// Required single word
+    Name,
+    /// Optional export format (`json`, `md`) or file path
+    Path,
+    /// Rest of the line, comma or space separated
+    List,
+}
+
+/// Static description of a palette command
+struct CommandSpec {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    arg: Arg,
+    description: &'static str,
+}
+
+const COMMANDS: &[CommandSpec] = &[
+    CommandSpec { name: "banks", aliases: &["bank", "b"], arg: Arg::None, description: "List banks" },
+    CommandSpec { name: "dashboard", aliases: &["dash", "stats"], arg: Arg::Bank, description: "Bank stats dashboard [bank]" },
+    CommandSpec { name: "memories", aliases: &["mem", "m"], arg: Arg::Bank, description: "Browse memories [bank]" },
+    CommandSpec { name: "entities", aliases: &["ent", "e"], arg: Arg::Bank, description: "Browse entities [bank]" },
+    CommandSpec { name: "documents", aliases: &["doc", "docs", "d"], arg: Arg::Bank, description: "Browse documents [bank]" },
+    CommandSpec { name: "operations", aliases: &["ops", "op", "o"], arg: Arg::Bank, description: "Browse async operations [bank]" },
+    CommandSpec { name: "query", aliases: &["search", "s"], arg: Arg::Bank, description: "Recall/reflect [bank]" },
+    CommandSpec { name: "saved", aliases: &["sq"], arg: Arg::Bank, description: "Browse saved queries [bank]" },
+    CommandSpec { name: "save", aliases: &["w"], arg: Arg::Name, description: "Save the current query <name>" },
+    CommandSpec { name: "run", aliases: &[], arg: Arg::Name, description: "Re-run a saved query and compare <name>" },
+    CommandSpec { name: "tags", aliases: &["tag", "t"], arg: Arg::List, description: "Set query tags [tag,...]" },
+    CommandSpec { name: "retain", aliases: &["compose", "add"], arg: Arg::Bank, description: "Compose a memory to retain [bank]" },
+    CommandSpec { name: "consolidate", aliases: &["cons"], arg: Arg::None, description: "Consolidate the current bank" },
+    CommandSpec { name: "export", aliases: &["exp"], arg: Arg::Path, description: "Export the current list [json|md|path]" },
+    CommandSpec { name: "refresh", aliases: &["r"], arg: Arg::None, description: "Reload current view" },
+    CommandSpec { name: "autorefresh", aliases: &["auto"], arg: Arg::None, description: "Toggle auto-refresh" },
+    CommandSpec { name: "help", aliases: &["h", "?"], arg: Arg::None, description: "Toggle help" },
+    CommandSpec { name: "quit", aliases: &["q", "exit"], arg: Arg::None, description: "Quit the explorer" },
+];
+
+/// Resolve a (possibly abbreviated) command word to its spec.
+///
+/// Exact names and aliases win; otherwise a prefix must identify exactly one
+/// command.
+fn resolve(word: &str) -> Result<&'static CommandSpec, String> {
+    let word = word.to_lowercase();
+    if let Some(spec) = COMMANDS
+        .iter()
+        .find(|c| c.name == word || c.aliases.contains(&word.as_str()))
+    {
+        return Ok(spec);
+    }
+
+    let candidates: Vec<&CommandSpec> = COMMANDS.iter().filter(|c| c.name.starts_with(&word)).collect();
+    match candidates.as_slice() {
+        [spec] => Ok(spec),
+        [] => Err(format!("Unknown command: {}", word)),
+        many => Err(format!(
+            "Ambiguous command '{}': {}",
+            word,
+            many.iter().map(|c| c.name).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+/// Parse a palette input line (without the leading `:`)
+pub fn parse(input: &str) -> Result<PaletteCommand, String> {
+    let input = input.trim();
+    let (word, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+    if word.is_empty() {
+        return Err("Empty command".to_string());
+    }
+    let spec = resolve(word)?;
+
+    let words: Vec<&str> = rest.split_whitespace().collect();
+    let arg = match (spec.arg, words.as_slice()) {
+        (Arg::List, _) => None,
+        (_, []) => None,
+        (Arg::None, _) => return Err(format!("'{}' does not take an argument", spec.name)),
+        (_, [one]) => Some(one.to_string()),
+        _ => return Err("Too many arguments".to_string()),
+    };
+    let name = || arg.clone().ok_or_else(|| format!("'{}' needs a name", spec.name));
+
+    Ok(match spec.name {
+        "banks" => PaletteCommand::Banks,
+        "dashboard" => PaletteCommand::Dashboard(arg),
+        "memories" => PaletteCommand::Memories(arg),
+        "entities" => PaletteCommand::Entities(arg),
+        "documents" => PaletteCommand::Documents(arg),
+        "operations" => PaletteCommand::Operations(arg),
+        "query" => PaletteCommand::Query(arg),
+        "saved" => PaletteCommand::Saved(arg),
+        "save" => PaletteCommand::Save(name()?),
+        "run" => PaletteCommand::Run(name()?),
+        "tags" => PaletteCommand::Tags(
+            rest.split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|t| !t.is_empty())
+                .map(|t| t.to_string())
+                .collect(),
+        ),
+        "retain" => PaletteCommand::Retain(arg),
+        "consolidate" => PaletteCommand::Consolidate,
+        "export" => PaletteCommand::Export(arg),
+        "refresh" => PaletteCommand::Refresh,
+        "autorefresh" => PaletteCommand::AutoRefresh,
+        "help" => PaletteCommand::Help,
+        "quit" => PaletteCommand::Quit,
+        _ => unreachable!("every command spec is handled"),
+    })
+}
+
+/// Completion candidates for the current input.
+///
+/// Each candidate is a full replacement for the input line. While typing the
+/// first word, command names are completed; once a command is followed by a
+/// space, bank IDs (for bank commands), saved query names (for `:run` and
+/// `:save`) or export formats are completed.
+pub fn complete(input: &str, bank_ids: &[String], saved_names: &[String]) -> Vec<String> {
+    let trimmed = input.trim_start();
+    match trimmed.split_once(char::is_whitespace) {
+        None => {
+            let word = trimmed.to_lowercase();
+            COMMANDS
+                .iter()
+                .filter(|c| c.name.starts_with(&word))
+                .map(|c| if c.arg == Arg::None { c.name.to_string() } else { format!("{} ", c.name) })
+                .collect()
+        }
+        Some((word, rest)) => {
+            let formats = ["json".to_string(), "md".to_string()];
+            let (spec, options) = match resolve(word) {
+                Ok(spec) if spec.arg == Arg::Bank => (spec, bank_ids),
+                Ok(spec) if spec.arg == Arg::Name => (spec, saved_names),
+                Ok(spec) if spec.arg == Arg::Path => (spec, &formats[..]),
+                _ => return Vec::new(),
+            };
+            let prefix = rest.trim_start();
+            let mut matches: Vec<&String> = options.iter().filter(|id| id.starts_with(prefix)).collect();
+            matches.sort();
+            matches.into_iter().map(|id| format!("{} {}", spec.name, id)).collect()
+        }
+    }
+}
+
+/// Hint line listing all commands, shown while the palette is open
+pub fn command_hints() -> Vec<(&'static str, &'static str)> {
+    COMMANDS.iter().map(|c| (c.name, c.description)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn banks() -> Vec<String> {
+        vec!["bank-x".to_string(), "bank-y".to_string(), "demo".to_string()]
+    }
+
+    #[test]
+    fn test_parse_view_commands() {
+        assert_eq!(parse("mem"), Ok(PaletteCommand::Memories(None)));
+        assert_eq!(parse("ent bank-x"), Ok(PaletteCommand::Entities(Some("bank-x".to_string()))));
+        assert_eq!(parse("ops"), Ok(PaletteCommand::Operations(None)));
+        assert_eq!(parse("banks"), Ok(PaletteCommand::Banks));
+        assert_eq!(parse("dash demo"), Ok(PaletteCommand::Dashboard(Some("demo".to_string()))));
+        assert_eq!(parse("stats"), Ok(PaletteCommand::Dashboard(None)));
+        assert_eq!(parse("cons"), Ok(PaletteCommand::Consolidate));
+        assert_eq!(parse("export"), Ok(PaletteCommand::Export(None)));
+        assert_eq!(parse("exp out/facts.md"), Ok(PaletteCommand::Export(Some("out/facts.md".to_string()))));
+        assert_eq!(parse("add demo"), Ok(PaletteCommand::Retain(Some("demo".to_string()))));
+        assert_eq!(parse("  docs   demo "), Ok(PaletteCommand::Documents(Some("demo".to_string()))));
+    }
+
+    #[test]
+    fn test_parse_prefix_and_case() {
+        assert_eq!(parse("MEMO"), Ok(PaletteCommand::Memories(None)));
+        assert_eq!(parse("autor"), Ok(PaletteCommand::AutoRefresh));
+        assert_eq!(parse("q"), Ok(PaletteCommand::Quit));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("nope").unwrap_err().contains("Unknown command"));
+        assert!(parse("quit now").is_err());
+        assert!(parse("mem a b").is_err());
+    }
+
+    #[test]
+    fn test_parse_query_commands() {
+        assert_eq!(parse("save weekly"), Ok(PaletteCommand::Save("weekly".to_string())));
+        assert_eq!(parse("run weekly"), Ok(PaletteCommand::Run("weekly".to_string())));
+        assert!(parse("run").unwrap_err().contains("needs a name"));
+        assert_eq!(parse("saved"), Ok(PaletteCommand::Saved(None)));
+        assert_eq!(
+            parse("tags work, travel  urgent"),
+            Ok(PaletteCommand::Tags(vec!["work".to_string(), "travel".to_string(), "urgent".to_string()]))
+        );
+        assert_eq!(parse("tags"), Ok(PaletteCommand::Tags(Vec::new())));
+    }
+
+    #[test]
+    fn test_complete_saved_query_names() {
+        let saved = vec!["weekly".to_string(), "work-notes".to_string()];
+        assert_eq!(complete("run w", &banks(), &saved), vec!["run weekly".to_string(), "run work-notes".to_string()]);
+        assert_eq!(complete("run wee", &banks(), &saved), vec!["run weekly".to_string()]);
+        // Tags have no completion source
+        assert!(complete("tags w", &banks(), &saved).is_empty());
+        assert_eq!(complete("export m", &banks(), &saved), vec!["export md".to_string()]);
+    }
+
+    #[test]
+    fn test_complete_command_names() {
+        assert_eq!(complete("me", &banks(), &[]), vec!["memories ".to_string()]);
+        assert_eq!(complete("ref", &banks(), &[]), vec!["refresh".to_string()]);
+        assert_eq!(complete("re", &banks(), &[]), vec!["retain ".to_string(), "refresh".to_string()]);
+        assert_eq!(complete("", &banks(), &[]).len(), COMMANDS.len());
+        assert!(complete("zzz", &banks(), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_complete_bank_argument() {
+        assert_eq!(
+            complete("ent bank", &banks(), &[]),
+            vec!["entities bank-x".to_string(), "entities bank-y".to_string()]
+        );
+        assert_eq!(complete("mem ", &banks(), &[]).len(), 3);
+        assert_eq!(complete("mem d", &banks(), &[]), vec!["memories demo".to_string()]);
+        // Commands without a bank argument have nothing to complete
+        assert!(complete("refresh x", &banks(), &[]).is_empty());
+    }
+}
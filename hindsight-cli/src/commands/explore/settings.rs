@@ -0,0 +1,265 @@
+//! Explorer settings file (`~/.hindsight/explore.toml`).
+//!
+//! ```toml
+//! keymap = "vim"            # default | vim | emacs
+//! theme = "high-contrast"   # default | high-contrast | monochrome
+//!
+//! [keys]
+//! quit = ["q", "C-q"]
+//! refresh = "F5"
+//!
+//! [colors]
+//! primary = "#ff8800"
+//! highlight = "blue"
+//! ```
+//!
+//! Like the main config file this is parsed by hand: only the simple subset
+//! of TOML above is supported.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+use super::keymap::{Action, KeyBinding, Keymap};
+use super::theme::{self, Theme};
+use crate::config::Config;
+
+const SETTINGS_FILE_NAME: &str = "explore.toml";
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub keymap: Keymap,
+    pub theme: Theme,
+}
+
+#[derive(PartialEq)]
+enum Section {
+    Root,
+    Keys,
+    Colors,
+}
+
+impl Settings {
+    pub fn file_path() -> Option<PathBuf> {
+        Config::config_dir().map(|dir| dir.join(SETTINGS_FILE_NAME))
+    }
+
+    /// Load settings from the config directory, falling back to defaults if
+    /// the file doesn't exist. `--no-color` (`no_color`) and `NO_COLOR` always
+    /// win over the theme.
+    pub fn load(no_color: bool) -> Result<Self> {
+        let no_color = no_color || theme::no_color_requested();
+        let path = match Self::file_path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Self::parse("", no_color).expect("empty settings are valid")),
+        };
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read explorer settings: {}", path.display()))?;
+        Self::parse(&content, no_color)
+            .map_err(|e| anyhow::anyhow!("Invalid explorer settings in {}: {}", path.display(), e))
+    }
+
+    pub fn parse(content: &str, no_color: bool) -> Result<Self, String> {
+        let mut keymap_name = "default".to_string();
+        let mut theme_name = "default".to_string();
+        let mut key_overrides: Vec<(Action, Vec<KeyBinding>)> = Vec::new();
+        let mut color_overrides: Vec<(String, String)> = Vec::new();
+        let mut section = Section::Root;
+
+        for (i, raw_line) in content.lines().enumerate() {
+            let line_no = i + 1;
+            let line = strip_comment(raw_line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = match name.trim() {
+                    "keys" => Section::Keys,
+                    "colors" | "colours" => Section::Colors,
+                    other => return Err(format!("line {}: unknown section [{}]", line_no, other)),
+                };
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", line_no))?;
+            let key = key.trim();
+            let values = parse_values(value.trim());
+
+            match section {
+                Section::Root => {
+                    let value = values.into_iter().next().unwrap_or_default();
+                    match key {
+                        "keymap" => keymap_name = value,
+                        "theme" => theme_name = value,
+                        other => return Err(format!("line {}: unknown setting `{}`", line_no, other)),
+                    }
+                }
+                Section::Keys => {
+                    let action = Action::from_name(key)
+                        .ok_or_else(|| format!("line {}: unknown action `{}`", line_no, key))?;
+                    let keys = values
+                        .iter()
+                        .map(|v| KeyBinding::parse(v))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| format!("line {}: {}", line_no, e))?;
+                    key_overrides.push((action, keys));
+                }
+                Section::Colors => {
+                    let value = values.into_iter().next().unwrap_or_default();
+                    color_overrides.push((key.to_string(), value));
+                }
+            }
+        }
+
+        let mut keymap = Keymap::preset(&keymap_name)
+            .ok_or_else(|| format!("unknown keymap `{}` (expected default, vim or emacs)", keymap_name))?;
+        for (action, keys) in key_overrides {
+            keymap.bind(action, keys);
+        }
+
+        let mut theme = Theme::preset(&theme_name).ok_or_else(|| {
+            format!("unknown theme `{}` (expected default, high-contrast or monochrome)", theme_name)
+        })?;
+        for (name, value) in color_overrides {
+            let color = theme::parse_color(&value)?;
+            theme.set_color(&name, color)?;
+        }
+        if no_color {
+            theme = Theme::monochrome();
+        }
+
+        Ok(Self { keymap, theme })
+    }
+}
+
+/// Drop a trailing `# comment`, ignoring `#` inside quotes (e.g. hex colours)
+fn strip_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parse a value that is either a single (optionally quoted) string or an
+/// array of strings: `"j"`, `j`, `["j", "Down"]`
+fn parse_values(value: &str) -> Vec<String> {
+    match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(items) => split_items(items)
+            .into_iter()
+            .map(unquote)
+            .filter(|s| !s.is_empty())
+            .collect(),
+        None => vec![unquote(value)],
+    }
+}
+
+/// Split array items on the commas outside quotes, so `","` is a value
+fn split_items(items: &str) -> Vec<&str> {
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    let mut parts = Vec::new();
+    for (i, c) in items.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, ',') => {
+                parts.push(&items[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&items[start..]);
+    parts
+}
+
+/// Trim, then drop one pair of matching quotes
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    ['"', '\'']
+        .iter()
+        .find_map(|&q| value.strip_prefix(q).and_then(|v| v.strip_suffix(q)))
+        .unwrap_or(value)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_empty_file_uses_defaults() {
+        let settings = Settings::parse("", false).unwrap();
+        assert_eq!(settings.theme, Theme::brand());
+        assert_eq!(settings.keymap.label(Action::Quit), "q");
+    }
+
+    #[test]
+    fn test_presets_and_overrides() {
+        let content = r##"
+# Explorer settings
+keymap = "emacs"
+theme = 'high-contrast'  # easier to read
+
+[keys]
+quit = ["q", "C-q"]
+refresh = F5
+
+[colors]
+primary = "#ff8800"  # orange
+"##;
+        let settings = Settings::parse(content, false).unwrap();
+        assert_eq!(settings.keymap.label(Action::Quit), "q/C-q");
+        assert_eq!(
+            settings.keymap.action_for(&KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)),
+            Some(Action::Refresh)
+        );
+        // Emacs preset is the base
+        assert_eq!(settings.keymap.label(Action::Command), "M-x/:");
+        assert_eq!(settings.theme.primary, Color::Rgb(255, 136, 0));
+        assert_eq!(settings.theme.secondary, Theme::high_contrast().secondary);
+    }
+
+    #[test]
+    fn test_quoted_commas() {
+        assert_eq!(parse_values(r#"[",", "a, b", 'x']"#), vec![",", "a, b", "x"]);
+        assert_eq!(parse_values(r#"",""#), vec![","]);
+        assert_eq!(parse_values("[j, Down]"), vec!["j", "Down"]);
+
+        let settings = Settings::parse("[keys]\nquit = [\",\", \"q\"]", false).unwrap();
+        assert_eq!(
+            settings.keymap.action_for(&KeyEvent::new(KeyCode::Char(','), KeyModifiers::NONE)),
+            Some(Action::Quit)
+        );
+    }
+
+    #[test]
+    fn test_no_color_forces_monochrome() {
+        let settings = Settings::parse("theme = \"high-contrast\"\n[colors]\nerror = \"red\"", true).unwrap();
+        assert_eq!(settings.theme, Theme::monochrome());
+    }
+
+    #[test]
+    fn test_errors_name_the_line() {
+        let err = Settings::parse("keymap = \"vim\"\n[keys]\nfly = \"f\"", false).unwrap_err();
+        assert!(err.contains("line 3"), "{}", err);
+        assert!(err.contains("fly"), "{}", err);
+
+        assert!(Settings::parse("keymap = \"nano\"", false).unwrap_err().contains("unknown keymap"));
+        assert!(Settings::parse("[colors]\nprimary = \"nope\"", false).is_err());
+        assert!(Settings::parse("[widgets]", false).is_err());
+        assert!(Settings::parse("just text", false).is_err());
+    }
+}
@@ -0,0 +1,337 @@
+//! Snapshot tests for the explorer.
+//!
+//! The App is driven with scripted key events against [`FakeApi`], rendered
+//! with ratatui's `TestBackend` and compared with the text snapshots in
+//! `snapshots/`. Run with `UPDATE_SNAPSHOTS=1` to (re)write them after an
+//! intentional UI change, and review the diff like any other change.
+
+use super::*;
+use anyhow::anyhow;
+use hindsight_client::types::{
+    ChunkResponse, ConsolidationResponse, DeleteResponse, DocumentResponse, EntityListResponse, ListDocumentsResponse,
+    ListMemoryUnitsResponse, RecallResponse, ReflectResponse, RetainRequest,
+};
+use ratatui::backend::TestBackend;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::api::{MemoryPutResult, OperationsResponse};
+
+const WIDTH: u16 = 100;
+const HEIGHT: u16 = 30;
+
+fn from_json<T: DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).expect("fixture matches the API type")
+}
+
+/// Canned bank `demo` with a few memories, entities and one document.
+/// Calls are recorded so tests can check what the App asked for.
+struct FakeApi {
+    calls: Mutex<Vec<String>>,
+}
+
+impl FakeApi {
+    fn new() -> Self {
+        Self { calls: Mutex::new(Vec::new()) }
+    }
+
+    fn record(&self, call: String) {
+        self.calls.lock().unwrap().push(call);
+    }
+
+    fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn memories() -> Vec<Value> {
+        vec![
+            json!({
+                "id": "m1", "fact_type": "world", "text": "Alice works at Acme as a data engineer",
+                "mentioned_at": "2024-03-01T10:00:00Z", "occurred_start": "2024-03-01T00:00:00Z",
+                "chunk_id": "demo_notes_0",
+            }),
+            json!({
+                "id": "m2", "fact_type": "experience", "text": "Alice prefers tea over coffee",
+                "mentioned_at": "2024-03-02T09:30:00Z", "chunk_id": "demo_notes_1",
+            }),
+            json!({
+                "id": "m3", "fact_type": "observation", "text": "Bob is planning a trip to Lisbon",
+                "mentioned_at": "2024-03-05T18:15:00Z",
+            }),
+        ]
+    }
+}
+
+impl ExploreApi for FakeApi {
+    fn list_banks(&self) -> Result<Vec<BankListItem>> {
+        let disposition = json!({"empathy": 3, "literalism": 3, "skepticism": 3});
+        Ok(from_json(json!([
+            {"bank_id": "demo", "name": "Demo Bank", "disposition": disposition},
+            {"bank_id": "empty", "name": "Empty Bank", "disposition": disposition},
+        ])))
+    }
+
+    fn get_stats(&self, bank_id: &str) -> Result<AgentStats> {
+        Ok(from_json(json!({
+            "bank_id": bank_id,
+            "total_nodes": 3,
+            "total_links": 4,
+            "total_documents": 1,
+            "nodes_by_fact_type": {"world": 1, "experience": 1, "observation": 1},
+            "links_by_link_type": {"entity": 3, "temporal": 1},
+            "links_by_fact_type": {"world": 2, "experience": 2},
+            "links_breakdown": {},
+            "pending_operations": 0,
+            "failed_operations": 0,
+        })))
+    }
+
+    fn list_memories(&self, _bank_id: &str, limit: i64, offset: i64) -> Result<ListMemoryUnitsResponse> {
+        let items = Self::memories();
+        Ok(from_json(json!({"items": items, "limit": limit, "offset": offset, "total": 3})))
+    }
+
+    fn list_entities(&self, _bank_id: &str, limit: i64) -> Result<EntityListResponse> {
+        Ok(from_json(json!({
+            "items": [
+                {"id": "e1", "canonical_name": "Alice", "mention_count": 2},
+                {"id": "e2", "canonical_name": "Bob", "mention_count": 1},
+            ],
+            "limit": limit, "offset": 0, "total": 2,
+        })))
+    }
+
+    fn list_documents(&self, bank_id: &str, limit: i32, offset: i32) -> Result<ListDocumentsResponse> {
+        Ok(from_json(json!({
+            "items": [{
+                "id": "notes", "bank_id": bank_id, "created_at": "2024-03-01T10:00:00Z",
+                "updated_at": "2024-03-01T10:00:00Z", "text_length": 64, "memory_unit_count": 2, "tags": [],
+            }],
+            "limit": limit, "offset": offset, "total": 1,
+        })))
+    }
+
+    fn list_operations(&self, bank_id: &str) -> Result<OperationsResponse> {
+        Ok(from_json(json!({
+            "bank_id": bank_id,
+            "operations": [{
+                "id": "op-1", "task_type": "retain", "items_count": 1, "document_id": "notes",
+                "created_at": "2024-03-01T10:00:00Z", "status": "completed", "error_message": null,
+            }],
+        })))
+    }
+
+    fn get_document(&self, bank_id: &str, document_id: &str) -> Result<DocumentResponse> {
+        Ok(from_json(json!({
+            "id": document_id, "bank_id": bank_id, "created_at": "2024-03-01T10:00:00Z",
+            "updated_at": "2024-03-01T10:00:00Z", "memory_unit_count": 2, "tags": ["work"],
+            "original_text": "Alice works at Acme.\nShe is a data engineer.\nAlice prefers tea.",
+        })))
+    }
+
+    fn get_chunk(&self, chunk_id: &str) -> Result<ChunkResponse> {
+        let text = match chunk_id {
+            "demo_notes_0" => "Alice works at Acme.\nShe is a data engineer.\n",
+            "demo_notes_1" => "Alice prefers tea.",
+            _ => return Err(anyhow!("Chunk not found")),
+        };
+        let index = chunk_id.rsplit('_').next().unwrap_or_default().parse::<i64>()?;
+        Ok(from_json(json!({
+            "chunk_id": chunk_id, "bank_id": "demo", "document_id": "notes", "chunk_index": index,
+            "chunk_text": text, "created_at": "2024-03-01T10:00:00Z",
+        })))
+    }
+
+    fn delete_document(&self, bank_id: &str, document_id: &str) -> Result<DeleteResponse> {
+        self.record(format!("delete_document {} {}", bank_id, document_id));
+        Ok(DeleteResponse { deleted_count: Some(2), message: None, success: true })
+    }
+
+    fn recall(&self, bank_id: &str, request: &RecallRequest) -> Result<RecallResponse> {
+        self.record(format!("recall {} {:?}", bank_id, request.query));
+        Ok(from_json(json!({
+            "results": [
+                {"id": "m1", "text": "Alice works at Acme as a data engineer", "type": "world"},
+                {"id": "m2", "text": "Alice prefers tea over coffee", "type": "experience"},
+            ],
+        })))
+    }
+
+    fn reflect(&self, bank_id: &str, request: &ReflectRequest) -> Result<ReflectResponse> {
+        self.record(format!("reflect {} {:?}", bank_id, request.query));
+        Ok(from_json(json!({"text": "Alice is a data engineer at Acme who prefers tea."})))
+    }
+
+    fn retain(&self, bank_id: &str, request: &RetainRequest, _async_mode: bool) -> Result<MemoryPutResult> {
+        self.record(format!("retain {} {} item(s)", bank_id, request.items.len()));
+        Ok(MemoryPutResult {
+            success: true,
+            items_count: request.items.len() as i64,
+            message: String::new(),
+            is_async: false,
+            operation_id: None,
+        })
+    }
+
+    fn trigger_consolidation(&self, bank_id: &str) -> Result<ConsolidationResponse> {
+        self.record(format!("consolidate {}", bank_id));
+        Ok(from_json(json!({"operation_id": "op-2", "deduplicated": false})))
+    }
+}
+
+/// An App on a fake backend and an in-memory terminal
+struct Harness {
+    api: Arc<FakeApi>,
+    app: App,
+    terminal: Terminal<TestBackend>,
+}
+
+impl Harness {
+    /// Start on the bank list with the initial load finished
+    fn new() -> Self {
+        let api = Arc::new(FakeApi::new());
+        let settings = Settings::parse("", false).unwrap();
+        let mut app = App::new(api.clone(), settings);
+        // Auto-refresh would make renders depend on timing
+        app.auto_refresh_enabled = false;
+        let terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        let mut harness = Self { api, app, terminal };
+        harness.app.refresh();
+        harness.settle();
+        harness
+    }
+
+    /// Apply background loads until nothing is in flight
+    fn settle(&mut self) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            self.app.poll_loads();
+            if self.app.loader.is_idle() {
+                break;
+            }
+            assert!(Instant::now() < deadline, "background loads did not finish");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    fn key(&mut self, code: KeyCode) -> KeyOutcome {
+        self.key_with(code, KeyModifiers::NONE)
+    }
+
+    fn key_with(&mut self, code: KeyCode, modifiers: KeyModifiers) -> KeyOutcome {
+        let outcome = self.app.handle_key(KeyEvent::new(code, modifiers));
+        self.settle();
+        outcome
+    }
+
+    /// Type each character as a separate key press
+    fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.key(KeyCode::Char(c));
+        }
+    }
+
+    /// Run a command palette command
+    fn command(&mut self, command: &str) {
+        self.type_text(":");
+        self.type_text(command);
+        self.key(KeyCode::Enter);
+    }
+
+    /// Render one frame and return the screen as text, one line per row
+    fn render(&mut self) -> String {
+        self.terminal.draw(|f| ui(f, &mut self.app)).unwrap();
+        let buffer = self.terminal.backend().buffer();
+        let mut screen = String::new();
+        for y in 0..buffer.area.height {
+            let row: String = (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect();
+            screen.push_str(row.trim_end());
+            screen.push('\n');
+        }
+        screen
+    }
+
+    fn assert_snapshot(&mut self, name: &str) {
+        let screen = self.render();
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/commands/explore/snapshots")
+            .join(format!("{}.txt", name));
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &screen).unwrap();
+            return;
+        }
+        let expected = std::fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("missing snapshot {}; run with UPDATE_SNAPSHOTS=1", path.display()));
+        assert!(
+            screen == expected,
+            "snapshot {} differs; run with UPDATE_SNAPSHOTS=1 to accept\n--- expected\n{}\n--- actual\n{}",
+            name,
+            expected,
+            screen
+        );
+    }
+}
+
+#[test]
+fn test_banks_view() {
+    let mut h = Harness::new();
+    h.assert_snapshot("banks");
+
+    h.key(KeyCode::Down);
+    assert_eq!(h.app.banks_state.selected(), Some(1));
+}
+
+#[test]
+fn test_dashboard() {
+    let mut h = Harness::new();
+    h.key(KeyCode::Enter);
+    assert_eq!(h.app.view, View::Dashboard("demo".to_string()));
+    h.assert_snapshot("dashboard");
+}
+
+#[test]
+fn test_memories_filter() {
+    let mut h = Harness::new();
+    h.key(KeyCode::Enter);
+    h.command("memories");
+    h.assert_snapshot("memories");
+
+    h.type_text("/alice");
+    h.key(KeyCode::Enter);
+    h.assert_snapshot("memories_filtered");
+}
+
+#[test]
+fn test_document_viewer() {
+    let mut h = Harness::new();
+    h.key(KeyCode::Enter);
+    h.command("documents");
+    h.key(KeyCode::Enter);
+    assert!(h.app.viewing_document.is_some());
+    h.assert_snapshot("document");
+}
+
+#[test]
+fn test_recall_query() {
+    let mut h = Harness::new();
+    h.key(KeyCode::Enter);
+    h.command("query");
+    // The query view starts with the query input focused
+    h.type_text("what does alice do");
+    h.key(KeyCode::Enter);
+    assert_eq!(h.api.calls(), vec!["recall demo \"what does alice do\"".to_string()]);
+    h.assert_snapshot("recall");
+}
+
+#[test]
+fn test_help_and_quit() {
+    let mut h = Harness::new();
+    h.type_text("?");
+    h.assert_snapshot("help");
+
+    assert_eq!(h.key(KeyCode::Char('q')), KeyOutcome::Quit);
+    assert_eq!(h.key_with(KeyCode::Char('c'), KeyModifiers::CONTROL), KeyOutcome::Quit);
+}
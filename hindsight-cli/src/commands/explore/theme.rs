@@ -0,0 +1,172 @@
+//! Colour themes for the explorer.
+//!
+//! Rendering code asks the [`Theme`] for semantic colours (primary, accent,
+//! error, ...) instead of using the brand colours directly. `--no-color` and
+//! `NO_COLOR` force the monochrome theme, which relies on bold/reverse instead of colour.
+
+use ratatui::style::{Color, Modifier, Style};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Borders, headers and table column titles
+    pub primary: Color,
+    /// Context info, input prompts and secondary shortcuts
+    pub secondary: Color,
+    /// Status messages and in-between shortcuts
+    pub accent: Color,
+    /// Regular list and body text
+    pub text: Color,
+    /// Hints and de-emphasised text
+    pub muted: Color,
+    /// Errors, destructive shortcuts and failed items
+    pub error: Color,
+    /// Selected list row
+    pub highlight: Style,
+}
+
+impl Theme {
+    /// Look up a theme by name (`default`, `high-contrast`, `monochrome`)
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace('_', "-").as_str() {
+            "default" | "brand" => Some(Self::brand()),
+            "high-contrast" | "contrast" => Some(Self::high_contrast()),
+            "monochrome" | "mono" | "none" => Some(Self::monochrome()),
+            _ => None,
+        }
+    }
+
+    /// Brand gradient colors: #0074d9 -> #009296
+    pub fn brand() -> Self {
+        Self {
+            primary: Color::Rgb(0, 116, 217),  // #0074d9
+            secondary: Color::Rgb(0, 146, 150), // #009296
+            accent: Color::Rgb(0, 131, 183),    // Midpoint
+            text: Color::White,
+            muted: Color::DarkGray,
+            error: Color::Red,
+            highlight: Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Bright ANSI colours that stay legible on dark and light terminals
+    pub fn high_contrast() -> Self {
+        Self {
+            primary: Color::LightYellow,
+            secondary: Color::LightCyan,
+            accent: Color::White,
+            text: Color::White,
+            muted: Color::Gray,
+            error: Color::LightRed,
+            highlight: Style::default().fg(Color::Black).bg(Color::LightYellow).add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Terminal default colours only; selection is shown reversed
+    pub fn monochrome() -> Self {
+        Self {
+            primary: Color::Reset,
+            secondary: Color::Reset,
+            accent: Color::Reset,
+            text: Color::Reset,
+            muted: Color::Reset,
+            error: Color::Reset,
+            highlight: Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+        }
+    }
+
+    /// Override one colour by name, as used by the `[colors]` config section
+    pub fn set_color(&mut self, name: &str, color: Color) -> Result<(), String> {
+        match name {
+            "primary" => self.primary = color,
+            "secondary" => self.secondary = color,
+            "accent" => self.accent = color,
+            "text" => self.text = color,
+            "muted" => self.muted = color,
+            "error" => self.error = color,
+            "highlight" => self.highlight = Style::default().bg(color).add_modifier(Modifier::BOLD),
+            _ => return Err(format!("Unknown theme color: {}", name)),
+        }
+        Ok(())
+    }
+}
+
+/// Whether `NO_COLOR` asks us to disable colour (set and non-empty, per no-color.org)
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").map(|v| !v.is_empty()).unwrap_or(false)
+}
+
+/// Parse `#rrggbb` or an ANSI colour name (`red`, `light-blue`, `darkgray`, `reset`, ...)
+pub fn parse_color(input: &str) -> Result<Color, String> {
+    let value = input.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() == 6 {
+            if let Ok(rgb) = u32::from_str_radix(hex, 16) {
+                return Ok(Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8));
+            }
+        }
+        return Err(format!("Invalid hex color: {}", input));
+    }
+
+    let name = value.to_lowercase().replace(['-', '_', ' '], "");
+    let color = match name.as_str() {
+        "reset" | "default" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        _ => return Err(format!("Unknown color: {}", input)),
+    };
+    Ok(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#0074d9"), Ok(Color::Rgb(0, 116, 217)));
+        assert_eq!(parse_color("Light-Blue"), Ok(Color::LightBlue));
+        assert_eq!(parse_color("dark_gray"), Ok(Color::DarkGray));
+        assert!(parse_color("#12345").is_err());
+        assert!(parse_color("#gggggg").is_err());
+        assert!(parse_color("chartreuse").is_err());
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(Theme::preset("default"), Some(Theme::brand()));
+        assert_eq!(Theme::preset("High_Contrast"), Some(Theme::high_contrast()));
+        assert_eq!(Theme::preset("mono"), Some(Theme::monochrome()));
+        assert!(Theme::preset("solarized").is_none());
+    }
+
+    #[test]
+    fn test_monochrome_uses_no_colors() {
+        let theme = Theme::monochrome();
+        for color in [theme.primary, theme.secondary, theme.accent, theme.text, theme.muted, theme.error] {
+            assert_eq!(color, Color::Reset);
+        }
+        assert!(theme.highlight.add_modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_set_color() {
+        let mut theme = Theme::brand();
+        theme.set_color("error", Color::Magenta).unwrap();
+        assert_eq!(theme.error, Color::Magenta);
+        assert!(theme.set_color("sparkle", Color::Red).is_err());
+    }
+}
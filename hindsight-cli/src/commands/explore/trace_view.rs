@@ -0,0 +1,161 @@
+//! Collapsible trace tree for the Query view.
+//!
+//! Nodes are addressed by their path of child indexes from the roots, and the
+//! tree is flattened into visible rows on every render so expanding or
+//! collapsing a node is just a change to the `expanded` set.
+
+use ratatui::widgets::ListState;
+use std::collections::HashSet;
+
+use crate::trace::TraceNode;
+
+/// A visible row of the flattened tree
+pub struct Row<'a> {
+    pub path: Vec<usize>,
+    pub node: &'a TraceNode,
+    pub expanded: bool,
+}
+
+impl Row<'_> {
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+}
+
+#[derive(Default)]
+pub struct TraceTree {
+    roots: Vec<TraceNode>,
+    expanded: HashSet<Vec<usize>>,
+    pub state: ListState,
+}
+
+impl TraceTree {
+    /// Roots and their direct children start expanded
+    pub fn new(roots: Vec<TraceNode>) -> Self {
+        let mut expanded = HashSet::new();
+        for (i, root) in roots.iter().enumerate() {
+            expanded.insert(vec![i]);
+            for (j, child) in root.children.iter().enumerate() {
+                if !child.children.is_empty() && child.children.len() <= 10 {
+                    expanded.insert(vec![i, j]);
+                }
+            }
+        }
+        let mut state = ListState::default();
+        if !roots.is_empty() {
+            state.select(Some(0));
+        }
+        Self { roots, expanded, state }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    pub fn rows(&self) -> Vec<Row<'_>> {
+        fn walk<'a>(node: &'a TraceNode, path: Vec<usize>, expanded: &HashSet<Vec<usize>>, rows: &mut Vec<Row<'a>>) {
+            let open = expanded.contains(&path);
+            rows.push(Row { path: path.clone(), node, expanded: open });
+            if open {
+                for (i, child) in node.children.iter().enumerate() {
+                    let mut child_path = path.clone();
+                    child_path.push(i);
+                    walk(child, child_path, expanded, rows);
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        for (i, root) in self.roots.iter().enumerate() {
+            walk(root, vec![i], &self.expanded, &mut rows);
+        }
+        rows
+    }
+
+    fn selected_row(&self) -> Option<(Vec<usize>, bool, bool)> {
+        let rows = self.rows();
+        let row = rows.get(self.state.selected()?)?;
+        Some((row.path.clone(), row.expanded, !row.node.children.is_empty()))
+    }
+
+    pub fn move_by(&mut self, delta: isize) {
+        let len = self.rows().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0) as isize;
+        self.state.select(Some((current + delta).clamp(0, len as isize - 1) as usize));
+    }
+
+    pub fn expand(&mut self) {
+        if let Some((path, false, true)) = self.selected_row() {
+            self.expanded.insert(path);
+        }
+    }
+
+    /// Collapse the selected node, or move to its parent if it is already closed
+    pub fn collapse(&mut self) {
+        let Some((path, expanded, _)) = self.selected_row() else {
+            return;
+        };
+        if expanded {
+            self.expanded.remove(&path);
+        } else if path.len() > 1 {
+            let parent = &path[..path.len() - 1];
+            let index = self.rows().iter().position(|row| row.path == parent);
+            self.state.select(index);
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        match self.selected_row() {
+            Some((_, true, _)) => self.collapse(),
+            Some(_) => self.expand(),
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(label: &str, children: Vec<TraceNode>) -> TraceNode {
+        let mut node = TraceNode::new(label, "");
+        node.children = children;
+        node
+    }
+
+    fn labels(tree: &TraceTree) -> Vec<String> {
+        tree.rows().iter().map(|r| format!("{}{}", "  ".repeat(r.depth()), r.node.label)).collect()
+    }
+
+    fn tree() -> TraceTree {
+        TraceTree::new(vec![node(
+            "root",
+            vec![node("stages", vec![node("embed", vec![node("detail", vec![])])]), node("results", vec![])],
+        )])
+    }
+
+    #[test]
+    fn test_initial_rows() {
+        // Root and its children are open; deeper levels start collapsed
+        assert_eq!(labels(&tree()), vec!["root", "  stages", "    embed", "  results"]);
+    }
+
+    #[test]
+    fn test_expand_and_collapse() {
+        let mut tree = tree();
+        tree.move_by(2);
+        tree.expand();
+        assert_eq!(labels(&tree), vec!["root", "  stages", "    embed", "      detail", "  results"]);
+
+        // Collapsing an open node closes it; collapsing again moves to the parent
+        tree.collapse();
+        assert_eq!(tree.rows().len(), 4);
+        tree.collapse();
+        assert_eq!(tree.state.selected(), Some(1));
+        tree.toggle();
+        assert_eq!(labels(&tree), vec!["root", "  stages", "  results"]);
+    }
+}
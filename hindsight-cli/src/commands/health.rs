@@ -1,18 +1,59 @@
 //! Health and metrics commands.
 
-use anyhow::Result;
-use serde::Deserialize;
+use anyhow::{Context, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use crate::api::ApiClient;
+use crate::metrics::{self, Filter, Sample};
 use crate::output::{self, OutputFormat};
+use crate::table::{self, Table};
 use crate::ui;
 
-// Local type for health response
-#[derive(Debug, Deserialize)]
-struct HealthResponse {
-    status: String,
-    database: Option<String>,
-    version: Option<String>,
+/// `/health` response (untyped in the OpenAPI spec), printed as-is by `health`
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct HealthResponse {
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Why the server is unhealthy, e.g. `not_initialized`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// `metrics` output for JSON and YAML
+#[derive(Debug, Serialize, JsonSchema)]
+pub(crate) struct MetricsOutput {
+    pub metrics: Vec<Sample>,
+}
+
+/// A sample value as Prometheus writes it: `+Inf`, `NaN`, or the shortest decimal
+fn format_value(value: f64) -> String {
+    match value {
+        v if v.is_nan() => "NaN".to_string(),
+        v if v == f64::INFINITY => "+Inf".to_string(),
+        v if v == f64::NEG_INFINITY => "-Inf".to_string(),
+        v => v.to_string(),
+    }
+}
+
+/// Rows of `metrics`, labels written as in the exposition text
+fn metrics_table(samples: &[Sample]) -> Table {
+    let mut table = Table::new(&["name", "type", "labels", "value"]).with_default_columns(&["name", "labels", "value"]);
+    for sample in samples {
+        let labels: Vec<String> = sample.labels.iter().map(|(k, v)| format!("{}={:?}", k, v)).collect();
+        table.push(vec![
+            sample.name.clone(),
+            sample.kind.clone().unwrap_or_default(),
+            labels.join(","),
+            format_value(sample.value),
+        ]);
+    }
+    table
 }
 
 /// Check API health
@@ -35,13 +76,9 @@ pub fn health(
 
     match response {
         Ok(value) => {
+            let result: HealthResponse = serde_json::from_value(value)
+                .with_context(|| "Failed to parse health response")?;
             if output_format == OutputFormat::Pretty {
-                let result: HealthResponse = serde_json::from_value(value.clone())
-                    .unwrap_or(HealthResponse {
-                        status: "unknown".to_string(),
-                        database: None,
-                        version: None,
-                    });
 
                 let status_str = if result.status == "healthy" {
                     ui::gradient_start(&result.status)
@@ -67,7 +104,7 @@ pub fn health(
 
                 println!();
             } else {
-                output::print_output(&value, output_format)?;
+                output::print_output(&result, output_format)?;
             }
             Ok(())
         }
@@ -114,9 +151,10 @@ pub fn version(
     }
 }
 
-/// Get Prometheus metrics
+/// Get Prometheus metrics, parsed into samples and narrowed by `filters`
 pub fn metrics(
     client: &ApiClient,
+    filters: &[Filter],
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
@@ -132,20 +170,21 @@ pub fn metrics(
         sp.finish();
     }
 
-    match response {
-        Ok(result) => {
-            if output_format == OutputFormat::Pretty {
-                ui::print_section_header("Prometheus Metrics");
-                println!("{}", result);
-            } else {
-                // For JSON/YAML, wrap in an object
-                let wrapped = serde_json::json!({ "metrics": result });
-                output::print_output(&wrapped, output_format)?;
-            }
-            Ok(())
+    let samples = metrics::filter(metrics::parse(&response?)?, filters);
+    if output_format == OutputFormat::Pretty {
+        if samples.is_empty() {
+            ui::print_info("No metrics found");
+        } else {
+            ui::print_section_header("Prometheus Metrics");
+            table::print(&metrics_table(&samples))?;
         }
-        Err(e) => Err(e),
+    } else if output_format == OutputFormat::Ndjson {
+        output::print_records(&samples)?;
+    } else {
+        let result = MetricsOutput { metrics: samples };
+        output::print_list(&result, output_format, || metrics_table(&result.metrics))?;
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -193,4 +232,14 @@ mod tests {
         assert_eq!(result.status, "unhealthy");
         assert_eq!(result.database, Some("disconnected".to_string()));
     }
+
+    #[test]
+    fn test_metrics_table() {
+        let samples = metrics::parse("# TYPE up gauge\nup{job=\"api\",instance=\"a:1\"} 1\nlatency_bucket{le=\"+Inf\"} 3.5\n").unwrap();
+        let table = metrics_table(&samples);
+        assert_eq!(table.rows[0], vec!["up", "gauge", "instance=\"a:1\",job=\"api\"", "1"]);
+        assert_eq!(table.rows[1], vec!["latency_bucket", "", "le=\"+Inf\"", "3.5"]);
+        assert_eq!(format_value(f64::INFINITY), "+Inf");
+        assert_eq!(format_value(f64::NAN), "NaN");
+    }
 }
@@ -5,33 +5,67 @@ use walkdir::WalkDir;
 
 use crate::api::{ApiClient, RecallRequest, ReflectRequest, MemoryItem, RetainRequest};
 use crate::config;
-use crate::output::{self, OutputFormat};
+use crate::markdown;
+use crate::output::{self, OutputFormat, Page};
+use crate::recall::GroupBy;
+use crate::table::{self, Table};
 use crate::ui;
 
 // Import types from generated client
-use hindsight_client::types::{Budget, ChunkIncludeOptions, IncludeOptions, TagsMatch};
-use serde::Deserialize;
+use hindsight_client::types::{
+    Budget, ChunkIncludeOptions, IncludeOptions, ReflectIncludeOptions, TagsMatch, ToolCallsIncludeOptions,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use serde_json;
 
-// Local types for serde_json::Value deserialization
-#[derive(Debug, Deserialize)]
-struct MemoryUnitDetail {
-    id: String,
-    text: String,
+/// `memory get` output: the memory unit as the API returns it (the endpoint
+/// is untyped in the OpenAPI spec)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct MemoryUnitDetail {
+    pub id: String,
+    pub text: String,
     #[serde(rename = "type")]
-    type_: Option<String>,
-    document_id: Option<String>,
-    context: Option<String>,
-    occurred_start: Option<String>,
-    occurred_end: Option<String>,
-    entities: Option<Vec<EntityRef>>,
-    tags: Option<Vec<String>>,
+    pub type_: Option<String>,
+    pub context: Option<String>,
+    pub date: Option<String>,
+    pub mentioned_at: Option<String>,
+    pub occurred_start: Option<String>,
+    pub occurred_end: Option<String>,
+    /// Canonical names of the entities mentioned
+    #[serde(default)]
+    pub entities: Vec<String>,
+    pub document_id: Option<String>,
+    pub chunk_id: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Memories an observation was consolidated from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_memory_ids: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_memories: Option<Vec<SourceMemory>>,
+}
+
+/// A memory an observation is based on
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct SourceMemory {
+    pub id: String,
+    pub text: String,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub context: Option<String>,
+    pub occurred_start: Option<String>,
+    pub mentioned_at: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-struct EntityRef {
-    id: String,
-    name: String,
+/// `retain-files` output for JSON and YAML
+#[derive(Debug, Serialize, JsonSchema)]
+pub(crate) struct RetainFilesOutput {
+    pub success: bool,
+    pub files_count: usize,
+    pub operation_ids: Vec<String>,
+    /// Queued with `--async`; the operations may still be running
+    pub is_async: bool,
 }
 
 // Helper function to parse budget string to Budget enum
@@ -43,6 +77,40 @@ fn parse_budget(budget: &str) -> Budget {
     }
 }
 
+/// Rows of `memory list`. `type` is the fact type; `document_id` comes from
+/// the chunk ID, which is `{bank}_{document}_{index}`.
+fn memories_table(bank_id: &str, items: &[serde_json::Map<String, serde_json::Value>]) -> Table {
+    let mut table = Table::new(&[
+        "id", "type", "text", "context", "date", "mentioned_at", "occurred_start", "occurred_end", "entities",
+        "chunk_id", "document_id",
+    ])
+    .with_default_columns(&["id", "type", "text", "document_id"]);
+    for item in items {
+        let field = |key: &str| table::cell(item.get(key));
+        let chunk_id = field("chunk_id");
+        let document_id = chunk_id
+            .strip_prefix(bank_id)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .and_then(|rest| rest.rsplit_once('_'))
+            .map(|(document_id, _)| document_id.to_string())
+            .unwrap_or_default();
+        table.push(vec![
+            field("id"),
+            field("fact_type"),
+            field("text"),
+            field("context"),
+            field("date"),
+            field("mentioned_at"),
+            field("occurred_start"),
+            field("occurred_end"),
+            field("entities"),
+            chunk_id,
+            document_id,
+        ]);
+    }
+    table
+}
+
 /// List memory units with pagination and optional filters
 pub fn list(
     client: &ApiClient,
@@ -54,6 +122,14 @@ pub fn list(
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
+    // NDJSON streams every page from `offset`, `limit` items at a time
+    if output_format == OutputFormat::Ndjson {
+        return output::stream_pages(std::io::stdout().lock(), offset, limit, |offset, limit| {
+            let page = client.list_memories(bank_id, type_filter.as_deref(), query.as_deref(), Some(limit), Some(offset), verbose)?;
+            Ok(Page { items: page.items, total: page.total })
+        });
+    }
+
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching memories..."))
     } else {
@@ -81,44 +157,12 @@ pub fn list(
                 if result.items.is_empty() {
                     println!("  {}", ui::dim("No memories found."));
                 } else {
-                    for item in &result.items {
-                        let fact_type = item.get("type")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("unknown");
-                        let type_t = match fact_type {
-                            "world" => 0.0,
-                            "experience" => 0.5,
-                            "opinion" => 1.0,
-                            _ => 0.5,
-                        };
-
-                        let id = item.get("id")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("unknown");
-
-                        println!(
-                            "  {} {}",
-                            ui::gradient(&format!("[{}]", fact_type.to_uppercase()), type_t),
-                            ui::dim(id)
-                        );
-
-                        // Truncate text if too long
-                        if let Some(text) = item.get("text").and_then(|v| v.as_str()) {
-                            let text_preview: String = text.chars().take(100).collect();
-                            let ellipsis = if text.len() > 100 { "..." } else { "" };
-                            println!("    {}{}", text_preview, ellipsis);
-                        }
-
-                        if let Some(doc_id) = item.get("document_id").and_then(|v| v.as_str()) {
-                            println!("    {} {}", ui::dim("doc:"), ui::dim(doc_id));
-                        }
-                        println!();
-                    }
-
+                    table::print(&memories_table(bank_id, &result.items))?;
+                    println!();
                     println!("  {} {} total", ui::dim("Total:"), result.total);
                 }
             } else {
-                output::print_output(&result, output_format)?;
+                output::print_list(&result, output_format, || memories_table(bank_id, &result.items))?;
             }
             Ok(())
         }
@@ -148,9 +192,9 @@ pub fn get(
 
     match response {
         Ok(value) => {
+            let result: MemoryUnitDetail = serde_json::from_value(value)
+                .with_context(|| "Failed to parse memory response")?;
             if output_format == OutputFormat::Pretty {
-                let result: MemoryUnitDetail = serde_json::from_value(value)
-                    .with_context(|| "Failed to parse memory response")?;
 
                 let fact_type = result.type_.as_deref().unwrap_or("unknown");
                 let type_t = match fact_type {
@@ -191,28 +235,24 @@ pub fn get(
                 }
 
                 // Show entities if available
-                if let Some(entities) = &result.entities {
-                    if !entities.is_empty() {
-                        println!();
-                        println!("{}", ui::gradient_text("─── Entities ───"));
-                        for entity in entities {
-                            println!("  • {} ({})", entity.name, entity.id);
-                        }
+                if !result.entities.is_empty() {
+                    println!();
+                    println!("{}", ui::gradient_text("─── Entities ───"));
+                    for entity in &result.entities {
+                        println!("  • {}", entity);
                     }
                 }
 
                 // Show tags if available
-                if let Some(tags) = &result.tags {
-                    if !tags.is_empty() {
-                        println!();
-                        println!("{}", ui::gradient_text("─── Tags ───"));
-                        println!("  {}", tags.join(", "));
-                    }
+                if !result.tags.is_empty() {
+                    println!();
+                    println!("{}", ui::gradient_text("─── Tags ───"));
+                    println!("  {}", result.tags.join(", "));
                 }
 
                 println!();
             } else {
-                output::print_output(&value, output_format)?;
+                output::print_output(&result, output_format)?;
             }
             Ok(())
         }
@@ -250,6 +290,7 @@ pub fn recall(
     trace: bool,
     include_chunks: bool,
     chunk_max_tokens: i64,
+    group_by: Option<GroupBy>,
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
@@ -293,7 +334,12 @@ pub fn recall(
     match response {
         Ok(result) => {
             if output_format == OutputFormat::Pretty {
-                ui::print_search_results(&result, trace, include_chunks);
+                match group_by {
+                    Some(by) => ui::print_grouped_results(&request.query, &result, by, trace, include_chunks),
+                    None => ui::print_search_results(&result, trace, include_chunks),
+                }
+            } else if output_format == OutputFormat::Markdown {
+                print!("{}", markdown::recall(&request.query, &result));
             } else {
                 output::print_output(&result, output_format)?;
             }
@@ -311,6 +357,7 @@ pub fn reflect(
     context: Option<String>,
     max_tokens: Option<i64>,
     schema_path: Option<PathBuf>,
+    trace: bool,
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
@@ -336,7 +383,11 @@ pub fn reflect(
         budget: Some(parse_budget(&budget)),
         context,
         max_tokens: max_tokens.unwrap_or(4096),
-        include: None,
+        // Tool inputs and timings only; outputs make the trace very large
+        include: trace.then_some(ReflectIncludeOptions {
+            facts: None,
+            tool_calls: Some(ToolCallsIncludeOptions { output: false }),
+        }),
         response_schema,
         tags: None,
         tags_match: TagsMatch::Any,
@@ -352,6 +403,11 @@ pub fn reflect(
         Ok(result) => {
             if output_format == OutputFormat::Pretty {
                 ui::print_think_response(&result);
+                if let Some(trace) = &result.trace {
+                    ui::print_trace(&crate::trace::reflect_tree(trace));
+                }
+            } else if output_format == OutputFormat::Markdown {
+                print!("{}", markdown::reflect(&request.query, &result));
             } else {
                 output::print_output(&result, output_format)?;
             }
@@ -506,7 +562,12 @@ pub fn retain_files(
                 println!("  Operation ID: {}", op_id);
             }
         } else {
-            let result = serde_json::json!({ "operation_ids": all_operation_ids });
+            let result = RetainFilesOutput {
+                success: true,
+                files_count: file_paths.len(),
+                operation_ids: all_operation_ids,
+                is_async: true,
+            };
             output::print_output(&result, output_format)?;
         }
     } else {
@@ -534,11 +595,12 @@ pub fn retain_files(
                 ui::print_success("Files retained successfully");
                 println!("  Files processed: {}", file_paths.len());
             } else {
-                let result = serde_json::json!({
-                    "success": true,
-                    "files_count": file_paths.len(),
-                    "operation_ids": all_operation_ids,
-                });
+                let result = RetainFilesOutput {
+                    success: true,
+                    files_count: file_paths.len(),
+                    operation_ids: all_operation_ids,
+                    is_async: false,
+                };
                 output::print_output(&result, output_format)?;
             }
         } else {
@@ -665,6 +727,24 @@ mod tests {
     use super::*;
     use std::path::Path;
 
+    #[test]
+    fn test_memories_table_document_id() {
+        let items: Vec<serde_json::Map<String, serde_json::Value>> = serde_json::from_str(
+            r#"[
+                {"id": "m1", "fact_type": "world", "text": "a", "chunk_id": "demo_notes_2_0"},
+                {"id": "m2", "fact_type": "experience", "text": "b", "chunk_id": null}
+            ]"#,
+        )
+        .unwrap();
+        let table = memories_table("demo", &items);
+        let column = |name: &str| table.columns.iter().position(|c| *c == name).unwrap();
+
+        assert_eq!(table.rows[0][column("type")], "world");
+        // Document IDs may contain underscores; only the chunk index is cut off
+        assert_eq!(table.rows[0][column("document_id")], "notes_2");
+        assert_eq!(table.rows[1][column("document_id")], "");
+    }
+
     #[test]
     fn test_is_supported_file_text_extensions() {
         let supported = [
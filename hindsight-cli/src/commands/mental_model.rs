@@ -3,11 +3,30 @@
 use anyhow::Result;
 
 use crate::api::ApiClient;
-use crate::output::{self, OutputFormat};
+use crate::output::{self, OutputFormat, Page};
+use crate::table::{self, Table};
 use crate::ui;
 
 use hindsight_client::types;
 
+/// Rows of `mental-model list`
+fn mental_models_table(mental_models: &[types::MentalModelResponse]) -> Table {
+    let mut table = Table::new(&["id", "name", "source_query", "content", "tags", "last_refreshed_at", "created_at"])
+        .with_default_columns(&["id", "name", "content"]);
+    for mental_model in mental_models {
+        table.push(vec![
+            mental_model.id.clone(),
+            mental_model.name.clone(),
+            mental_model.source_query.clone(),
+            mental_model.content.clone(),
+            mental_model.tags.join(", "),
+            mental_model.last_refreshed_at.clone().unwrap_or_default(),
+            mental_model.created_at.clone().unwrap_or_default(),
+        ]);
+    }
+    table
+}
+
 /// List mental models for a bank
 pub fn list(
     client: &ApiClient,
@@ -15,13 +34,22 @@ pub fn list(
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
+    // NDJSON streams every mental models, a page at a time
+    if output_format == OutputFormat::Ndjson {
+        return output::stream_pages(std::io::stdout().lock(), 0, output::STREAM_PAGE_SIZE, |offset, limit| {
+            let page = client.list_mental_models(bank_id, Some(limit), Some(offset), verbose)?;
+            // No total in the response; a short page ends the stream
+            Ok(Page { items: page.items, total: i64::MAX })
+        });
+    }
+
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching mental models..."))
     } else {
         None
     };
 
-    let response = client.list_mental_models(bank_id, verbose);
+    let response = client.list_mental_models(bank_id, None, None, verbose);
 
     if let Some(mut sp) = spinner {
         sp.finish();
@@ -35,23 +63,10 @@ pub fn list(
                 if result.items.is_empty() {
                     println!("  {}", ui::dim("No mental models found."));
                 } else {
-                    for mental_model in &result.items {
-                        println!(
-                            "  {} {}",
-                            ui::gradient_start(&mental_model.id),
-                            mental_model.name
-                        );
-
-                        // Show content preview
-                        let preview: String = mental_model.content.chars().take(80).collect();
-                        let ellipsis = if mental_model.content.len() > 80 { "..." } else { "" };
-                        println!("    {}{}", ui::dim(&preview), ellipsis);
-
-                        println!();
-                    }
+                    table::print(&mental_models_table(&result.items))?;
                 }
             } else {
-                output::print_output(&result, output_format)?;
+                output::print_list(&result, output_format, || mental_models_table(&result.items))?;
             }
             Ok(())
         }
@@ -225,7 +240,8 @@ pub fn delete(
             if output_format == OutputFormat::Pretty {
                 ui::print_success(&format!("Mental model '{}' deleted successfully", mental_model_id));
             } else {
-                println!("{{\"success\": true}}");
+                let result = types::DeleteResponse { success: true, message: None, deleted_count: None };
+                output::print_output(&result, output_format)?;
             }
             Ok(())
         }
@@ -1,6 +1,7 @@
 pub mod bank;
 pub mod chunk;
 pub mod directive;
+pub mod doctor;
 pub mod document;
 pub mod entity;
 pub mod explore;
@@ -9,3 +10,4 @@ pub mod memory;
 pub mod operation;
 pub mod mental_model;
 pub mod tag;
+pub mod top;
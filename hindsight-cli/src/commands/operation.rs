@@ -1,21 +1,52 @@
 use anyhow::Result;
 use crate::api::ApiClient;
-use crate::output::{self, OutputFormat};
+use crate::api::Operation;
+use crate::output::{self, OutputFormat, Page};
+use crate::table::{self, Table};
 use crate::ui;
 
+/// Rows of `operation list`
+fn operations_table(operations: &[Operation]) -> Table {
+    let mut table = Table::new(&[
+        "id", "task_type", "status", "items_count", "document_id", "created_at", "error_message",
+    ])
+    .with_default_columns(&["id", "task_type", "status", "items_count", "document_id", "created_at"]);
+    for op in operations {
+        table.push(vec![
+            op.id.clone(),
+            op.task_type.clone(),
+            op.status.clone(),
+            op.items_count.to_string(),
+            op.document_id.clone().unwrap_or_default(),
+            op.created_at.clone(),
+            op.error_message.clone().unwrap_or_default(),
+        ]);
+    }
+    table
+}
+
 pub fn list(
     client: &ApiClient,
     agent_id: &str,
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
+    // NDJSON streams every operations, a page at a time
+    if output_format == OutputFormat::Ndjson {
+        return output::stream_pages(std::io::stdout().lock(), 0, output::STREAM_PAGE_SIZE, |offset, limit| {
+            let page = client.list_operations(agent_id, Some(limit), Some(offset), verbose)?;
+            // No total in the response; a short page ends the stream
+            Ok(Page { items: page.operations, total: i64::MAX })
+        });
+    }
+
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching operations..."))
     } else {
         None
     };
 
-    let response = client.list_operations(agent_id, verbose);
+    let response = client.list_operations(agent_id, None, None, verbose);
 
     if let Some(mut sp) = spinner {
         sp.finish();
@@ -28,18 +59,11 @@ pub fn list(
                     ui::print_info("No operations found");
                 } else {
                     ui::print_info(&format!("Found {} operation(s)", ops_response.operations.len()));
-                    for op in &ops_response.operations {
-                        println!("\n  Operation ID: {}", op.id);
-                        println!("    Type: {}", op.task_type);
-                        println!("    Status: {}", op.status);
-                        println!("    Items: {}", op.items_count);
-                        if let Some(doc_id) = &op.document_id {
-                            println!("    Document ID: {}", doc_id);
-                        }
-                    }
+                    println!();
+                    table::print(&operations_table(&ops_response.operations))?;
                 }
             } else {
-                output::print_output(&ops_response, output_format)?;
+                output::print_list(&ops_response, output_format, || operations_table(&ops_response.operations))?;
             }
             Ok(())
         }
@@ -3,9 +3,20 @@
 use anyhow::Result;
 
 use crate::api::ApiClient;
-use crate::output::{self, OutputFormat};
+use crate::api::types::TagItem;
+use crate::output::{self, OutputFormat, Page};
+use crate::table::{self, Table};
 use crate::ui;
 
+/// Rows of `tag list`
+fn tags_table(tags: &[TagItem]) -> Table {
+    let mut table = Table::new(&["tag", "count"]);
+    for tag in tags {
+        table.push(vec![tag.tag.clone(), tag.count.to_string()]);
+    }
+    table
+}
+
 /// List tags in a bank
 pub fn list(
     client: &ApiClient,
@@ -16,6 +27,14 @@ pub fn list(
     verbose: bool,
     output_format: OutputFormat,
 ) -> Result<()> {
+    // NDJSON streams every page from `offset`, `limit` tags at a time
+    if output_format == OutputFormat::Ndjson {
+        return output::stream_pages(std::io::stdout().lock(), offset, limit, |offset, limit| {
+            let page = client.list_tags(bank_id, query.as_deref(), Some(limit), Some(offset), verbose)?;
+            Ok(Page { items: page.items, total: page.total })
+        });
+    }
+
     let spinner = if output_format == OutputFormat::Pretty {
         Some(ui::create_spinner("Fetching tags..."))
     } else {
@@ -42,19 +61,12 @@ pub fn list(
                 if result.items.is_empty() {
                     println!("  {}", ui::dim("No tags found."));
                 } else {
-                    for (i, tag) in result.items.iter().enumerate() {
-                        let t = i as f32 / result.items.len().max(1) as f32;
-                        println!(
-                            "  {} {}",
-                            ui::gradient(&tag.tag, t),
-                            ui::dim(&format!("({})", tag.count))
-                        );
-                    }
+                    table::print(&tags_table(&result.items))?;
                     println!();
                     println!("  {} {} total", ui::dim("Total:"), result.total);
                 }
             } else {
-                output::print_output(&result, output_format)?;
+                output::print_list(&result, output_format, || tags_table(&result.items))?;
             }
             Ok(())
         }
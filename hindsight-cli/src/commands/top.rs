@@ -0,0 +1,415 @@
+//! `hindsight top`: a live view of API traffic and queued work.
+//!
+//! Each refresh scrapes `/metrics` and the pending operations of every bank,
+//! counting those a worker is already processing.
+//! Rates and latency percentiles are computed over the interval between two
+//! scrapes, the way `rate()` and `histogram_quantile()` work in the Grafana
+//! dashboards, so the first frame only has totals.
+
+use anyhow::Result;
+use colored::Colorize;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use crate::api::ApiClient;
+use crate::metrics::{self, Sample};
+use crate::table::{self, Table};
+use crate::ui;
+
+const HTTP_REQUESTS: &str = "hindsight_http_requests_total";
+const HTTP_DURATION: &str = "hindsight_http_duration_seconds";
+const HTTP_IN_PROGRESS: &str = "hindsight_http_requests_in_progress_requests";
+const OPERATIONS: &str = "hindsight_operation_operations_total";
+const OPERATION_DURATION: &str = "hindsight_operation_duration_seconds";
+
+/// Pending operations fetched per bank; enough to break down by task type
+const PENDING_SAMPLE: u64 = 100;
+
+/// `/metrics` at one point in time
+struct Scrape {
+    at: Instant,
+    samples: Vec<Sample>,
+}
+
+/// Pending work of one bank
+#[derive(Debug, Clone, PartialEq)]
+struct BankWork {
+    bank_id: String,
+    pending: i64,
+    /// Pending operations by task type, from the first [`PENDING_SAMPLE`]
+    by_task: BTreeMap<String, usize>,
+}
+
+/// Traffic for one label value (an endpoint or an operation)
+#[derive(Debug, Default, PartialEq)]
+struct Row {
+    key: String,
+    total: f64,
+    /// Per second over the last interval
+    rate: Option<f64>,
+    /// Share of 5xx responses over the last interval
+    errors: Option<f64>,
+    p50: Option<f64>,
+    p95: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct Dashboard {
+    /// Whether there was a previous scrape to compute rates from
+    has_rates: bool,
+    requests: Vec<Row>,
+    in_progress: f64,
+    operations: Vec<Row>,
+    banks: Vec<BankWork>,
+    /// Why the last refresh failed; the previous numbers stay on screen
+    error: Option<String>,
+}
+
+/// Samples of `name` whose `label` is `value`
+fn only(samples: &[Sample], label: &str, value: &str) -> Vec<Sample> {
+    samples.iter().filter(|s| s.labels.get(label).is_some_and(|v| v == value)).cloned().collect()
+}
+
+/// Totals, rates and percentiles of a counter and its duration histogram, by `label`
+fn rows(previous: Option<&Scrape>, current: &Scrape, counter: &str, histogram: &str, label: &str) -> Vec<Row> {
+    let totals = metrics::sum_by(&current.samples, counter, label);
+    let errors = metrics::sum_by(&only(&current.samples, "status_class", "5xx"), counter, label);
+    let buckets = metrics::buckets_by(&current.samples, histogram, label);
+
+    let mut rows: Vec<Row> = totals
+        .iter()
+        .map(|(key, &total)| {
+            let mut row = Row { key: key.clone(), total, ..Row::default() };
+            let Some(previous) = previous else {
+                return row;
+            };
+            let seconds = current.at.duration_since(previous.at).as_secs_f64();
+            let before = |sums: &BTreeMap<String, f64>| sums.get(key).copied().unwrap_or(0.0);
+
+            let requests = metrics::increase(before(&metrics::sum_by(&previous.samples, counter, label)), total);
+            if seconds > 0.0 {
+                row.rate = Some(requests / seconds);
+            }
+            if requests > 0.0 {
+                let previous_errors = metrics::sum_by(&only(&previous.samples, "status_class", "5xx"), counter, label);
+                let failed = metrics::increase(before(&previous_errors), errors.get(key).copied().unwrap_or(0.0));
+                row.errors = Some(failed / requests);
+            }
+            if let Some(current_buckets) = buckets.get(key) {
+                let previous_buckets = metrics::buckets_by(&previous.samples, histogram, label);
+                let observed = metrics::bucket_increase(
+                    previous_buckets.get(key).map(Vec::as_slice).unwrap_or_default(),
+                    current_buckets,
+                );
+                row.p50 = metrics::quantile(0.5, &observed);
+                row.p95 = metrics::quantile(0.95, &observed);
+            }
+            row
+        })
+        .collect();
+
+    // Busiest first: by rate once there is one, by total before that
+    rows.sort_by(|a, b| {
+        let busy = |row: &Row| (row.rate.unwrap_or(0.0), row.total);
+        busy(b).partial_cmp(&busy(a)).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.key.cmp(&b.key))
+    });
+    rows
+}
+
+fn build(previous: Option<&Scrape>, current: &Scrape, banks: Vec<BankWork>) -> Dashboard {
+    let in_progress = current.samples.iter().filter(|s| s.name == HTTP_IN_PROGRESS).fold(0.0, |sum, s| sum + s.value);
+    let mut banks: Vec<BankWork> = banks.into_iter().filter(|b| b.pending > 0).collect();
+    banks.sort_by(|a, b| b.pending.cmp(&a.pending).then_with(|| a.bank_id.cmp(&b.bank_id)));
+    Dashboard {
+        has_rates: previous.is_some(),
+        requests: rows(previous, current, HTTP_REQUESTS, HTTP_DURATION, "endpoint"),
+        in_progress,
+        operations: rows(previous, current, OPERATIONS, OPERATION_DURATION, "operation"),
+        banks,
+        error: None,
+    }
+}
+
+fn format_rate(rate: Option<f64>) -> String {
+    rate.map_or_else(|| "-".to_string(), |r| format!("{:.2}", r))
+}
+
+fn format_percent(share: Option<f64>) -> String {
+    share.map_or_else(|| "-".to_string(), |s| format!("{:.1}%", s * 100.0))
+}
+
+fn format_seconds(seconds: Option<f64>) -> String {
+    match seconds {
+        None => "-".to_string(),
+        Some(s) if s < 1.0 => format!("{:.0}ms", s * 1000.0),
+        Some(s) => format!("{:.2}s", s),
+    }
+}
+
+fn rows_table(first_column: &'static str, rows: &[Row], errors: bool) -> Table {
+    let columns: &[&'static str] = if errors {
+        &[first_column, "req/s", "err%", "p50", "p95", "total"]
+    } else {
+        &[first_column, "ops/s", "p50", "p95", "total"]
+    };
+    let mut table = Table::new(columns);
+    for row in rows {
+        let mut cells = vec![row.key.clone(), format_rate(row.rate)];
+        if errors {
+            cells.push(format_percent(row.errors));
+        }
+        cells.extend([format_seconds(row.p50), format_seconds(row.p95), format!("{}", row.total)]);
+        table.push(cells);
+    }
+    table
+}
+
+fn banks_table(banks: &[BankWork]) -> Table {
+    let mut table = Table::new(&["bank", "pending", "tasks"]);
+    for bank in banks {
+        let tasks: Vec<String> = bank.by_task.iter().map(|(task, n)| format!("{} {}", task, n)).collect();
+        table.push(vec![bank.bank_id.clone(), bank.pending.to_string(), tasks.join(", ")]);
+    }
+    table
+}
+
+/// Lines of one frame, fitted to `width` columns
+fn render(dashboard: &Dashboard, api_url: &str, interval: Duration, width: Option<usize>) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{} {}  {}",
+        ui::gradient_text("hindsight top"),
+        ui::dim(api_url),
+        ui::dim(&format!("every {}s · q to quit", interval.as_secs_f64()))
+    )];
+    if let Some(error) = &dashboard.error {
+        lines.push(format!("{} {}", "✗".bright_red(), error));
+    }
+
+    let total_rate = dashboard.has_rates.then(|| dashboard.requests.iter().fold(0.0, |sum, r| sum + r.rate.unwrap_or(0.0)));
+    let pending: i64 = dashboard.banks.iter().map(|b| b.pending).sum();
+    lines.push(format!(
+        "{} {}   {} {}   {} {}",
+        ui::dim("Requests/s:"),
+        format_rate(total_rate),
+        ui::dim("In flight:"),
+        dashboard.in_progress,
+        ui::dim("Pending operations:"),
+        pending
+    ));
+
+    let mut section = |title: &str, table: Table, empty: &str| {
+        lines.push(String::new());
+        lines.push(ui::gradient_start(title));
+        if table.rows.is_empty() {
+            lines.push(ui::dim(empty));
+            return;
+        }
+        for (i, line) in table::render(&table, width, true).into_iter().enumerate() {
+            lines.push(if i == 0 { line.bold().to_string() } else { line });
+        }
+    };
+    section("Requests", rows_table("endpoint", &dashboard.requests, true), "No requests yet");
+    section("Operations", rows_table("operation", &dashboard.operations, false), "No operations yet");
+    section("Pending work", banks_table(&dashboard.banks), "No pending operations");
+    lines
+}
+
+/// Pending work of `bank_ids`, or of every bank when empty
+fn bank_work(client: &ApiClient, bank_ids: &[String], verbose: bool) -> Result<Vec<BankWork>> {
+    let bank_ids = if bank_ids.is_empty() {
+        client.list_agents(verbose)?.into_iter().map(|b| b.bank_id).collect()
+    } else {
+        bank_ids.to_vec()
+    };
+    bank_ids
+        .into_iter()
+        .map(|bank_id| {
+            let response = client.list_pending_operations(&bank_id, PENDING_SAMPLE, verbose)?;
+            let mut by_task = BTreeMap::new();
+            for operation in &response.operations {
+                *by_task.entry(operation.task_type.clone()).or_insert(0) += 1;
+            }
+            Ok(BankWork { bank_id, pending: response.total, by_task })
+        })
+        .collect()
+}
+
+/// Whether the user asked to quit while waiting up to `timeout`
+fn wait_for_quit(timeout: Duration) -> Result<bool> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() || !event::poll(left)? {
+            return Ok(false);
+        }
+        if let Event::Key(key) = event::read()? {
+            let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+            if key.kind == KeyEventKind::Press && (ctrl_c || matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)) {
+                return Ok(true);
+            }
+        }
+    }
+}
+
+/// Refresh the dashboard every `interval` until `q`, or `iterations` times.
+///
+/// With `iterations`, or when stdout is not a terminal, frames are printed one
+/// after another instead of redrawn in place.
+pub fn run(
+    client: &ApiClient,
+    api_url: &str,
+    bank_ids: &[String],
+    interval: Duration,
+    iterations: Option<u64>,
+    verbose: bool,
+) -> Result<()> {
+    // Fail fast, before taking over the screen, if the API is unreachable
+    let first = Scrape { at: Instant::now(), samples: metrics::parse(&client.metrics(verbose)?)? };
+
+    let live = redraws(iterations);
+    if live {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+    }
+    let result = refresh_loop(client, api_url, bank_ids, interval, iterations, verbose, first);
+    if live {
+        execute!(io::stdout(), Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+    }
+    result
+}
+
+/// Whether frames are redrawn in place on the alternate screen
+fn redraws(iterations: Option<u64>) -> bool {
+    iterations.is_none() && io::stdout().is_terminal()
+}
+
+fn refresh_loop(
+    client: &ApiClient,
+    api_url: &str,
+    bank_ids: &[String],
+    interval: Duration,
+    iterations: Option<u64>,
+    verbose: bool,
+    first: Scrape,
+) -> Result<()> {
+    let live = redraws(iterations);
+    let mut previous: Option<Scrape> = None;
+    let mut current = first;
+    let mut banks = bank_work(client, bank_ids, verbose);
+    let mut frame = 0;
+    loop {
+        let mut dashboard = build(previous.as_ref(), &current, banks.as_ref().cloned().unwrap_or_default());
+        dashboard.error = banks.as_ref().err().map(|e| e.to_string());
+
+        let mut out = io::stdout();
+        if live {
+            let (columns, rows) = terminal::size()?;
+            queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+            for line in render(&dashboard, api_url, interval, Some(columns as usize)).iter().take(rows as usize) {
+                write!(out, "{}\r\n", line)?;
+            }
+            out.flush()?;
+        } else {
+            if frame > 0 {
+                println!();
+            }
+            for line in render(&dashboard, api_url, interval, None) {
+                println!("{}", line);
+            }
+        }
+
+        frame += 1;
+        if iterations.is_some_and(|n| frame >= n) {
+            return Ok(());
+        }
+        if live {
+            if wait_for_quit(interval)? {
+                return Ok(());
+            }
+        } else {
+            std::thread::sleep(interval);
+        }
+
+        // A failed scrape keeps the last numbers and says why
+        match client.metrics(verbose).and_then(|text| metrics::parse(&text)) {
+            Ok(samples) => {
+                previous = Some(std::mem::replace(&mut current, Scrape { at: Instant::now(), samples }));
+                banks = bank_work(client, bank_ids, verbose);
+            }
+            Err(e) => banks = Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scrape(at: Instant, text: &str) -> Scrape {
+        Scrape { at, samples: metrics::parse(text).unwrap() }
+    }
+
+    #[test]
+    fn test_rates_and_latencies() {
+        let start = Instant::now();
+        let before = scrape(
+            start,
+            r#"hindsight_http_requests_total{endpoint="/recall",status_class="2xx"} 10
+hindsight_http_requests_total{endpoint="/health",status_class="2xx"} 50
+hindsight_http_duration_seconds_bucket{endpoint="/recall",le="0.5"} 10
+hindsight_http_duration_seconds_bucket{endpoint="/recall",le="+Inf"} 10
+"#,
+        );
+        let after = scrape(
+            start + Duration::from_secs(2),
+            r#"hindsight_http_requests_total{endpoint="/recall",status_class="2xx"} 16
+hindsight_http_requests_total{endpoint="/recall",status_class="5xx"} 2
+hindsight_http_requests_total{endpoint="/health",status_class="2xx"} 52
+hindsight_http_duration_seconds_bucket{endpoint="/recall",le="0.5"} 14
+hindsight_http_duration_seconds_bucket{endpoint="/recall",le="+Inf"} 18
+hindsight_http_requests_in_progress_requests{endpoint="/recall"} 3
+"#,
+        );
+
+        let first = build(None, &before, vec![]);
+        assert_eq!(first.requests[0].key, "/health");
+        assert_eq!(first.requests[0].rate, None);
+
+        let dashboard = build(Some(&before), &after, vec![]);
+        let recall = &dashboard.requests[0];
+        assert_eq!(recall.key, "/recall");
+        assert_eq!(recall.rate, Some(4.0));
+        assert_eq!(recall.errors, Some(0.25));
+        // Half of the 8 new requests took up to 0.5s, the rest longer
+        assert_eq!(recall.p50, Some(0.5));
+        assert_eq!(recall.p95, Some(0.5));
+        assert_eq!(dashboard.requests[1].rate, Some(1.0));
+        assert_eq!(dashboard.in_progress, 3.0);
+    }
+
+    #[test]
+    fn test_render_frame() {
+        colored::control::set_override(false);
+        let now = Instant::now();
+        let current = scrape(now, "hindsight_operation_operations_total{operation=\"retain\"} 4\n");
+        let banks = vec![
+            BankWork { bank_id: "idle".into(), pending: 0, by_task: BTreeMap::new() },
+            BankWork { bank_id: "busy".into(), pending: 120, by_task: BTreeMap::from([("retain".into(), 100)]) },
+        ];
+        let dashboard = build(None, &current, banks);
+        let lines = render(&dashboard, "http://localhost:8888", Duration::from_secs(2), None);
+
+        assert_eq!(lines[0], "hindsight top http://localhost:8888  every 2s · q to quit");
+        assert_eq!(lines[1], "Requests/s: -   In flight: 0   Pending operations: 120");
+        assert!(lines.contains(&"No requests yet".to_string()));
+        assert!(lines.contains(&"retain     -      -    -    4".to_string()), "{:#?}", lines);
+        assert!(lines.contains(&"busy  120      retain 100".to_string()), "{:#?}", lines);
+        assert!(!lines.iter().any(|l| l.starts_with("idle")));
+    }
+}
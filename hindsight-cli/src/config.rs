@@ -72,11 +72,11 @@ impl Config {
         Ok(Config { api_url, api_key, source })
     }
 
-    fn config_dir() -> Option<PathBuf> {
+    pub fn config_dir() -> Option<PathBuf> {
         dirs::home_dir().map(|home| home.join(CONFIG_DIR_NAME))
     }
 
-    fn config_file_path() -> Option<PathBuf> {
+    pub fn config_file_path() -> Option<PathBuf> {
         Self::config_dir().map(|dir| dir.join(CONFIG_FILE_NAME))
     }
 
@@ -157,8 +157,8 @@ impl Config {
 pub fn prompt_api_url(current_url: Option<&str>) -> Result<String> {
     let default = current_url.unwrap_or(DEFAULT_API_URL);
 
-    print!("Enter API URL [{}]: ", default);
-    io::stdout().flush()?;
+    eprint!("Enter API URL [{}]: ", default);
+    io::stderr().flush()?;
 
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
@@ -1,13 +1,34 @@
 use colored::*;
+use hindsight_client::{CallError, Unsupported};
+
+use crate::ui;
 
 pub fn handle_api_error(err: anyhow::Error, api_url: &str) -> ! {
-    eprintln!("{}", format_error_message(&err, api_url));
+    eprintln!("{}", ui::for_stderr(|| format_error_message(&err, api_url)));
     std::process::exit(1);
 }
 
 fn format_error_message(err: &anyhow::Error, api_url: &str) -> String {
     let err_str = err.to_string();
 
+    // API the server reported (on /version) that it does not offer
+    let unsupported = err.downcast_ref::<Unsupported>().or(match err.downcast_ref::<CallError>() {
+        Some(CallError::Unsupported(unsupported)) => Some(unsupported),
+        _ => None,
+    });
+    if let Some(unsupported) = unsupported {
+        return format!(
+            "{} {}\n\n{}\n  {} (API {})\n\n{}\n  {}",
+            "✗".bright_red().bold(),
+            format!("The {} is not available on this server", unsupported.capability).bright_red().bold(),
+            "API URL:".bright_yellow(),
+            api_url.bright_white(),
+            unsupported.api_version,
+            "Reason:".bright_yellow(),
+            unsupported.reason.bright_white()
+        );
+    }
+
     // Connection refused
     if err_str.contains("Connection refused") || err_str.contains("tcp connect error") || err_str.contains("error sending request") {
         return format!(
@@ -200,16 +221,18 @@ fn format_error_message(err: &anyhow::Error, api_url: &str) -> String {
 }
 
 pub fn print_config_help() {
-    println!("\n{}", "Configuration:".bright_cyan().bold());
-    println!("  Run the configure command to set the API URL:");
-    println!("  {}", "hindsight configure".bright_white());
-    println!();
-    println!("  Or set it directly:");
-    println!("  {}", "hindsight configure --api-url http://your-api:8888".bright_white());
-    println!();
-    println!("  {}", "Configuration priority:".bright_yellow());
-    println!("    1. Environment variable (HINDSIGHT_API_URL) - highest priority");
-    println!("    2. Config file (~/.hindsight/config)");
-    println!("    3. Default (http://localhost:8888)");
-    println!();
+    ui::for_stderr(|| {
+        eprintln!("\n{}", "Configuration:".bright_cyan().bold());
+        eprintln!("  Run the configure command to set the API URL:");
+        eprintln!("  {}", "hindsight configure".bright_white());
+        eprintln!();
+        eprintln!("  Or set it directly:");
+        eprintln!("  {}", "hindsight configure --api-url http://your-api:8888".bright_white());
+        eprintln!();
+        eprintln!("  {}", "Configuration priority:".bright_yellow());
+        eprintln!("    1. Environment variable (HINDSIGHT_API_URL) - highest priority");
+        eprintln!("    2. Config file (~/.hindsight/config)");
+        eprintln!("    3. Default (http://localhost:8888)");
+        eprintln!();
+    });
 }
@@ -3,21 +3,38 @@ mod commands;
 mod config;
 mod errors;
 mod output;
+mod markdown;
+mod metrics;
+mod query;
+mod recall;
+mod render;
+mod schema;
+mod table;
+mod trace;
 mod ui;
 mod utils;
 
 use anyhow::Result;
 use api::ApiClient;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use config::Config;
 use output::OutputFormat;
+use schemars::JsonSchema;
+use serde::Serialize;
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 enum Format {
     Pretty,
     Json,
     Yaml,
+    Csv,
+    Tsv,
+    #[value(alias = "jsonl")]
+    Ndjson,
+    #[value(alias = "md")]
+    Markdown,
 }
 
 impl From<Format> for OutputFormat {
@@ -26,6 +43,10 @@ impl From<Format> for OutputFormat {
             Format::Pretty => OutputFormat::Pretty,
             Format::Json => OutputFormat::Json,
             Format::Yaml => OutputFormat::Yaml,
+            Format::Csv => OutputFormat::Csv,
+            Format::Tsv => OutputFormat::Tsv,
+            Format::Ndjson => OutputFormat::Ndjson,
+            Format::Markdown => OutputFormat::Markdown,
         }
     }
 }
@@ -37,14 +58,50 @@ impl From<Format> for OutputFormat {
 #[command(before_help = get_before_help())]
 #[command(after_help = get_after_help())]
 struct Cli {
-    /// Output format (pretty, json, yaml)
+    /// Output format (pretty, json, yaml, ndjson, markdown; csv and tsv for list commands)
     #[arg(short = 'o', long, global = true, default_value = "pretty")]
     output: Format,
 
+    /// Columns to show in list output, comma-separated (e.g. id,type,text)
+    #[arg(long, global = true, value_delimiter = ',')]
+    columns: Option<Vec<String>>,
+
+    /// Sort list output by a column, e.g. created_at or created_at:desc
+    #[arg(long, global = true)]
+    sort: Option<table::Sort>,
+
+    /// Show every column of list output without truncating to the terminal width
+    #[arg(long, global = true)]
+    wide: bool,
+
+    /// Omit the header row of list output
+    #[arg(long, global = true)]
+    no_header: bool,
+
+    /// JMESPath expression applied to JSON/YAML/NDJSON output, e.g. 'results[].text'
+    #[arg(long = "query", global = true, value_name = "EXPR")]
+    query_expr: Option<query::Query>,
+
+    /// Print strings in JSON/NDJSON output without quotes, one array element per line
+    #[arg(long, global = true)]
+    raw: bool,
+
+    /// Wrap JSON/YAML output as {"apiVersion", "kind", "data"}; see 'hindsight schema'
+    #[arg(long, global = true)]
+    envelope: bool,
+
     /// Show verbose output including full requests and responses
     #[arg(short = 'v', long, global = true)]
     verbose: bool,
 
+    /// Only print results and errors: no status messages, spinners or progress bars
+    #[arg(long, global = true)]
+    quiet: bool,
+
+    /// Disable colours (also set by NO_COLOR, and automatic when output is not a terminal)
+    #[arg(long, global = true)]
+    no_color: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -107,11 +164,44 @@ enum Commands {
     Health,
 
     /// Get Prometheus metrics
-    Metrics,
+    #[command(after_help = "Filters:\n  NAME           Metric name, e.g. hindsight_http_requests_total\n  LABEL=VALUE    Samples whose label has this value\n  LABEL!=VALUE   Samples whose label is missing or has another value\n  '*' matches any characters; repeated filters must all match.\n\nExamples:\n  hindsight metrics --filter 'hindsight_http_*' --filter status_class=5xx\n  hindsight metrics -o csv --filter 'endpoint=/v1/*'")]
+    Metrics {
+        /// Only show samples matching NAME, LABEL=VALUE or LABEL!=VALUE (repeatable)
+        #[arg(long = "filter", value_name = "FILTER")]
+        filters: Vec<metrics::Filter>,
+    },
+
+    /// Live dashboard of request rates, latencies and pending work per bank
+    #[command(after_help = "Keys:\n  q, Esc, Ctrl-C   Quit\n\nWith -n, or when stdout is not a terminal, frames are printed one after another.")]
+    Top {
+        /// Seconds between refreshes
+        #[arg(long, default_value = "2", value_name = "SECONDS")]
+        interval: u64,
+        /// Only show pending work of this bank (repeatable; default: all banks)
+        #[arg(long = "bank", value_name = "BANK_ID")]
+        banks: Vec<String>,
+        /// Print this many frames and exit
+        #[arg(short = 'n', long, value_name = "N")]
+        iterations: Option<u64>,
+    },
 
     /// Get API version information
     Version,
 
+    /// Check configuration, connectivity, authentication and API health
+    #[command(after_help = "Checks, in order: configuration and config file permissions, DNS, TCP, TLS,\n/health, /version and compatibility with this CLI, authentication, the bank\nconfig API and a recall round trip. Exits with status 1 if any check fails.\n\nExamples:\n  hindsight doctor\n  hindsight doctor --bank my-bank -o json\n  hindsight doctor --wait-until-healthy --timeout 120   Readiness check for scripts")]
+    Doctor {
+        /// Bank for the bank config and recall checks (default: the first bank)
+        #[arg(long, value_name = "BANK_ID")]
+        bank: Option<String>,
+        /// Only wait until /health reports healthy, then exit
+        #[arg(long)]
+        wait_until_healthy: bool,
+        /// Seconds to wait with --wait-until-healthy
+        #[arg(long, default_value = "60", value_name = "SECONDS", requires = "wait_until_healthy")]
+        timeout: u64,
+    },
+
     /// Interactive TUI explorer (k9s-style) for navigating banks, memories, entities, and performing recall/reflect
     #[command(alias = "tui")]
     Explore,
@@ -129,6 +219,13 @@ enum Commands {
         #[arg(long)]
         api_key: Option<String>,
     },
+
+    /// Print the JSON Schema of a command's JSON/YAML output
+    #[command(after_help = "Examples:\n  hindsight schema                 List commands with structured output\n  hindsight schema memory recall   Schema of 'memory recall -o json'\n  hindsight schema memory recall --envelope\n                                   Schema of 'memory recall -o json --envelope'")]
+    Schema {
+        /// Command to describe, e.g. memory recall (lists commands when omitted)
+        command: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -336,7 +433,7 @@ enum MemoryCommands {
 
         /// Full-text search query
         #[arg(short = 'q', long)]
-        query: Option<String>,
+        search: Option<String>,
 
         /// Maximum number of results
         #[arg(short = 'l', long, default_value = "100")]
@@ -387,6 +484,10 @@ enum MemoryCommands {
         /// Maximum tokens for chunks (only used with --include-chunks)
         #[arg(long, default_value = "8192")]
         chunk_max_tokens: i64,
+
+        /// Group results by document or entity, with query terms highlighted and relative times
+        #[arg(long, value_name = "BY")]
+        group_by: Option<recall::GroupBy>,
     },
 
     /// Generate answers using bank identity (reflect/reasoning)
@@ -412,6 +513,10 @@ enum MemoryCommands {
         /// Path to JSON schema file for structured output
         #[arg(short = 's', long)]
         schema: Option<PathBuf>,
+
+        /// Show trace of the LLM and tool calls made while reflecting
+        #[arg(long)]
+        trace: bool,
     },
 
     /// Store (retain) a single memory
@@ -489,7 +594,7 @@ enum DocumentCommands {
 
         /// Search query to filter documents
         #[arg(short = 'q', long)]
-        query: Option<String>,
+        search: Option<String>,
 
         /// Filter by date (yesterday, today, YYYY-MM-DD, or all)
         #[arg(short = 'd', long)]
@@ -590,7 +695,7 @@ enum TagCommands {
 
         /// Wildcard search query (e.g., 'user:*')
         #[arg(short = 'q', long)]
-        query: Option<String>,
+        search: Option<String>,
 
         /// Maximum number of results
         #[arg(short = 'l', long, default_value = "100")]
@@ -746,17 +851,118 @@ fn main() {
     }
 }
 
+/// Commands whose output is a list of rows and can be written as CSV/TSV
+fn supports_table_output(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Bank(BankCommands::List | BankCommands::Graph { .. })
+            | Commands::Memory(MemoryCommands::List { .. })
+            | Commands::Document(DocumentCommands::List { .. })
+            | Commands::Entity(EntityCommands::List { .. })
+            | Commands::Tag(TagCommands::List { .. })
+            | Commands::Operation(OperationCommands::List { .. })
+            | Commands::MentalModel(MentalModelCommands::List { .. })
+            | Commands::Directive(DirectiveCommands::List { .. })
+            | Commands::Metrics { .. }
+    ) || matches!(command, Commands::Schema { command } if command.is_empty())
+}
+
+/// Subcommand path of this run, e.g. `memory recall`
+fn command_path(matches: &clap::ArgMatches) -> String {
+    let mut words = Vec::new();
+    let mut matches = matches;
+    while let Some((name, sub_matches)) = matches.subcommand() {
+        words.push(name);
+        matches = sub_matches;
+    }
+    words.join(" ")
+}
+
+/// Commands with a Markdown report: every table plus recall, reflect, stats and entities
+fn supports_markdown_output(command: &Commands) -> bool {
+    supports_table_output(command)
+        || matches!(
+            command,
+            Commands::Memory(MemoryCommands::Recall { .. } | MemoryCommands::Reflect { .. })
+                | Commands::Bank(BankCommands::Stats { .. })
+                | Commands::Entity(EntityCommands::Get { .. })
+        )
+}
+
 fn run() -> Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     let output_format: OutputFormat = cli.output.into();
     let verbose = cli.verbose;
+    ui::configure(cli.quiet, cli.no_color);
+    table::set_options(table::TableOptions {
+        columns: cli.columns.clone(),
+        sort: cli.sort.clone(),
+        wide: cli.wide,
+        header: !cli.no_header,
+    });
+
+    // A query projects structured data, so it needs a structured format
+    if cli.query_expr.is_some() && !matches!(output_format, OutputFormat::Json | OutputFormat::Yaml | OutputFormat::Ndjson) {
+        ui::print_error("--query needs structured output: add -o json, -o yaml or -o ndjson");
+        std::process::exit(1);
+    }
+    output::set_query_options(output::QueryOptions { query: cli.query_expr.clone(), raw: cli.raw });
+
+    // `schema --envelope` describes the envelope instead of wrapping anything
+    if cli.envelope && !matches!(cli.command, Commands::Schema { .. }) {
+        if !matches!(output_format, OutputFormat::Json | OutputFormat::Yaml) {
+            ui::print_error("--envelope wraps structured output: add -o json or -o yaml");
+            std::process::exit(1);
+        }
+        let path = command_path(&matches);
+        match schema::find(&path) {
+            Some(command_output) => output::set_envelope(command_output.kind()),
+            None => {
+                ui::print_error(&format!("'hindsight {}' has no structured output to wrap in an envelope", path));
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Reject CSV/TSV up front rather than after a command has already run
+    if output_format.is_delimited() && !supports_table_output(&cli.command) {
+        ui::print_error("CSV/TSV output is only supported by: bank list, bank graph, memory list, document list, entity list, tag list, operation list, mental-model list, directive list, metrics, schema");
+        std::process::exit(1);
+    }
+    if output_format == OutputFormat::Markdown && !supports_markdown_output(&cli.command) {
+        ui::print_error("Markdown output is only supported by: memory recall, memory reflect, bank stats, entity get, and list commands");
+        std::process::exit(1);
+    }
+
+    if matches!(cli.command, Commands::Top { .. }) && output_format != OutputFormat::Pretty {
+        ui::print_error("top is a live dashboard; use 'hindsight metrics -o json' for structured metrics");
+        std::process::exit(1);
+    }
 
     // Handle configure command before loading full config (it doesn't need API client)
     if let Commands::Configure { api_url, api_key } = cli.command {
         return handle_configure(api_url, api_key, output_format);
     }
 
+    // Doctor checks the configuration itself, so it runs before it is loaded
+    if let Commands::Doctor { bank, wait_until_healthy, timeout } = &cli.command {
+        return if *wait_until_healthy {
+            commands::doctor::wait_until_healthy(Duration::from_secs(*timeout), verbose, output_format)
+        } else {
+            commands::doctor::run(bank.as_deref(), verbose, output_format)
+        };
+    }
+
+    if let Commands::Schema { command } = &cli.command {
+        if let Err(e) = handle_schema(command, cli.envelope, output_format) {
+            ui::print_error(&format!("{:#}", e));
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Handle ui command - needs config but not API client
     if let Commands::Ui = cli.command {
         return handle_ui(output_format);
@@ -781,11 +987,16 @@ fn run() -> Result<()> {
     let result: Result<()> = match cli.command {
         Commands::Configure { .. } => unreachable!(), // Handled above
         Commands::Ui => unreachable!(), // Handled above
-        Commands::Explore => commands::explore::run(&client),
+        Commands::Schema { .. } => unreachable!(), // Handled above
+        Commands::Doctor { .. } => unreachable!(), // Handled above
+        Commands::Explore => commands::explore::run(&client, cli.no_color),
 
         // Health, Metrics, and Version
         Commands::Health => commands::health::health(&client, verbose, output_format),
-        Commands::Metrics => commands::health::metrics(&client, verbose, output_format),
+        Commands::Metrics { filters } => commands::health::metrics(&client, &filters, verbose, output_format),
+        Commands::Top { interval, banks, iterations } => {
+            commands::top::run(&client, &api_url, &banks, Duration::from_secs(interval.max(1)), iterations, verbose)
+        }
         Commands::Version => commands::health::version(&client, verbose, output_format),
 
         // Bank commands
@@ -831,17 +1042,17 @@ fn run() -> Result<()> {
 
         // Memory commands
         Commands::Memory(memory_cmd) => match memory_cmd {
-            MemoryCommands::List { bank_id, fact_type, query, limit, offset } => {
-                commands::memory::list(&client, &bank_id, fact_type, query, limit, offset, verbose, output_format)
+            MemoryCommands::List { bank_id, fact_type, search, limit, offset } => {
+                commands::memory::list(&client, &bank_id, fact_type, search, limit, offset, verbose, output_format)
             }
             MemoryCommands::Get { bank_id, memory_id } => {
                 commands::memory::get(&client, &bank_id, &memory_id, verbose, output_format)
             }
-            MemoryCommands::Recall { bank_id, query, fact_type, budget, max_tokens, trace, include_chunks, chunk_max_tokens } => {
-                commands::memory::recall(&client, &bank_id, query, fact_type, budget, max_tokens, trace, include_chunks, chunk_max_tokens, verbose, output_format)
+            MemoryCommands::Recall { bank_id, query, fact_type, budget, max_tokens, trace, include_chunks, chunk_max_tokens, group_by } => {
+                commands::memory::recall(&client, &bank_id, query, fact_type, budget, max_tokens, trace, include_chunks, chunk_max_tokens, group_by, verbose, output_format)
             }
-            MemoryCommands::Reflect { bank_id, query, budget, context, max_tokens, schema } => {
-                commands::memory::reflect(&client, &bank_id, query, budget, context, max_tokens, schema, verbose, output_format)
+            MemoryCommands::Reflect { bank_id, query, budget, context, max_tokens, schema, trace } => {
+                commands::memory::reflect(&client, &bank_id, query, budget, context, max_tokens, schema, trace, verbose, output_format)
             }
             MemoryCommands::Retain { bank_id, content, doc_id, context, r#async } => {
                 commands::memory::retain(&client, &bank_id, content, doc_id, context, r#async, verbose, output_format)
@@ -859,8 +1070,8 @@ fn run() -> Result<()> {
 
         // Document commands
         Commands::Document(doc_cmd) => match doc_cmd {
-            DocumentCommands::List { bank_id, query, date, limit, offset } => {
-                commands::document::list(&client, &bank_id, query, date, limit, offset, verbose, output_format)
+            DocumentCommands::List { bank_id, search, date, limit, offset } => {
+                commands::document::list(&client, &bank_id, search, date, limit, offset, verbose, output_format)
             }
             DocumentCommands::Get { bank_id, document_id } => {
                 commands::document::get(&client, &bank_id, &document_id, verbose, output_format)
@@ -885,8 +1096,8 @@ fn run() -> Result<()> {
 
         // Tag commands
         Commands::Tag(tag_cmd) => match tag_cmd {
-            TagCommands::List { bank_id, query, limit, offset } => {
-                commands::tag::list(&client, &bank_id, query, limit, offset, verbose, output_format)
+            TagCommands::List { bank_id, search, limit, offset } => {
+                commands::tag::list(&client, &bank_id, search, limit, offset, verbose, output_format)
             }
         },
 
@@ -960,6 +1171,14 @@ fn run() -> Result<()> {
     Ok(())
 }
 
+/// `configure` output for JSON and YAML
+#[derive(Serialize, JsonSchema)]
+struct ConfigureOutput {
+    api_url: String,
+    api_key_set: bool,
+    config_path: String,
+}
+
 fn handle_configure(api_url: Option<String>, api_key: Option<String>, output_format: OutputFormat) -> Result<()> {
     // Load current config to show current state
     let current_config = Config::load().ok();
@@ -1025,17 +1244,50 @@ fn handle_configure(api_url: Option<String>, api_key: Option<String>, output_for
         println!();
         println!("Note: Environment variables HINDSIGHT_API_URL and HINDSIGHT_API_KEY will override these settings.");
     } else {
-        let result = serde_json::json!({
-            "api_url": new_api_url,
-            "api_key_set": new_api_key.is_some(),
-            "config_path": config_path.display().to_string(),
-        });
+        let result = ConfigureOutput {
+            api_url: new_api_url,
+            api_key_set: new_api_key.is_some(),
+            config_path: config_path.display().to_string(),
+        };
         output::print_output(&result, output_format)?;
     }
 
     Ok(())
 }
 
+fn handle_schema(command: &[String], envelope: bool, output_format: OutputFormat) -> Result<()> {
+    // Without a command, list what can be described
+    if command.is_empty() {
+        let rows: Vec<serde_json::Value> = schema::COMMANDS
+            .iter()
+            .map(|o| serde_json::json!({ "command": o.command, "kind": o.kind() }))
+            .collect();
+        let schemas_table = || {
+            let mut rows = table::Table::new(&["command", "kind"]);
+            for o in schema::COMMANDS {
+                rows.push(vec![o.command.to_string(), o.kind()]);
+            }
+            rows
+        };
+        return match output_format {
+            OutputFormat::Pretty => table::print(&schemas_table()),
+            _ => output::print_list(&rows, output_format, schemas_table),
+        };
+    }
+
+    let path = command.join(" ");
+    let Some(command_output) = schema::find(&path) else {
+        ui::print_error(&format!("No output schema for '{}'. Run 'hindsight schema' to list commands.", path));
+        std::process::exit(1);
+    };
+    let schema = if envelope { command_output.envelope_schema() } else { command_output.schema() };
+    match output_format {
+        OutputFormat::Pretty => println!("{}", output::to_json(&schema)?),
+        _ => output::print_output(&schema, output_format)?,
+    }
+    Ok(())
+}
+
 fn handle_ui(output_format: OutputFormat) -> Result<()> {
     use std::process::Command;
 
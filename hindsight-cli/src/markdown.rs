@@ -0,0 +1,290 @@
+//! Markdown reports for `-o markdown`, ready to paste into tickets and wikis.
+//!
+//! Each renderer returns the whole document as a string; the terminal
+//! counterparts live in `ui` (`print_search_results`, `print_think_response`).
+
+use crate::api::{types, AgentStats, RecallResponse, ReflectResponse};
+use crate::table::Table;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Fact types in the order recall groups are shown; others follow alphabetically
+const FACT_TYPE_ORDER: &[&str] = &["world", "experience", "opinion", "observation"];
+
+/// Text on one line, for list items and table cells
+fn inline(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn cell(text: &str) -> String {
+    inline(text).replace('|', "\\|")
+}
+
+/// `"world"` -> `"World"`
+fn title(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// A date or date range, as shown next to a fact
+fn when(start: Option<&str>, end: Option<&str>) -> Option<String> {
+    match (start, end) {
+        (Some(start), Some(end)) if start != end => Some(format!("{} – {}", start, end)),
+        (Some(start), _) => Some(start.to_string()),
+        (None, end) => end.map(str::to_string),
+    }
+}
+
+/// A GitHub-flavored table
+pub fn table(table: &Table) -> String {
+    let mut out = format!("| {} |\n", table.columns.join(" | "));
+    out.push_str(&format!("|{}\n", " --- |".repeat(table.columns.len())));
+    for row in &table.rows {
+        let cells: Vec<String> = row.iter().map(|c| cell(c)).collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    out
+}
+
+/// Two-column table of named counts, sorted by name
+fn counts_table(heading: &str, counts: &HashMap<String, i32>) -> String {
+    let sorted: BTreeMap<_, _> = counts.iter().collect();
+    let mut out = format!("| {} | Count |\n| --- | ---: |\n", heading);
+    for (name, count) in sorted {
+        out.push_str(&format!("| {} | {} |\n", cell(name), count));
+    }
+    out
+}
+
+/// Recall results grouped by fact type, each citing its source in a footnote
+pub fn recall(query: &str, response: &RecallResponse) -> String {
+    let mut out = format!("## Recall: {}\n\n", inline(query));
+    if response.results.is_empty() {
+        out.push_str("_No results found._\n");
+        return out;
+    }
+
+    let mut groups: Vec<&str> = response
+        .results
+        .iter()
+        .map(|r| r.type_.as_deref().unwrap_or("unknown"))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    groups.sort_by_key(|t| FACT_TYPE_ORDER.iter().position(|o| o == t).unwrap_or(FACT_TYPE_ORDER.len()));
+
+    // Footnotes are numbered in the order results are printed
+    let mut sources = Vec::new();
+    for group in groups {
+        out.push_str(&format!("### {}\n\n", title(group)));
+        for result in response.results.iter().filter(|r| r.type_.as_deref().unwrap_or("unknown") == group) {
+            sources.push(result);
+            out.push_str(&format!("- {} [^{}]\n", inline(&result.text), sources.len()));
+        }
+        out.push('\n');
+    }
+
+    for (i, result) in sources.iter().enumerate() {
+        let mut parts = vec![format!("Memory `{}`", result.id)];
+        if let Some(document_id) = &result.document_id {
+            parts.push(format!("document `{}`", document_id));
+        }
+        if let Some(date) = when(result.occurred_start.as_deref(), result.occurred_end.as_deref()) {
+            parts.push(date);
+        }
+        if let Some(context) = &result.context {
+            parts.push(format!("_{}_", inline(context)));
+        }
+        out.push_str(&format!("[^{}]: {}\n", i + 1, parts.join(" · ")));
+    }
+    out
+}
+
+/// The reflect answer (already markdown) and what it was based on
+pub fn reflect(query: &str, response: &ReflectResponse) -> String {
+    let mut out = format!("## Reflection: {}\n\n{}\n", inline(query), response.text.trim_end());
+
+    let Some(based_on) = &response.based_on else {
+        return out;
+    };
+    if based_on.memories.is_empty() && based_on.mental_models.is_empty() && based_on.directives.is_empty() {
+        return out;
+    }
+
+    out.push_str("\n---\n\n**Based on**\n\n");
+    let mut n = 0;
+    for memory in &based_on.memories {
+        n += 1;
+        let mut details: Vec<String> = memory.type_.iter().cloned().collect();
+        if let Some(date) = when(memory.occurred_start.as_deref(), memory.occurred_end.as_deref()) {
+            details.push(date);
+        }
+        if let Some(id) = &memory.id {
+            details.push(format!("`{}`", id));
+        }
+        out.push_str(&format!("{}. {}", n, inline(&memory.text)));
+        if !details.is_empty() {
+            out.push_str(&format!(" ({})", details.join(", ")));
+        }
+        out.push('\n');
+    }
+    for model in &based_on.mental_models {
+        n += 1;
+        out.push_str(&format!("{}. Mental model `{}`: {}\n", n, model.id, inline(&model.text)));
+    }
+    for directive in &based_on.directives {
+        n += 1;
+        out.push_str(&format!("{}. Directive **{}**: {}\n", n, inline(&directive.name), inline(&directive.content)));
+    }
+
+    if let Some(structured) = &response.structured_output {
+        let json = serde_json::to_string_pretty(structured).unwrap_or_default();
+        out.push_str(&format!("\n**Structured output**\n\n```json\n{}\n```\n", json));
+    }
+    out
+}
+
+/// Bank statistics as a summary table followed by per-type breakdowns
+pub fn bank_stats(stats: &AgentStats) -> String {
+    let mut out = format!("## Statistics: {}\n\n", stats.bank_id);
+    out.push_str("| Metric | Count |\n| --- | ---: |\n");
+    for (name, count) in [
+        ("Memory units", stats.total_nodes),
+        ("Links", stats.total_links),
+        ("Documents", stats.total_documents),
+        ("Pending operations", stats.pending_operations),
+        ("Failed operations", stats.failed_operations),
+    ] {
+        out.push_str(&format!("| {} | {} |\n", name, count));
+    }
+
+    for (heading, column, counts) in [
+        ("Memory Units by Type", "Type", &stats.nodes_by_fact_type),
+        ("Links by Type", "Link type", &stats.links_by_link_type),
+        ("Links by Fact Type", "Fact type", &stats.links_by_fact_type),
+    ] {
+        if !counts.is_empty() {
+            out.push_str(&format!("\n### {}\n\n{}", heading, counts_table(column, counts)));
+        }
+    }
+
+    // Fact types down the side, link types across the top
+    if !stats.links_breakdown.is_empty() {
+        let link_types: BTreeSet<&String> = stats.links_breakdown.values().flat_map(|links| links.keys()).collect();
+        let fact_types: BTreeMap<_, _> = stats.links_breakdown.iter().collect();
+        out.push_str("\n### Detailed Link Breakdown\n\n| Fact type |");
+        for link_type in &link_types {
+            out.push_str(&format!(" {} |", cell(link_type)));
+        }
+        out.push_str(&format!("\n| --- |{}\n", " ---: |".repeat(link_types.len())));
+        for (fact_type, links) in fact_types {
+            out.push_str(&format!("| {} |", cell(fact_type)));
+            for link_type in &link_types {
+                out.push_str(&format!(" {} |", links.get(*link_type).copied().unwrap_or(0)));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// An entity's details and its observations
+pub fn entity(entity: &types::EntityDetailResponse) -> String {
+    let mut out = format!("## {}\n\n", inline(&entity.canonical_name));
+    out.push_str(&format!("- **ID:** `{}`\n- **Mentions:** {}\n", entity.id, entity.mention_count));
+    if let Some(first_seen) = &entity.first_seen {
+        out.push_str(&format!("- **First seen:** {}\n", first_seen));
+    }
+    if let Some(last_seen) = &entity.last_seen {
+        out.push_str(&format!("- **Last seen:** {}\n", last_seen));
+    }
+
+    if !entity.observations.is_empty() {
+        out.push_str("\n### Observations\n\n");
+        for observation in &entity.observations {
+            out.push_str(&format!("- {}", inline(&observation.text)));
+            if let Some(mentioned_at) = &observation.mentioned_at {
+                out.push_str(&format!(" _({})_", mentioned_at));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_recall_groups_and_cites() {
+        let response: RecallResponse = serde_json::from_value(json!({
+            "results": [
+                {"id": "m1", "text": "Bob moved\nto Paris", "type": "experience", "document_id": "d1"},
+                {"id": "m2", "text": "Alice likes tea", "type": "world", "occurred_start": "2024-03-01", "context": "chat"},
+                {"id": "m3", "text": "Paris is in France", "type": "world"}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            recall("who lives where", &response),
+            "## Recall: who lives where\n\n\
+             ### World\n\n- Alice likes tea [^1]\n- Paris is in France [^2]\n\n\
+             ### Experience\n\n- Bob moved to Paris [^3]\n\n\
+             [^1]: Memory `m2` · 2024-03-01 · _chat_\n\
+             [^2]: Memory `m3`\n\
+             [^3]: Memory `m1` · document `d1`\n"
+        );
+    }
+
+    #[test]
+    fn test_reflect_based_on() {
+        let response: ReflectResponse = serde_json::from_value(json!({
+            "text": "Alice **prefers** tea.\n",
+            "based_on": {
+                "memories": [{"id": "m2", "text": "Alice likes tea", "type": "world"}],
+                "mental_models": [],
+                "directives": [{"id": "d1", "name": "Be brief", "content": "Answer in one line"}]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            reflect("What does Alice drink?", &response),
+            "## Reflection: What does Alice drink?\n\nAlice **prefers** tea.\n\n---\n\n**Based on**\n\n\
+             1. Alice likes tea (world, `m2`)\n\
+             2. Directive **Be brief**: Answer in one line\n"
+        );
+    }
+
+    #[test]
+    fn test_bank_stats_tables() {
+        let stats: AgentStats = serde_json::from_value(json!({
+            "bank_id": "b1", "total_nodes": 3, "total_links": 4, "total_documents": 1,
+            "nodes_by_fact_type": {"world": 2, "experience": 1},
+            "links_by_link_type": {}, "links_by_fact_type": {},
+            "links_breakdown": {"world": {"semantic": 2, "temporal": 1}, "experience": {"semantic": 1}},
+            "pending_operations": 0, "failed_operations": 0
+        }))
+        .unwrap();
+        let markdown = bank_stats(&stats);
+
+        assert!(markdown.contains("| Memory units | 3 |\n"));
+        assert!(markdown.contains("### Memory Units by Type\n\n| Type | Count |\n| --- | ---: |\n| experience | 1 |\n| world | 2 |\n"));
+        assert!(!markdown.contains("### Links by Type"));
+        assert!(markdown.contains(
+            "| Fact type | semantic | temporal |\n| --- | ---: | ---: |\n| experience | 1 | 0 |\n| world | 2 | 1 |\n"
+        ));
+    }
+
+    #[test]
+    fn test_table_escapes_cells() {
+        let mut rows = Table::new(&["id", "text"]);
+        rows.push(vec!["1".to_string(), "a | b\nc".to_string()]);
+        assert_eq!(table(&rows), "| id | text |\n| --- | --- |\n| 1 | a \\| b c |\n");
+    }
+}
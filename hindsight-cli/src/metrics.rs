@@ -0,0 +1,325 @@
+//! Prometheus exposition text parsed into samples, for `metrics` and `top`.
+//!
+//! Only the parts of the text format the API emits are handled: `# TYPE`
+//! comments, labelled samples and optional timestamps. The aggregation helpers
+//! mirror the PromQL the Grafana dashboards use (`sum by`, `rate`,
+//! `histogram_quantile`) over two scrapes instead of a range.
+
+use anyhow::{anyhow, bail, Result};
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+/// Suffixes of the series that make up a histogram or summary family
+const FAMILY_SUFFIXES: &[&str] = &["_bucket", "_sum", "_count", "_total", "_created"];
+
+/// One sample of the exposition text
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct Sample {
+    pub name: String,
+    /// Family type from `# TYPE`: counter, gauge, histogram, summary or untyped
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    /// NaN and infinities serialize as null in JSON
+    pub value: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_ms: Option<i64>,
+}
+
+/// Parse exposition text, e.g. the body of `GET /metrics`
+pub fn parse(text: &str) -> Result<Vec<Sample>> {
+    let mut types: HashMap<&str, &str> = HashMap::new();
+    let mut samples = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            let mut words = comment.split_whitespace();
+            if let (Some("TYPE"), Some(family), Some(kind)) = (words.next(), words.next(), words.next()) {
+                types.insert(family, kind);
+            }
+            continue;
+        }
+        let mut sample = parse_sample(line).map_err(|e| anyhow!("line {}: {}", number + 1, e))?;
+        sample.kind = family_type(&types, &sample.name).map(str::to_string);
+        samples.push(sample);
+    }
+    Ok(samples)
+}
+
+/// The type of the family `name` belongs to, e.g. `foo_bucket` in histogram `foo`
+fn family_type<'a>(types: &HashMap<&str, &'a str>, name: &str) -> Option<&'a str> {
+    if let Some(kind) = types.get(name) {
+        return Some(kind);
+    }
+    FAMILY_SUFFIXES
+        .iter()
+        .filter_map(|suffix| name.strip_suffix(suffix))
+        .find_map(|family| types.get(family).copied())
+}
+
+fn parse_sample(line: &str) -> Result<Sample> {
+    let name_end = line.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':')).unwrap_or(line.len());
+    if name_end == 0 {
+        bail!("expected a metric name");
+    }
+    let name = line[..name_end].to_string();
+    let mut rest = &line[name_end..];
+
+    let mut labels = BTreeMap::new();
+    if let Some(body) = rest.strip_prefix('{') {
+        rest = parse_labels(body, &mut labels)?;
+    }
+
+    let mut fields = rest.split_whitespace();
+    let value = fields.next().ok_or_else(|| anyhow!("missing value for {}", name))?;
+    let value = value.parse::<f64>().map_err(|_| anyhow!("invalid value '{}' for {}", value, name))?;
+    let timestamp_ms = match fields.next() {
+        Some(ts) => Some(ts.parse::<i64>().map_err(|_| anyhow!("invalid timestamp '{}' for {}", ts, name))?),
+        None => None,
+    };
+    Ok(Sample { name, kind: None, labels, value, timestamp_ms })
+}
+
+/// Parse `a="x",b="y"}` into `labels`; returns what follows the closing brace
+fn parse_labels<'a>(mut body: &'a str, labels: &mut BTreeMap<String, String>) -> Result<&'a str> {
+    loop {
+        body = body.trim_start();
+        if let Some(rest) = body.strip_prefix('}') {
+            return Ok(rest);
+        }
+        let (name, rest) = body.split_once('=').ok_or_else(|| anyhow!("expected label=\"value\""))?;
+        let rest = rest.trim_start().strip_prefix('"').ok_or_else(|| anyhow!("label {} is not quoted", name.trim()))?;
+
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        let end = loop {
+            match chars.next() {
+                Some((i, '"')) => break i,
+                Some((_, '\\')) => match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, c)) => value.push(c),
+                    None => bail!("unterminated label {}", name.trim()),
+                },
+                Some((_, c)) => value.push(c),
+                None => bail!("unterminated label {}", name.trim()),
+            }
+        };
+        labels.insert(name.trim().to_string(), value);
+
+        body = rest[end + 1..].trim_start();
+        body = body.strip_prefix(',').unwrap_or(body);
+    }
+}
+
+/// `*` matches any run of characters; everything else literally
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, rest)) => {
+            let Some(text) = text.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=text.len()).filter(|&i| text.is_char_boundary(i)).any(|i| glob_match(rest, &text[i..]))
+        }
+    }
+}
+
+/// `metrics --filter`: a metric name, or `label=value` / `label!=value`; `*` is a wildcard
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Name(String),
+    Label { name: String, value: String, negate: bool },
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value, negate) = match s.split_once('=') {
+            None if s.is_empty() => return Err("empty filter".to_string()),
+            None => return Ok(Filter::Name(s.to_string())),
+            Some((name, value)) => match name.strip_suffix('!') {
+                Some(name) => (name, value, true),
+                None => (name, value, false),
+            },
+        };
+        if name.is_empty() {
+            return Err(format!("missing label name in '{}'", s));
+        }
+        Ok(Filter::Label { name: name.to_string(), value: value.to_string(), negate })
+    }
+}
+
+impl Filter {
+    pub fn matches(&self, sample: &Sample) -> bool {
+        match self {
+            Filter::Name(pattern) => glob_match(pattern, &sample.name),
+            Filter::Label { name, value, negate } => {
+                let found = sample.labels.get(name).is_some_and(|v| glob_match(value, v));
+                found != *negate
+            }
+        }
+    }
+}
+
+/// Samples matching every filter
+pub fn filter(samples: Vec<Sample>, filters: &[Filter]) -> Vec<Sample> {
+    samples.into_iter().filter(|s| filters.iter().all(|f| f.matches(s))).collect()
+}
+
+/// `sum by (label) (name)`; samples without the label are summed under ""
+pub fn sum_by(samples: &[Sample], name: &str, label: &str) -> BTreeMap<String, f64> {
+    let mut sums = BTreeMap::new();
+    for sample in samples.iter().filter(|s| s.name == name) {
+        let key = sample.labels.get(label).cloned().unwrap_or_default();
+        *sums.entry(key).or_insert(0.0) += sample.value;
+    }
+    sums
+}
+
+/// Growth of a counter between two scrapes; a restarted server counts from zero
+pub fn increase(previous: f64, current: f64) -> f64 {
+    if current >= previous {
+        current - previous
+    } else {
+        current
+    }
+}
+
+/// Histogram buckets of `name` (the family name, without `_bucket`) as
+/// `(le, cumulative count)`, summed by `label` and sorted by bound
+pub fn buckets_by(samples: &[Sample], name: &str, label: &str) -> BTreeMap<String, Vec<(f64, f64)>> {
+    let bucket = format!("{}_bucket", name);
+    let mut by_key: BTreeMap<String, Vec<(f64, f64)>> = BTreeMap::new();
+    for sample in samples.iter().filter(|s| s.name == bucket) {
+        let Some(le) = sample.labels.get("le").and_then(|le| le.parse::<f64>().ok()) else {
+            continue;
+        };
+        let key = sample.labels.get(label).cloned().unwrap_or_default();
+        let buckets = by_key.entry(key).or_default();
+        match buckets.iter_mut().find(|(bound, _)| *bound == le) {
+            Some((_, count)) => *count += sample.value,
+            None => buckets.push((le, sample.value)),
+        }
+    }
+    for buckets in by_key.values_mut() {
+        buckets.sort_by(|a, b| a.0.total_cmp(&b.0));
+    }
+    by_key
+}
+
+/// Bucket counts observed between two scrapes of the same histogram
+pub fn bucket_increase(previous: &[(f64, f64)], current: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    current
+        .iter()
+        .map(|&(le, count)| {
+            let before = previous.iter().find(|(bound, _)| *bound == le).map_or(0.0, |&(_, c)| c);
+            (le, increase(before, count))
+        })
+        .collect()
+}
+
+/// `histogram_quantile`: the `q` quantile of cumulative `(le, count)` buckets,
+/// interpolated linearly within the bucket it falls in
+pub fn quantile(q: f64, buckets: &[(f64, f64)]) -> Option<f64> {
+    let total = buckets.last()?.1;
+    if total <= 0.0 {
+        return None;
+    }
+    let rank = q * total;
+    let mut lower = (0.0, 0.0);
+    for &(le, count) in buckets {
+        if count >= rank {
+            if le.is_infinite() {
+                // Past the last finite bound; its upper edge is the best estimate
+                return Some(lower.0);
+            }
+            let in_bucket = count - lower.1;
+            let fraction = if in_bucket > 0.0 { (rank - lower.1) / in_bucket } else { 1.0 };
+            return Some(lower.0 + (le - lower.0) * fraction);
+        }
+        lower = (le, count);
+    }
+    Some(lower.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = r#"# HELP hindsight_http_requests_total Total number of HTTP requests
+# TYPE hindsight_http_requests_total counter
+hindsight_http_requests_total{endpoint="/v1/recall",method="POST",status_class="2xx"} 10.0
+hindsight_http_requests_total{endpoint="/v1/recall",method="POST",status_class="5xx"} 2.0
+hindsight_http_requests_total{endpoint="/health",method="GET",status_class="2xx"} 5.0
+# TYPE hindsight_http_duration_seconds histogram
+hindsight_http_duration_seconds_bucket{endpoint="/v1/recall",le="0.1"} 4.0
+hindsight_http_duration_seconds_bucket{endpoint="/v1/recall",le="1.0"} 10.0
+hindsight_http_duration_seconds_bucket{endpoint="/v1/recall",le="+Inf"} 12.0
+hindsight_http_duration_seconds_count{endpoint="/v1/recall"} 12.0
+process_start_time_seconds 1.7e9 1718000000000
+odd{msg="say \"hi\"\nthere", } NaN
+"#;
+
+    #[test]
+    fn test_parse_samples() {
+        let samples = parse(TEXT).unwrap();
+        assert_eq!(samples.len(), 9);
+
+        assert_eq!(samples[0].name, "hindsight_http_requests_total");
+        assert_eq!(samples[0].kind.as_deref(), Some("counter"));
+        assert_eq!(samples[0].labels["endpoint"], "/v1/recall");
+        assert_eq!(samples[0].value, 10.0);
+        assert_eq!(samples[5].kind.as_deref(), Some("histogram"));
+        assert_eq!(samples[5].labels["le"], "+Inf");
+        assert_eq!(samples[6].kind.as_deref(), Some("histogram"));
+        assert_eq!(samples[7].kind, None);
+        assert_eq!(samples[7].timestamp_ms, Some(1718000000000));
+        assert_eq!(samples[8].labels["msg"], "say \"hi\"\nthere");
+        assert!(samples[8].value.is_nan());
+
+        let error = parse("ok 1\nbroken{a=\"x\" 1\n").unwrap_err().to_string();
+        assert!(error.starts_with("line 2:"), "{}", error);
+        assert!(parse("no_value\n").is_err());
+    }
+
+    #[test]
+    fn test_filters() {
+        let samples = parse(TEXT).unwrap();
+        let count = |filters: &[&str]| {
+            let filters: Vec<Filter> = filters.iter().map(|f| f.parse().unwrap()).collect();
+            filter(samples.clone(), &filters).len()
+        };
+        assert_eq!(count(&["hindsight_http_requests_total"]), 3);
+        assert_eq!(count(&["hindsight_http_*"]), 7);
+        assert_eq!(count(&["endpoint=/v1/*"]), 6);
+        assert_eq!(count(&["hindsight_http_requests_total", "status_class!=2xx"]), 1);
+        assert_eq!(count(&["method!=GET", "*_total"]), 2);
+        assert!("=x".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn test_aggregation_and_quantiles() {
+        let samples = parse(TEXT).unwrap();
+        let by_endpoint = sum_by(&samples, "hindsight_http_requests_total", "endpoint");
+        assert_eq!(by_endpoint["/v1/recall"], 12.0);
+        assert_eq!(by_endpoint["/health"], 5.0);
+        assert_eq!(increase(12.0, 15.0), 3.0);
+        assert_eq!(increase(12.0, 4.0), 4.0);
+
+        let buckets = &buckets_by(&samples, "hindsight_http_duration_seconds", "endpoint")["/v1/recall"];
+        assert_eq!(buckets, &vec![(0.1, 4.0), (1.0, 10.0), (f64::INFINITY, 12.0)]);
+        // Rank 6 of 12 lies a third of the way into the (0.1, 1.0] bucket
+        assert!((quantile(0.5, buckets).unwrap() - 0.4).abs() < 1e-9);
+        assert_eq!(quantile(0.99, buckets), Some(1.0));
+        assert_eq!(quantile(0.5, &[(0.1, 0.0), (f64::INFINITY, 0.0)]), None);
+
+        let earlier = [(0.1, 4.0), (1.0, 4.0), (f64::INFINITY, 4.0)];
+        assert_eq!(bucket_increase(&earlier, buckets), vec![(0.1, 0.0), (1.0, 6.0), (f64::INFINITY, 8.0)]);
+    }
+}
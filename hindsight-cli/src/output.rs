@@ -1,11 +1,25 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use serde::Serialize;
+use serde_json::Value;
+
+use crate::markdown;
+use crate::query::Query;
+use crate::schema;
+use crate::table::{self, Table};
+use std::io::{self, Write};
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OutputFormat {
     Pretty,
     Json,
     Yaml,
+    Csv,
+    Tsv,
+    /// One compact JSON record per line
+    Ndjson,
+    /// Reports for tickets and wikis
+    Markdown,
 }
 
 impl OutputFormat {
@@ -15,9 +29,102 @@ impl OutputFormat {
             "json" => Some(OutputFormat::Json),
             "yaml" | "yml" => Some(OutputFormat::Yaml),
             "pretty" | "text" => Some(OutputFormat::Pretty),
+            "csv" => Some(OutputFormat::Csv),
+            "tsv" => Some(OutputFormat::Tsv),
+            "ndjson" | "jsonl" => Some(OutputFormat::Ndjson),
+            "markdown" | "md" => Some(OutputFormat::Markdown),
             _ => None,
         }
     }
+
+    /// CSV and TSV, which only list commands can produce
+    pub fn is_delimited(&self) -> bool {
+        matches!(self, OutputFormat::Csv | OutputFormat::Tsv)
+    }
+}
+
+/// `--query` and `--raw`, applied to JSON, YAML and NDJSON output
+#[derive(Debug, Clone, Default)]
+pub struct QueryOptions {
+    pub query: Option<Query>,
+    /// Strings without quotes, arrays one element per line
+    pub raw: bool,
+}
+
+static QUERY_OPTIONS: OnceLock<QueryOptions> = OnceLock::new();
+
+/// Set the query flags for this run (first call wins)
+pub fn set_query_options(options: QueryOptions) {
+    let _ = QUERY_OPTIONS.set(options);
+}
+
+fn query_options() -> &'static QueryOptions {
+    static DEFAULT: QueryOptions = QueryOptions { query: None, raw: false };
+    QUERY_OPTIONS.get().unwrap_or(&DEFAULT)
+}
+
+/// `--envelope`: JSON and YAML output wrapped with its version and kind
+#[derive(Serialize)]
+struct Envelope<'a, T> {
+    #[serde(rename = "apiVersion")]
+    api_version: &'static str,
+    kind: &'a str,
+    data: &'a T,
+}
+
+static ENVELOPE_KIND: OnceLock<String> = OnceLock::new();
+
+/// Wrap JSON and YAML output in an envelope of `kind` (first call wins)
+pub fn set_envelope(kind: String) {
+    let _ = ENVELOPE_KIND.set(kind);
+}
+
+/// `data` as JSON with `--query` applied
+fn project<T: Serialize>(data: &T, query: &Query) -> Result<Value> {
+    query.apply(&serde_json::to_value(data)?)
+}
+
+/// `--raw` lines of a value: strings unquoted, arrays one compact element per line
+fn raw_lines(value: &Value) -> Result<Vec<String>> {
+    let line = |item: &Value| match item {
+        Value::String(s) => Ok(s.clone()),
+        other => serde_json::to_string(other),
+    };
+    Ok(match value {
+        Value::Array(items) => items.iter().map(line).collect::<Result<_, _>>()?,
+        Value::String(s) => vec![s.clone()],
+        other => vec![to_json(other)?],
+    })
+}
+
+/// Quote a CSV field when it contains a comma, quote or line break (RFC 4180)
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// TSV has no quoting, so tabs, line breaks and backslashes are escaped
+fn tsv_field(field: &str) -> String {
+    field.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+/// Render a table as CSV or TSV, one line per row
+pub fn to_delimited(table: &Table, format: OutputFormat, header: bool) -> String {
+    let (separator, field): (&str, fn(&str) -> String) = match format {
+        OutputFormat::Tsv => ("\t", tsv_field),
+        _ => (",", csv_field),
+    };
+    let mut out = String::new();
+    let header_row = header.then(|| table.columns.iter().map(|c| c.to_string()).collect::<Vec<_>>());
+    for row in header_row.iter().chain(&table.rows) {
+        let fields: Vec<String> = row.iter().map(|f| field(f)).collect();
+        out.push_str(&fields.join(separator));
+        out.push('\n');
+    }
+    out
 }
 
 /// Format data as JSON string
@@ -30,8 +137,31 @@ pub fn to_yaml<T: Serialize>(data: &T) -> Result<String> {
     Ok(serde_yaml::to_string(data)?)
 }
 
+/// Print a command's result, with `--envelope` and then `--query` applied to
+/// JSON and YAML. NDJSON applies the query to each record instead.
 pub fn print_output<T: Serialize>(data: &T, format: OutputFormat) -> Result<()> {
+    match ENVELOPE_KIND.get() {
+        Some(kind) if matches!(format, OutputFormat::Json | OutputFormat::Yaml) => {
+            print_projected(&Envelope { api_version: schema::API_VERSION, kind, data }, format)
+        }
+        _ => print_projected(data, format),
+    }
+}
+
+fn print_projected<T: Serialize>(data: &T, format: OutputFormat) -> Result<()> {
+    match (&query_options().query, format) {
+        (Some(query), OutputFormat::Json | OutputFormat::Yaml) => write_output(&project(data, query)?, format),
+        _ => write_output(data, format),
+    }
+}
+
+fn write_output<T: Serialize>(data: &T, format: OutputFormat) -> Result<()> {
     match format {
+        OutputFormat::Json if query_options().raw => {
+            for line in raw_lines(&serde_json::to_value(data)?)? {
+                println!("{}", line);
+            }
+        }
         OutputFormat::Json => {
             println!("{}", to_json(data)?);
         }
@@ -42,10 +172,132 @@ pub fn print_output<T: Serialize>(data: &T, format: OutputFormat) -> Result<()>
             // This should not be called - pretty printing is handled in ui.rs
             unreachable!("Pretty format should be handled separately")
         }
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            bail!("CSV/TSV output is only supported by list commands")
+        }
+        OutputFormat::Markdown => {
+            bail!("Markdown output is not supported by this command")
+        }
+        OutputFormat::Ndjson => {
+            // Arrays become one record per element, anything else a single record
+            match serde_json::to_value(data)? {
+                Value::Array(items) => print_records(&items)?,
+                value => print_records(&[value])?,
+            }
+        }
     }
     Ok(())
 }
 
+/// Page size for NDJSON streams of listings that have no `--limit`
+pub const STREAM_PAGE_SIZE: i64 = 100;
+
+/// Writes NDJSON records (to stdout, in practice), one compact JSON value per line.
+///
+/// Each record is flushed as it is written so output can be piped while a
+/// listing is still being fetched. A closed pipe (e.g. `| head`) ends the
+/// stream quietly instead of failing. `--query` applies to each record.
+pub struct NdjsonWriter<W: Write> {
+    out: W,
+    closed: bool,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, closed: false }
+    }
+
+    /// Whether the reader went away; nothing more will be written
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn write<T: Serialize>(&mut self, record: &T) -> Result<()> {
+        if self.closed {
+            return Ok(());
+        }
+        let options = query_options();
+        let line = match &options.query {
+            None if !options.raw => serde_json::to_string(record)?,
+            query => {
+                let record = match query {
+                    Some(query) => project(record, query)?,
+                    None => serde_json::to_value(record)?,
+                };
+                match record {
+                    Value::String(s) if options.raw => s,
+                    record => serde_json::to_string(&record)?,
+                }
+            }
+        };
+        match writeln!(self.out, "{}", line).and_then(|_| self.out.flush()) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {
+                self.closed = true;
+                Ok(())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Print each record as an NDJSON line
+pub fn print_records<T: Serialize>(records: &[T]) -> Result<()> {
+    let mut writer = NdjsonWriter::new(io::stdout().lock());
+    for record in records {
+        writer.write(record)?;
+    }
+    Ok(())
+}
+
+/// One page of a paginated listing
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Total number of items across all pages
+    pub total: i64,
+}
+
+/// Stream every page of a listing to `out` as NDJSON, starting at `offset`.
+///
+/// `fetch(offset, limit)` loads one page. Pages are written as they arrive
+/// and then dropped, so memory use stays flat however large the listing.
+pub fn stream_pages<T: Serialize>(
+    out: impl Write,
+    offset: i64,
+    page_size: i64,
+    mut fetch: impl FnMut(i64, i64) -> Result<Page<T>>,
+) -> Result<()> {
+    let mut writer = NdjsonWriter::new(out);
+    let page_size = page_size.max(1);
+    let mut offset = offset.max(0);
+    loop {
+        let page = fetch(offset, page_size)?;
+        let count = page.items.len() as i64;
+        for item in &page.items {
+            writer.write(item)?;
+        }
+        offset += count;
+        if count == 0 || count < page_size || offset >= page.total || writer.is_closed() {
+            return Ok(());
+        }
+    }
+}
+
+/// Print the result of a list command: `table` for CSV/TSV and Markdown, `data` as-is otherwise
+pub fn print_list<T: Serialize>(data: &T, format: OutputFormat, table: impl FnOnce() -> Table) -> Result<()> {
+    let options = table::options();
+    if format.is_delimited() {
+        print!("{}", to_delimited(&table().view(&options, false)?, format, options.header));
+        Ok(())
+    } else if format == OutputFormat::Markdown {
+        // Same columns as the terminal table, which keeps reports readable
+        print!("{}", markdown::table(&table().view(&options, true)?));
+        Ok(())
+    } else {
+        print_output(data, format)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,6 +310,16 @@ mod tests {
         active: bool,
     }
 
+    #[test]
+    fn test_envelope_field_order() {
+        let data = TestData { name: "a".to_string(), count: 1, active: true };
+        let envelope = Envelope { api_version: schema::API_VERSION, kind: "MemoryRecall", data: &data };
+        assert_eq!(
+            serde_json::to_string(&envelope).unwrap(),
+            r#"{"apiVersion":"hindsight.cli/v1","kind":"MemoryRecall","data":{"name":"a","count":1,"active":true}}"#
+        );
+    }
+
     #[test]
     fn test_output_format_from_str_json() {
         assert_eq!(OutputFormat::from_str("json"), Some(OutputFormat::Json));
@@ -83,10 +345,83 @@ mod tests {
     #[test]
     fn test_output_format_from_str_invalid() {
         assert_eq!(OutputFormat::from_str("xml"), None);
-        assert_eq!(OutputFormat::from_str("csv"), None);
+        assert_eq!(OutputFormat::from_str("excel"), None);
         assert_eq!(OutputFormat::from_str(""), None);
     }
 
+    #[test]
+    fn test_output_format_from_str_delimited() {
+        assert_eq!(OutputFormat::from_str("CSV"), Some(OutputFormat::Csv));
+        assert_eq!(OutputFormat::from_str("tsv"), Some(OutputFormat::Tsv));
+        assert!(OutputFormat::Csv.is_delimited());
+        assert!(!OutputFormat::Json.is_delimited());
+    }
+
+    #[test]
+    fn test_to_delimited_quoting() {
+        let mut table = Table::new(&["id", "text"]);
+        table.push(vec!["1".to_string(), "plain".to_string()]);
+        table.push(vec!["2".to_string(), "a, \"b\"\nc\td\\".to_string()]);
+
+        assert_eq!(
+            to_delimited(&table, OutputFormat::Csv, true),
+            "id,text\n1,plain\n2,\"a, \"\"b\"\"\nc\td\\\"\n"
+        );
+        assert_eq!(to_delimited(&table, OutputFormat::Tsv, false), "1\tplain\n2\ta, \"b\"\\nc\\td\\\\\n");
+    }
+
+    #[test]
+    fn test_output_format_from_str_markdown() {
+        assert_eq!(OutputFormat::from_str("markdown"), Some(OutputFormat::Markdown));
+        assert_eq!(OutputFormat::from_str("MD"), Some(OutputFormat::Markdown));
+    }
+
+    #[test]
+    fn test_output_format_from_str_ndjson() {
+        assert_eq!(OutputFormat::from_str("ndjson"), Some(OutputFormat::Ndjson));
+        assert_eq!(OutputFormat::from_str("JSONL"), Some(OutputFormat::Ndjson));
+    }
+
+    #[test]
+    fn test_stream_pages_fetches_until_total() {
+        let items: Vec<i64> = (0..7).collect();
+        let mut requests = Vec::new();
+        let mut out = Vec::new();
+        stream_pages(&mut out, 1, 3, |offset, limit| {
+            requests.push((offset, limit));
+            let page = items.iter().copied().skip(offset as usize).take(limit as usize).collect();
+            Ok(Page { items: page, total: items.len() as i64 })
+        })
+        .unwrap();
+
+        // 6 items from offset 1: two full pages, and the total ends the stream
+        assert_eq!(requests, vec![(1, 3), (4, 3)]);
+        assert_eq!(String::from_utf8(out).unwrap(), "1\n2\n3\n4\n5\n6\n");
+    }
+
+    #[test]
+    fn test_stream_pages_stops_on_short_page() {
+        let mut calls = 0;
+        let mut out = Vec::new();
+        stream_pages(&mut out, 0, 10, |_, _| {
+            calls += 1;
+            // A short page is the last one, whatever total the server reports
+            Ok(Page { items: vec![serde_json::json!({"n": calls})], total: i64::MAX })
+        })
+        .unwrap();
+        assert_eq!(calls, 1);
+        assert_eq!(String::from_utf8(out).unwrap(), "{\"n\":1}\n");
+    }
+
+    #[test]
+    fn test_raw_lines() {
+        let lines = |value: Value| raw_lines(&value).unwrap();
+        assert_eq!(lines(serde_json::json!("a \"quoted\" text")), vec!["a \"quoted\" text"]);
+        assert_eq!(lines(serde_json::json!(["one", 2, {"k": "v"}])), vec!["one", "2", "{\"k\":\"v\"}"]);
+        assert!(lines(serde_json::json!([])).is_empty());
+        assert_eq!(lines(serde_json::json!({"k": 1})), vec!["{\n  \"k\": 1\n}"]);
+    }
+
     #[test]
     fn test_to_json() {
         let data = TestData {
@@ -0,0 +1,121 @@
+//! `--query`: JMESPath expressions applied to structured output.
+//!
+//! Parsing and evaluation are done by the `jmespath` crate, so the full
+//! JMESPath grammar and built-in functions behave as in the specification.
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed `--query` expression
+#[derive(Clone)]
+pub struct Query {
+    expression: jmespath::Expression<'static>,
+}
+
+impl FromStr for Query {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        jmespath::compile(s).map(|expression| Query { expression }).map_err(|e| e.to_string())
+    }
+}
+
+impl fmt::Debug for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Query").field(&self.expression.as_str()).finish()
+    }
+}
+
+impl Query {
+    pub fn apply(&self, value: &Value) -> Result<Value> {
+        let result = self.expression.search(value).map_err(|e| anyhow!(e.to_string()))?;
+        Ok(serde_json::to_value(&*result)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn query(expr: &str, value: &Value) -> Value {
+        expr.parse::<Query>().unwrap().apply(value).unwrap()
+    }
+
+    fn recall() -> Value {
+        json!({
+            "results": [
+                {"id": "m1", "text": "Alice likes tea", "type": "world", "entities": ["Alice"], "score": 0.9},
+                {"id": "m2", "text": "Bob moved to Paris", "type": "experience", "entities": ["Bob", "Paris"], "score": 0.4},
+                {"id": "m3", "text": "Alice met Bob", "type": "world", "entities": null, "score": 0.7}
+            ],
+            "trace": {"total_ms": 12}
+        })
+    }
+
+    #[test]
+    fn test_fields_indexes_and_slices() {
+        let data = recall();
+        assert_eq!(query("trace.total_ms", &data), json!(12));
+        assert_eq!(query("results[0].id", &data), json!("m1"));
+        assert_eq!(query("results[-1].id", &data), json!("m3"));
+        assert_eq!(query("results[5]", &data), Value::Null);
+        assert_eq!(query("results[::-1].id", &data), json!(["m3", "m2", "m1"]));
+        assert_eq!(query("results[1:].id", &data), json!(["m2", "m3"]));
+        assert_eq!(query("\"trace\".total_ms", &data), json!(12));
+        assert_eq!(query("missing.field", &data), Value::Null);
+    }
+
+    #[test]
+    fn test_projections_and_flatten() {
+        let data = recall();
+        assert_eq!(query("results[].text", &data), json!(["Alice likes tea", "Bob moved to Paris", "Alice met Bob"]));
+        assert_eq!(query("results[*].entities", &data), json!([["Alice"], ["Bob", "Paris"]]));
+        assert_eq!(query("results[].entities[]", &data), json!(["Alice", "Bob", "Paris"]));
+        assert_eq!(query("trace.*", &data), json!([12]));
+        // A pipe ends the projection, so [0] indexes the projected list
+        assert_eq!(query("results[*].id | [0]", &data), json!("m1"));
+    }
+
+    #[test]
+    fn test_filters_and_multiselect() {
+        let data = recall();
+        assert_eq!(query("results[?type == 'world'].id", &data), json!(["m1", "m3"]));
+        assert_eq!(query("results[?score > `0.5` && !contains(text, 'tea')].id", &data), json!(["m3"]));
+        assert_eq!(
+            query("results[?type != 'world'].{id: id, n: length(entities)}", &data),
+            json!([{"id": "m2", "n": 2}])
+        );
+        assert_eq!(query("results[0].[id, score]", &data), json!(["m1", 0.9]));
+        assert_eq!(query("nothing || 'default'", &data), json!("default"));
+    }
+
+    #[test]
+    fn test_functions() {
+        let data = recall();
+        assert_eq!(query("length(results)", &data), json!(3));
+        assert_eq!(query("sort_by(results, &score)[].id", &data), json!(["m2", "m3", "m1"]));
+        assert_eq!(query("max_by(results, &score).id", &data), json!("m1"));
+        // JMESPath sums are floating point
+        assert_eq!(query("sum(results[].length(text))", &data), json!(46.0));
+        assert_eq!(query("join(', ', sort(results[].id))", &data), json!("m1, m2, m3"));
+        assert_eq!(query("keys(trace)", &data), json!(["total_ms"]));
+
+        let err = "length(trace.total_ms)".parse::<Query>().unwrap().apply(&data).unwrap_err();
+        assert!(err.to_string().contains("length"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // Errors point at the offending column
+        let err = "results[".parse::<Query>().unwrap_err();
+        assert!(err.starts_with("Parse error"), "{}", err);
+        assert!(err.ends_with("results[\n        ^\n"), "{}", err);
+        assert!("a b".parse::<Query>().is_err());
+        assert!("results[?type == 'world'".parse::<Query>().is_err());
+        assert!("'unterminated".parse::<Query>().is_err());
+        assert!("a.`{bad`".parse::<Query>().is_err());
+    }
+}
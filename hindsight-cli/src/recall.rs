@@ -0,0 +1,218 @@
+//! The grouped view of recall results (`memory recall --group-by`).
+//!
+//! Results are clustered by document or entity, keeping the ranking: groups
+//! appear in the order of their best result, and results keep their order
+//! within a group. The helpers here are pure; `ui::print_grouped_results`
+//! prints them.
+
+use crate::api::RecallResult;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use clap::ValueEnum;
+
+/// Query words too common to highlight
+const STOPWORDS: &[&str] = &[
+    "about", "and", "are", "did", "does", "for", "from", "has", "have", "how", "the", "that", "this", "was",
+    "were", "what", "when", "where", "which", "who", "why", "with", "you", "your",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum GroupBy {
+    /// The document each fact was extracted from
+    Document,
+    /// Each entity a fact mentions; a fact can appear under several
+    Entity,
+}
+
+/// Results sharing a document or entity; `key` is `None` for results without one
+#[derive(Debug)]
+pub struct Group<'a> {
+    pub key: Option<String>,
+    pub results: Vec<&'a RecallResult>,
+}
+
+pub fn group(results: &[RecallResult], by: GroupBy) -> Vec<Group<'_>> {
+    let mut groups: Vec<Group> = Vec::new();
+    for result in results {
+        let keys: Vec<Option<String>> = match by {
+            GroupBy::Document => vec![result.document_id.clone()],
+            GroupBy::Entity => match result.entities.as_deref() {
+                Some(entities) if !entities.is_empty() => entities.iter().cloned().map(Some).collect(),
+                _ => vec![None],
+            },
+        };
+        for key in keys {
+            match groups.iter_mut().find(|g| g.key == key) {
+                Some(group) => group.results.push(result),
+                None => groups.push(Group { key, results: vec![result] }),
+            }
+        }
+    }
+    // Ungrouped results go last whatever their rank
+    groups.sort_by_key(|g| g.key.is_none());
+    groups
+}
+
+/// Query words worth highlighting, lowercased, with a plural `s` dropped
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in query.split(|c: char| !c.is_alphanumeric()).map(str::to_lowercase) {
+        if word.chars().count() < 3 || STOPWORDS.contains(&word.as_str()) {
+            continue;
+        }
+        let term = match word.strip_suffix('s') {
+            Some(stem) if stem.chars().count() >= 4 => stem.to_string(),
+            _ => word,
+        };
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Byte ranges of the words in `text` that start with a query term
+fn term_spans(text: &str, terms: &[String]) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, ch) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        match (ch.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                let word = text[s..i].to_lowercase();
+                if terms.iter().any(|term| word.starts_with(term.as_str())) {
+                    spans.push((s, i));
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+/// `text` with the words matching a query term passed through `paint`
+pub fn highlight(text: &str, terms: &[String], paint: impl Fn(&str) -> String) -> String {
+    let mut out = String::new();
+    let mut last = 0;
+    for (start, end) in term_spans(text, terms) {
+        out.push_str(&text[last..start]);
+        out.push_str(&paint(&text[start..end]));
+        last = end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Up to `max_chars` of `text` on one line, around the first query term
+pub fn excerpt(text: &str, terms: &[String], max_chars: usize) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let chars: Vec<char> = flat.chars().collect();
+    if chars.len() <= max_chars {
+        return flat;
+    }
+    // Start a third of the way before the first hit, so it has some context
+    let hit = term_spans(&flat, terms).first().map(|&(start, _)| flat[..start].chars().count()).unwrap_or(0);
+    let start = hit.saturating_sub(max_chars / 3).min(chars.len() - max_chars);
+    let end = start + max_chars;
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out.push_str(chars[start..end].iter().collect::<String>().trim());
+    if end < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
+/// Timestamps come as RFC 3339, naive date-times (UTC) or plain dates
+fn parse_time(timestamp: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(timestamp) {
+        return Some(time.with_timezone(&Utc));
+    }
+    if let Ok(time) = NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(time.and_utc());
+    }
+    NaiveDate::parse_from_str(timestamp, "%Y-%m-%d").ok().map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+}
+
+/// `timestamp` relative to `now`, e.g. "3 days ago" or "in 2 months"
+pub fn relative_time(timestamp: &str, now: DateTime<Utc>) -> Option<String> {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let seconds = (now - parse_time(timestamp)?).num_seconds();
+    let (amount, unit) = match seconds.abs() {
+        s if s < MINUTE => return Some("just now".to_string()),
+        s if s < HOUR => (s / MINUTE, "minute"),
+        s if s < DAY => (s / HOUR, "hour"),
+        s if s < 30 * DAY => (s / DAY, "day"),
+        s if s < 365 * DAY => (s / (30 * DAY), "month"),
+        s => (s / (365 * DAY), "year"),
+    };
+    let unit = if amount == 1 { unit.to_string() } else { format!("{}s", unit) };
+    Some(if seconds >= 0 { format!("{} {} ago", amount, unit) } else { format!("in {} {}", amount, unit) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn results() -> Vec<RecallResult> {
+        serde_json::from_value(json!([
+            {"id": "m1", "text": "Alice likes tea", "document_id": "d1", "entities": ["Alice"]},
+            {"id": "m2", "text": "Loose fact"},
+            {"id": "m3", "text": "Bob met Alice", "document_id": "d2", "entities": ["Bob", "Alice"]},
+            {"id": "m4", "text": "Alice drinks coffee", "document_id": "d1", "entities": []}
+        ]))
+        .unwrap()
+    }
+
+    fn ids<'a>(group: &Group<'a>) -> Vec<&'a str> {
+        group.results.iter().map(|r| r.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_group_by_document_and_entity() {
+        let results = results();
+
+        let groups = group(&results, GroupBy::Document);
+        let keys: Vec<_> = groups.iter().map(|g| g.key.as_deref()).collect();
+        assert_eq!(keys, vec![Some("d1"), Some("d2"), None]);
+        assert_eq!(ids(&groups[0]), vec!["m1", "m4"]);
+
+        let groups = group(&results, GroupBy::Entity);
+        let keys: Vec<_> = groups.iter().map(|g| g.key.as_deref()).collect();
+        assert_eq!(keys, vec![Some("Alice"), Some("Bob"), None]);
+        assert_eq!(ids(&groups[0]), vec!["m1", "m3"]);
+        assert_eq!(ids(&groups[2]), vec!["m2", "m4"]);
+    }
+
+    #[test]
+    fn test_highlight_and_excerpt() {
+        let terms = query_terms("What drinks does Alice like?");
+        assert_eq!(terms, vec!["drink", "alice", "like"]);
+        assert_eq!(
+            highlight("Alice likes drinking tea, Malice doesn't", &terms, |w| format!("*{}*", w)),
+            "*Alice* *likes* *drinking* tea, Malice doesn't"
+        );
+
+        let text = "Intro line.\n\nSome filler words here and then Alice arrives at the cafe for tea";
+        assert_eq!(excerpt(text, &terms, 20), "…then Alice arrives…");
+        assert_eq!(excerpt("Short  text", &terms, 20), "Short text");
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = DateTime::parse_from_rfc3339("2024-06-15T12:00:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(relative_time("2024-06-15T11:59:30Z", now).as_deref(), Some("just now"));
+        assert_eq!(relative_time("2024-06-15T09:00:00+00:00", now).as_deref(), Some("3 hours ago"));
+        assert_eq!(relative_time("2024-06-14T12:00:00", now).as_deref(), Some("1 day ago"));
+        assert_eq!(relative_time("2024-03-01", now).as_deref(), Some("3 months ago"));
+        assert_eq!(relative_time("2026-07-01", now).as_deref(), Some("in 2 years"));
+        assert_eq!(relative_time("someday", now), None);
+    }
+}
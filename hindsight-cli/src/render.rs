@@ -0,0 +1,720 @@
+//! Markdown answers rendered for the terminal.
+//!
+//! Reflect answers are usually Markdown. [`render`] lays one out as styled
+//! lines wrapped to a width: headings, emphasis, lists, quotes, tables and
+//! syntax-highlighted code blocks.
+//!
+//! The answer cites the `based_on` entries listed under it by number, e.g.
+//! `[2]`. The server only reports which entries the whole answer used, so an
+//! entry is cited where the answer mentions its id, and any not mentioned are
+//! cited together at the end of the answer.
+//!
+//! Styling is decided once per render rather than by `colored`'s global
+//! switch, so the plain layout can be produced (and tested) on its own.
+
+use crate::api::types::ReflectBasedOn;
+use crate::table;
+use crate::ui;
+use colored::Colorize;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, LinkType, Options, Parser, Tag, TagEnd};
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::{as_24_bit_terminal_escaped, LinesWithEndings};
+use unicode_width::UnicodeWidthStr;
+
+/// Answers are wrapped to the terminal, but never wider than this
+const MAX_WIDTH: usize = 100;
+const CODE_THEME: &str = "base16-ocean.dark";
+/// Private-use characters around a source number, standing in for a cited id
+/// until the text is laid out
+const CITE_OPEN: char = '\u{E000}';
+const CITE_CLOSE: char = '\u{E001}';
+
+static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+
+/// Width to wrap answers to: the terminal's, capped at [`MAX_WIDTH`]
+pub fn terminal_width() -> usize {
+    let columns = if std::io::stdout().is_terminal() {
+        crossterm::terminal::size().ok().map(|(columns, _)| columns as usize)
+    } else {
+        None
+    };
+    columns.unwrap_or(MAX_WIDTH).min(MAX_WIDTH)
+}
+
+/// A `based_on` entry an answer can cite
+pub struct Source {
+    pub number: usize,
+    id: Option<String>,
+}
+
+/// Citable sources, numbered in the order they are listed: memories, mental
+/// models, then directives
+pub fn sources(based_on: &ReflectBasedOn) -> Vec<Source> {
+    let ids = based_on
+        .memories
+        .iter()
+        .map(|memory| memory.id.clone())
+        .chain(based_on.mental_models.iter().map(|model| Some(model.id.clone())))
+        .chain(based_on.directives.iter().map(|directive| Some(directive.id.clone())));
+    ids.enumerate().map(|(i, id)| Source { number: i + 1, id }).collect()
+}
+
+fn placeholder(number: usize) -> String {
+    format!("{}{}{}", CITE_OPEN, number, CITE_CLOSE)
+}
+
+/// Replace whole-word mentions of `id` in `text`, with any brackets or
+/// parentheses around them, by `with`. Returns whether there were any.
+fn replace_id(text: &mut String, id: &str, with: &str) -> bool {
+    let part_of_id = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+    let mut found = false;
+    while let Some(start) = rest.find(id) {
+        let (before, after) = (&rest[..start], &rest[start + id.len()..]);
+        if before.chars().next_back().is_some_and(part_of_id) || after.chars().next().is_some_and(part_of_id) {
+            out.push_str(&rest[..start + id.len()]);
+            rest = after;
+            continue;
+        }
+        let wrapped = match (before.chars().next_back(), after.chars().next()) {
+            (Some('['), Some(']')) | (Some('('), Some(')')) => 1,
+            _ => 0,
+        };
+        out.push_str(&before[..before.len() - wrapped]);
+        out.push_str(with);
+        rest = &after[wrapped..];
+        found = true;
+    }
+    out.push_str(rest);
+    *text = out;
+    found
+}
+
+/// `markdown` with the ids of `sources` it mentions replaced by placeholders,
+/// and the numbers of the sources it doesn't mention
+fn cite(markdown: &str, sources: &[Source]) -> (String, Vec<usize>) {
+    let mut text = markdown.to_string();
+    let mut uncited = Vec::new();
+    for source in sources {
+        let id = source.id.as_deref().filter(|id| !id.is_empty());
+        if !id.is_some_and(|id| replace_id(&mut text, id, &placeholder(source.number))) {
+            uncited.push(source.number);
+        }
+    }
+    (text, uncited)
+}
+
+/// Pieces of `text`, each flagged if it is a citation marker such as `[2]`
+fn split_citations(text: &str) -> Vec<(String, bool)> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find(CITE_OPEN) {
+        let Some(close) = rest[open..].find(CITE_CLOSE).map(|close| open + close) else {
+            break;
+        };
+        if open > 0 {
+            pieces.push((rest[..open].to_string(), false));
+        }
+        pieces.push((format!("[{}]", &rest[open + CITE_OPEN.len_utf8()..close]), true));
+        rest = &rest[close + CITE_CLOSE.len_utf8()..];
+    }
+    if !rest.is_empty() {
+        pieces.push((rest.to_string(), false));
+    }
+    pieces
+}
+
+/// `text` with citation markers written out, for code and table cells
+fn plain_citations(text: &str) -> String {
+    split_citations(text).into_iter().map(|(piece, _)| piece).collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+    link: bool,
+    heading: bool,
+    dim: bool,
+}
+
+fn paint(text: &str, style: Style, colors: bool) -> String {
+    if !colors {
+        return text.to_string();
+    }
+    if style.dim {
+        return ui::dim(text);
+    }
+    let mut painted = if style.heading { ui::gradient_start(text).normal() } else { text.normal() };
+    if style.code {
+        painted = painted.cyan();
+    }
+    if style.link {
+        painted = painted.underline();
+    }
+    if style.bold || style.heading {
+        painted = painted.bold();
+    }
+    if style.italic {
+        painted = painted.italic();
+    }
+    if style.strike {
+        painted = painted.strikethrough();
+    }
+    painted.to_string()
+}
+
+/// Inline content of the block being read
+enum Inline {
+    Text(String, Style),
+    Break,
+}
+
+/// A word to wrap, possibly in several styles (`**bold**,`)
+enum Token {
+    Word(Vec<(String, Style)>),
+    Break,
+}
+
+fn tokens(inline: &[Inline]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut word: Vec<(String, Style)> = Vec::new();
+    for item in inline {
+        match item {
+            Inline::Break => {
+                if !word.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut word)));
+                }
+                tokens.push(Token::Break);
+            }
+            Inline::Text(text, style) => {
+                for ch in text.chars() {
+                    if ch.is_whitespace() {
+                        if !word.is_empty() {
+                            tokens.push(Token::Word(std::mem::take(&mut word)));
+                        }
+                    } else {
+                        match word.last_mut() {
+                            Some((fragment, last)) if last == style => fragment.push(ch),
+                            _ => word.push((ch.to_string(), *style)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(Token::Word(word));
+    }
+    tokens
+}
+
+/// Text in front of a line, already styled, with its printed width
+#[derive(Clone)]
+struct Prefix {
+    text: String,
+    width: usize,
+}
+
+/// Fill lines greedily up to `width`. A word wider than a line gets one to itself.
+fn wrap(tokens: &[Token], width: usize, first: &Prefix, rest: &Prefix, colors: bool) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = first.text.clone();
+    let mut used = first.width;
+    let mut empty = true;
+    for token in tokens {
+        let fragments = match token {
+            Token::Break => {
+                lines.push(std::mem::replace(&mut line, rest.text.clone()));
+                used = rest.width;
+                empty = true;
+                continue;
+            }
+            Token::Word(fragments) => fragments,
+        };
+        let word_width: usize = fragments.iter().map(|(text, _)| text.width()).sum();
+        if !empty && used + 1 + word_width > width {
+            lines.push(std::mem::replace(&mut line, rest.text.clone()));
+            used = rest.width;
+            empty = true;
+        }
+        if !empty {
+            line.push(' ');
+            used += 1;
+        }
+        for (text, style) in fragments {
+            line.push_str(&paint(text, *style, colors));
+        }
+        used += word_width;
+        empty = false;
+    }
+    lines.push(line);
+    lines
+}
+
+/// `marker` and `text` with later lines indented under the text, e.g. a
+/// numbered source under an answer. `detail` follows the text, dimmed.
+pub fn list_item(marker: &str, text: &str, detail: Option<&str>, width: usize) -> Vec<String> {
+    layout_list_item(marker, text, detail, width, ui::colors_enabled())
+}
+
+fn layout_list_item(marker: &str, text: &str, detail: Option<&str>, width: usize, colors: bool) -> Vec<String> {
+    let painted = if colors { ui::gradient_start(marker) } else { marker.to_string() };
+    let first = Prefix { text: format!("  {} ", painted), width: marker.width() + 3 };
+    let rest = Prefix { text: " ".repeat(first.width), width: first.width };
+    let mut inline = vec![Inline::Text(text.to_string(), Style::default())];
+    if let Some(detail) = detail {
+        inline.push(Inline::Text(format!(" {}", detail), Style { dim: true, ..Style::default() }));
+    }
+    wrap(&tokens(&inline), width, &first, &rest, colors)
+}
+
+/// Syntax-highlighted lines of a code block, plain when colours are off
+fn highlight(code: &str, language: &str, colors: bool) -> Vec<String> {
+    if !colors {
+        return code.lines().map(str::to_string).collect();
+    }
+    let syntaxes = SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines);
+    let themes = THEMES.get_or_init(ThemeSet::load_defaults);
+    let syntax = syntaxes.find_syntax_by_token(language).unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+    let mut highlighter = HighlightLines::new(syntax, &themes.themes[CODE_THEME]);
+    LinesWithEndings::from(code)
+        .map(|line| match highlighter.highlight_line(line, syntaxes) {
+            Ok(ranges) => format!("{}\x1b[0m", as_24_bit_terminal_escaped(&ranges, false).trim_end_matches(['\n', '\r'])),
+            Err(_) => line.trim_end_matches(['\n', '\r']).to_string(),
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct TableBuffer {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    row: Vec<String>,
+    cell: String,
+    header_rows: usize,
+}
+
+struct Renderer {
+    width: usize,
+    colors: bool,
+    lines: Vec<String>,
+    /// The last line printed is blank, so blocks aren't separated twice
+    blank: bool,
+    inline: Vec<Inline>,
+    style: Style,
+    quote: usize,
+    /// Next number of each open ordered list; `None` for bullets
+    lists: Vec<Option<u64>>,
+    /// Continuation indent of each open list item
+    items: Vec<usize>,
+    /// Marker of a list item whose first line hasn't been printed yet
+    marker: Option<String>,
+    /// Language and text of the code block being read
+    code: Option<(String, String)>,
+    table: Option<TableBuffer>,
+    /// Destinations of open links; `None` when the text already is the URL
+    links: Vec<Option<String>>,
+}
+
+impl Renderer {
+    fn new(width: usize, colors: bool) -> Self {
+        Self {
+            width,
+            colors,
+            lines: Vec::new(),
+            blank: true,
+            inline: Vec::new(),
+            style: Style::default(),
+            quote: 0,
+            lists: Vec::new(),
+            items: Vec::new(),
+            marker: None,
+            code: None,
+            table: None,
+            links: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, line: String) {
+        self.lines.push(line);
+        self.blank = false;
+    }
+
+    fn dim(&self, text: &str) -> String {
+        paint(text, Style { dim: true, ..Style::default() }, self.colors)
+    }
+
+    /// Bars in front of lines inside block quotes
+    fn quote_bars(&self) -> String {
+        match self.quote {
+            0 => String::new(),
+            depth => self.dim(&"│ ".repeat(depth)),
+        }
+    }
+
+    /// Separate blocks with one blank line (a bare bar inside quotes)
+    fn separate(&mut self) {
+        if !self.blank {
+            let bars = match self.quote {
+                0 => String::new(),
+                depth => self.dim("│ ".repeat(depth).trim_end()),
+            };
+            self.lines.push(bars);
+            self.blank = true;
+        }
+    }
+
+    /// Prefixes of the first and following lines of a block
+    fn prefixes(&mut self) -> (Prefix, Prefix) {
+        let quote = self.quote_bars();
+        let indent: usize = self.items.iter().sum();
+        let rest = Prefix { text: format!("{}{}", quote, " ".repeat(indent)), width: 2 * self.quote + indent };
+        let first = match self.marker.take() {
+            Some(marker) => Prefix {
+                text: format!(
+                    "{}{}{}",
+                    quote,
+                    " ".repeat(indent - marker.width()),
+                    paint(&marker, Style { heading: true, ..Style::default() }, self.colors)
+                ),
+                width: rest.width,
+            },
+            None => rest.clone(),
+        };
+        (first, rest)
+    }
+
+    /// Print the inline content read so far as a wrapped block
+    fn flush(&mut self) {
+        if self.inline.is_empty() {
+            return;
+        }
+        let tokens = tokens(&std::mem::take(&mut self.inline));
+        let (first, rest) = self.prefixes();
+        for line in wrap(&tokens, self.width, &first, &rest, self.colors) {
+            self.push(line);
+        }
+    }
+
+    fn text(&mut self, text: &str, style: Style) {
+        if let Some(table) = &mut self.table {
+            table.cell.push_str(&plain_citations(text));
+            return;
+        }
+        for (piece, marker) in split_citations(text) {
+            let style = if marker { Style { dim: true, ..Style::default() } } else { style };
+            self.inline.push(Inline::Text(piece, style));
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        if let Some((_, code)) = &mut self.code {
+            match event {
+                Event::Text(text) => code.push_str(&plain_citations(&text)),
+                Event::End(TagEnd::CodeBlock) => self.code_block(),
+                _ => {}
+            }
+            return;
+        }
+
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text, self.style),
+            Event::Code(text) => self.text(&text, Style { code: true, ..self.style }),
+            Event::Html(html) | Event::InlineHtml(html) => self.text(html.trim_end(), self.style),
+            Event::SoftBreak => self.text(" ", self.style),
+            Event::HardBreak => self.inline.push(Inline::Break),
+            Event::TaskListMarker(checked) => self.text(if checked { "[x] " } else { "[ ] " }, self.style),
+            Event::Rule => {
+                self.flush();
+                self.separate();
+                let (_, rest) = self.prefixes();
+                let rule = self.dim(&"─".repeat(self.width.saturating_sub(rest.width)));
+                self.push(format!("{}{}", rest.text, rule));
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            // Paragraphs in list items follow the marker directly
+            Tag::Paragraph if self.items.is_empty() => self.separate(),
+            Tag::Heading { .. } => {
+                self.separate();
+                self.style.heading = true;
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.separate();
+                self.quote += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                self.separate();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or("").to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(first) => {
+                // Text of a tight item comes before its nested list
+                self.flush();
+                if self.lists.is_empty() {
+                    self.separate();
+                }
+                self.lists.push(first);
+            }
+            Tag::Item => {
+                self.flush();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        let marker = format!("{}. ", number);
+                        *number += 1;
+                        marker
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.items.push(marker.width());
+                self.marker = Some(marker);
+            }
+            Tag::Table(alignments) => {
+                self.flush();
+                self.separate();
+                self.table = Some(TableBuffer { alignments, ..TableBuffer::default() });
+            }
+            Tag::Emphasis => self.style.italic = true,
+            Tag::Strong => self.style.bold = true,
+            Tag::Strikethrough => self.style.strike = true,
+            Tag::Link { link_type, dest_url, .. } | Tag::Image { link_type, dest_url, .. } => {
+                let shown = matches!(link_type, LinkType::Autolink | LinkType::Email) || dest_url.starts_with('#');
+                self.links.push((!shown).then(|| dest_url.to_string()));
+                self.style.link = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.flush(),
+            TagEnd::Heading(_) => {
+                self.flush();
+                self.style.heading = false;
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quote -= 1;
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::Item => {
+                self.flush();
+                self.items.pop();
+                self.marker = None;
+            }
+            TagEnd::TableCell => {
+                if let Some(table) = &mut self.table {
+                    let cell = std::mem::take(&mut table.cell);
+                    table.row.push(cell.split_whitespace().collect::<Vec<_>>().join(" "));
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(table) = &mut self.table {
+                    let row = std::mem::take(&mut table.row);
+                    table.rows.push(row);
+                    if tag == TagEnd::TableHead {
+                        table.header_rows = table.rows.len();
+                    }
+                }
+            }
+            TagEnd::Table => self.table_block(),
+            TagEnd::Emphasis => self.style.italic = false,
+            TagEnd::Strong => self.style.bold = false,
+            TagEnd::Strikethrough => self.style.strike = false,
+            TagEnd::Link | TagEnd::Image => {
+                self.style.link = false;
+                if let Some(Some(url)) = self.links.pop() {
+                    self.text(&format!(" ({})", url), Style { dim: true, ..Style::default() });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn code_block(&mut self) {
+        let Some((language, code)) = self.code.take() else {
+            return;
+        };
+        let (_, rest) = self.prefixes();
+        for line in highlight(&code, &language, self.colors) {
+            self.push(format!("{}    {}", rest.text, line).trim_end().to_string());
+        }
+    }
+
+    /// Columns fitted to the width like list tables, header in bold
+    fn table_block(&mut self) {
+        let Some(buffer) = self.table.take() else {
+            return;
+        };
+        let columns = buffer.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+        let natural: Vec<usize> = (0..columns)
+            .map(|i| buffer.rows.iter().filter_map(|row| row.get(i)).map(|cell| cell.width()).max().unwrap_or(0))
+            .collect();
+        let (_, rest) = self.prefixes();
+        let widths = table::fit_widths(&natural, self.width.saturating_sub(rest.width));
+
+        for (r, row) in buffer.rows.iter().enumerate() {
+            let cells: Vec<String> = (0..columns)
+                .map(|i| {
+                    let text = table::truncate(row.get(i).map(String::as_str).unwrap_or(""), widths[i]);
+                    // Padded by display width, as `format!` would count wide characters as one column
+                    let space = widths[i].saturating_sub(text.width());
+                    match buffer.alignments.get(i) {
+                        Some(Alignment::Right) => format!("{}{}", " ".repeat(space), text),
+                        Some(Alignment::Center) => format!("{}{}{}", " ".repeat(space / 2), text, " ".repeat(space - space / 2)),
+                        _ => table::pad(&text, widths[i]),
+                    }
+                })
+                .collect();
+            let line = cells.join("  ").trim_end().to_string();
+            if r < buffer.header_rows {
+                self.push(format!("{}{}", rest.text, paint(&line, Style { bold: true, ..Style::default() }, self.colors)));
+                if r + 1 == buffer.header_rows {
+                    let rule = widths.iter().map(|&w| "─".repeat(w)).collect::<Vec<_>>().join("  ");
+                    self.push(format!("{}{}", rest.text, self.dim(&rule)));
+                }
+            } else {
+                self.push(format!("{}{}", rest.text, line));
+            }
+        }
+    }
+}
+
+/// Lay out Markdown as terminal lines wrapped to `width`, citing `sources`
+pub fn render(markdown: &str, width: usize, sources: &[Source]) -> Vec<String> {
+    layout(markdown, width, sources, ui::colors_enabled())
+}
+
+/// [`render`], styled only if `colors` is set
+fn layout(markdown: &str, width: usize, sources: &[Source], colors: bool) -> Vec<String> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let (markdown, uncited) = cite(markdown, sources);
+    let mut events: Vec<Event> = Parser::new_ext(&markdown, options).collect();
+    if !uncited.is_empty() {
+        // Sources the answer doesn't mention are cited after its last words:
+        // in the closing paragraph or list item, else a paragraph of their own
+        let markers: String = uncited.iter().map(|&number| placeholder(number)).collect();
+        let end = events.iter().rposition(|event| !matches!(event, Event::End(_))).map_or(0, |i| i + 1);
+        match events.get(end) {
+            Some(Event::End(TagEnd::Paragraph | TagEnd::Item)) => {
+                events.insert(end, Event::Text(format!(" {}", markers).into()));
+            }
+            _ => events.extend([
+                Event::Start(Tag::Paragraph),
+                Event::Text(markers.into()),
+                Event::End(TagEnd::Paragraph),
+            ]),
+        }
+    }
+    let mut renderer = Renderer::new(width, colors);
+    for event in events {
+        renderer.event(event);
+    }
+    renderer.flush();
+    renderer.lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_blocks() {
+        let markdown = "# Summary\n\nAlice **prefers** tea over coffee in the morning.\n\n\
+                        - first\n- second\n  1. nested\n\n> quoted\n\n```rust\nlet x = 1;\n```\n";
+        assert_eq!(
+            layout(markdown, 24, &[], false),
+            vec![
+                "Summary",
+                "",
+                "Alice prefers tea over",
+                "coffee in the morning.",
+                "",
+                "• first",
+                "• second",
+                "  1. nested",
+                "",
+                "│ quoted",
+                "",
+                "    let x = 1;",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let markdown = "| Name | Count |\n| --- | ---: |\n| tea | 3 |\n| coffee | 12 |\n";
+        assert_eq!(
+            layout(markdown, 80, &[], false),
+            vec!["Name    Count", "──────  ─────", "tea         3", "coffee     12"]
+        );
+    }
+
+    #[test]
+    fn test_render_table_wide_characters() {
+        let markdown = "| City | Visits |\n| :---: | --- |\n| 東京 | 3 |\n| Paris | 12 |\n";
+        assert_eq!(
+            layout(markdown, 80, &[], false),
+            vec!["City   Visits", "─────  ──────", "東京   3", "Paris  12"]
+        );
+    }
+
+    #[test]
+    fn test_list_item_hangs() {
+        assert_eq!(
+            layout_list_item("[1]", "Alice likes green tea", Some("(world)"), 20, false),
+            vec!["  [1] Alice likes", "      green tea", "      (world)"]
+        );
+    }
+
+    #[test]
+    fn test_citations() {
+        let based_on: ReflectBasedOn = serde_json::from_value(serde_json::json!({
+            "memories": [
+                {"id": "3f2a", "text": "Alice likes green tea"},
+                {"id": "9c1b", "text": "Bob moved to Paris in 2021"},
+                {"text": "Alice works remotely"}
+            ],
+            "mental_models": [{"id": "alice", "text": "Alice: prefers tea"}],
+            "directives": [{"id": "d1", "name": "tone", "content": "Be brief"}]
+        }))
+        .unwrap();
+        let sources = sources(&based_on);
+        assert_eq!(sources.iter().map(|s| s.number).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        // Mentioned ids become their markers; the rest are cited at the end.
+        // "alice-notes" isn't the id "alice", so isn't a mention of it.
+        let lines = layout("Alice likes tea [3f2a].\n\n- Bob lives in Paris (9c1b)\n- See alice-notes\n", 80, &sources, false);
+        assert_eq!(lines, vec!["Alice likes tea [1].", "", "• Bob lives in Paris [2]", "• See alice-notes [3][4][5]"]);
+
+        // After a code block they get a paragraph of their own
+        let lines = layout("```\nuse 3f2a\n```\n", 80, &sources, false);
+        assert_eq!(lines, vec!["    use [1]", "", "[2][3][4][5]"]);
+    }
+}
@@ -0,0 +1,342 @@
+//! JSON Schemas of command output, for `hindsight schema` and `--envelope`.
+//!
+//! Every command with structured output is listed in [`COMMANDS`] with the
+//! Rust type it prints, so a schema is generated from the same type that
+//! `-o json` serializes and cannot drift from it.
+
+use schemars::schema::RootSchema;
+use schemars::JsonSchema;
+use serde_json::{json, Map, Value};
+
+use crate::api::{types, AgentStats, MemoryPutResult, OperationsResponse};
+use crate::commands::doctor::DoctorReport;
+use crate::commands::health::{HealthResponse, MetricsOutput};
+use crate::commands::memory::{MemoryUnitDetail, RetainFilesOutput};
+use crate::ConfigureOutput;
+
+/// `apiVersion` of the `--envelope` wrapper; bumped when an output shape changes incompatibly
+pub const API_VERSION: &str = "hindsight.cli/v1";
+
+/// `document list` prints the API page, or just the documents with `--date`
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+enum DocumentListOutput {
+    Page(types::ListDocumentsResponse),
+    ByDate(Vec<Map<String, Value>>),
+}
+
+/// A command and the type of its JSON/YAML output
+pub struct CommandOutput {
+    /// Subcommand path, e.g. `memory recall`
+    pub command: &'static str,
+    schema: fn() -> RootSchema,
+}
+
+fn schema<T: JsonSchema>() -> RootSchema {
+    schemars::schema_for!(T)
+}
+
+macro_rules! outputs {
+    ($($command:literal => $ty:ty,)*) => {
+        &[$(CommandOutput { command: $command, schema: schema::<$ty> },)*]
+    };
+}
+
+/// Every command with structured output
+pub static COMMANDS: &[CommandOutput] = outputs! {
+    "bank list" => Vec<types::BankListItem>,
+    "bank create" => types::BankProfileResponse,
+    "bank update" => types::BankProfileResponse,
+    "bank disposition" => types::BankProfileResponse,
+    "bank stats" => AgentStats,
+    "bank name" => types::BankProfileResponse,
+    "bank mission" => types::BankProfileResponse,
+    "bank background" => types::BackgroundResponse,
+    "bank graph" => types::GraphDataResponse,
+    "bank delete" => types::DeleteResponse,
+    "bank consolidate" => types::ConsolidationResponse,
+    "bank clear-observations" => types::DeleteResponse,
+    "bank config" => types::BankConfigResponse,
+    "bank set-config" => types::BankConfigResponse,
+    "bank reset-config" => types::BankConfigResponse,
+    "memory list" => types::ListMemoryUnitsResponse,
+    "memory get" => MemoryUnitDetail,
+    "memory recall" => types::RecallResponse,
+    "memory reflect" => types::ReflectResponse,
+    "memory retain" => MemoryPutResult,
+    "memory retain-files" => RetainFilesOutput,
+    "memory delete" => types::DeleteResponse,
+    "memory clear" => types::DeleteResponse,
+    "document list" => DocumentListOutput,
+    "document get" => types::DocumentResponse,
+    "document delete" => types::DeleteResponse,
+    "entity list" => types::EntityListResponse,
+    "entity get" => types::EntityDetailResponse,
+    "entity regenerate" => types::EntityDetailResponse,
+    "tag list" => types::ListTagsResponse,
+    "chunk get" => types::ChunkResponse,
+    "operation list" => OperationsResponse,
+    "operation get" => types::OperationStatusResponse,
+    "operation cancel" => types::DeleteResponse,
+    "mental-model list" => types::MentalModelListResponse,
+    "mental-model get" => types::MentalModelResponse,
+    "mental-model create" => types::CreateMentalModelResponse,
+    "mental-model update" => types::MentalModelResponse,
+    "mental-model delete" => types::DeleteResponse,
+    "mental-model refresh" => types::AsyncOperationSubmitResponse,
+    "directive list" => types::DirectiveListResponse,
+    "directive get" => types::DirectiveResponse,
+    "directive create" => types::DirectiveResponse,
+    "directive update" => types::DirectiveResponse,
+    "directive delete" => types::DeleteResponse,
+    "health" => HealthResponse,
+    "metrics" => MetricsOutput,
+    "version" => types::VersionResponse,
+    "doctor" => DoctorReport,
+    "configure" => ConfigureOutput,
+};
+
+/// Generated client types repeat their OpenAPI schema in a `<details>` block
+/// of their doc comment; keep only the prose.
+fn trim_descriptions(schema: &mut Value) {
+    match schema {
+        Value::Object(map) => {
+            if let Some(Value::String(description)) = map.get_mut("description") {
+                if let Some(end) = description.find("<details>") {
+                    description.truncate(end);
+                    description.truncate(description.trim_end().len());
+                }
+            }
+            if map.get("description").is_some_and(|d| d == "") {
+                map.remove("description");
+            }
+            map.values_mut().for_each(trim_descriptions);
+        }
+        Value::Array(items) => items.iter_mut().for_each(trim_descriptions),
+        _ => {}
+    }
+}
+
+/// The output of `command` (`memory recall`), if it has structured output
+pub fn find(command: &str) -> Option<&'static CommandOutput> {
+    COMMANDS.iter().find(|output| output.command == command)
+}
+
+impl CommandOutput {
+    /// The envelope `kind`: the command path in PascalCase, e.g. `MentalModelList`
+    pub fn kind(&self) -> String {
+        self.command
+            .split([' ', '-'])
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// Schema of the output as printed by `-o json`
+    pub fn schema(&self) -> Value {
+        let mut schema = serde_json::to_value((self.schema)()).unwrap_or(Value::Null);
+        trim_descriptions(&mut schema);
+        schema
+    }
+
+    /// Schema of the output wrapped by `--envelope`. Definitions stay at the
+    /// root so `$ref`s in the data schema still resolve.
+    pub fn envelope_schema(&self) -> Value {
+        let mut data = match self.schema() {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        let dialect = data.remove("$schema");
+        let definitions = data.remove("definitions");
+        data.remove("title");
+
+        let mut envelope = json!({
+            "$schema": dialect,
+            "title": self.kind(),
+            "type": "object",
+            "required": ["apiVersion", "kind", "data"],
+            "properties": {
+                "apiVersion": {"const": API_VERSION},
+                "kind": {"const": self.kind()},
+                "data": data,
+            },
+        });
+        if let Some(definitions) = definitions {
+            envelope["definitions"] = definitions;
+        }
+        envelope
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cli;
+    use clap::CommandFactory;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    #[test]
+    fn test_every_entry_is_a_command() {
+        let cli = Cli::command();
+        for output in COMMANDS {
+            let mut command = &cli;
+            for word in output.command.split(' ') {
+                command = command
+                    .find_subcommand(word)
+                    .unwrap_or_else(|| panic!("'{}' is not a command", output.command));
+            }
+            assert!(output.schema().is_object(), "no schema for '{}'", output.command);
+        }
+    }
+
+    #[test]
+    fn test_kind_and_envelope_schema() {
+        let output = find("mental-model list").unwrap();
+        assert_eq!(output.kind(), "MentalModelList");
+
+        let envelope = find("memory recall").unwrap().envelope_schema();
+        assert_eq!(envelope["properties"]["kind"]["const"], "MemoryRecall");
+        assert_eq!(envelope["properties"]["data"]["properties"]["results"]["type"], "array");
+        assert!(envelope["definitions"]["RecallResult"].is_object());
+        assert!(!envelope.to_string().contains("<details>"));
+        assert!(find("explore").is_none());
+    }
+
+    /// What `-o json` prints for a server `payload`, given the command prints `T`
+    fn printed<T: DeserializeOwned + Serialize>(payload: Value) -> Value {
+        serde_json::to_value(serde_json::from_value::<T>(payload).unwrap()).unwrap()
+    }
+
+    /// Check `value` against `schema`: types, required properties, and no
+    /// properties the schema doesn't declare (unless it allows any)
+    fn check(schema: &Value, definitions: &Value, value: &Value, path: &str) -> Result<(), String> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/definitions/");
+            return check(&definitions[name], definitions, value, path);
+        }
+        if let Some(Value::Array(all)) = schema.get("allOf") {
+            for sub in all {
+                check(sub, definitions, value, path)?;
+            }
+        }
+        for key in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(options)) = schema.get(key) {
+                if !options.iter().any(|sub| check(sub, definitions, value, path).is_ok()) {
+                    return Err(format!("{}: {} matches no variant", path, value));
+                }
+            }
+        }
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect(),
+            };
+            let matches = |ty: &str| match ty {
+                "null" => value.is_null(),
+                "boolean" => value.is_boolean(),
+                "integer" => value.is_i64() || value.is_u64(),
+                "number" => value.is_number(),
+                "string" => value.is_string(),
+                "array" => value.is_array(),
+                "object" => value.is_object(),
+                _ => true,
+            };
+            if !types.iter().any(|ty| matches(ty)) {
+                return Err(format!("{}: {} is not {:?}", path, value, types));
+            }
+        }
+        match value {
+            Value::Object(map) => {
+                let properties = schema.get("properties").and_then(Value::as_object);
+                for required in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+                    let name = required.as_str().unwrap_or_default();
+                    if !map.contains_key(name) {
+                        return Err(format!("{}: missing required '{}'", path, name));
+                    }
+                }
+                for (key, item) in map {
+                    let item_path = format!("{}.{}", path, key);
+                    match (properties.and_then(|p| p.get(key)), schema.get("additionalProperties")) {
+                        (Some(sub), _) => check(sub, definitions, item, &item_path)?,
+                        (None, Some(Value::Bool(true))) => {}
+                        (None, Some(sub @ Value::Object(_))) => check(sub, definitions, item, &item_path)?,
+                        (None, _) if properties.is_some() => return Err(format!("{}: not in the schema", item_path)),
+                        (None, _) => {}
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(sub) = schema.get("items") {
+                    for (i, item) in items.iter().enumerate() {
+                        check(sub, definitions, item, &format!("{}[{}]", path, i))?;
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_schemas_describe_printed_output() {
+        let samples = [
+            ("health", printed::<HealthResponse>(json!({"status": "healthy", "database": "connected"}))),
+            ("health", printed::<HealthResponse>(json!({"status": "unhealthy", "database": "error", "error": "timeout"}))),
+            (
+                "memory get",
+                printed::<MemoryUnitDetail>(json!({
+                    "id": "m1", "text": "Alice likes tea", "context": "", "date": "2024-03-01T00:00:00",
+                    "type": "world", "mentioned_at": "2024-03-01T00:00:00", "occurred_start": null,
+                    "occurred_end": null, "entities": ["Alice"], "document_id": "doc1", "chunk_id": null, "tags": []
+                })),
+            ),
+            (
+                "memory get",
+                printed::<MemoryUnitDetail>(json!({
+                    "id": "o1", "text": "Alice prefers tea", "context": "", "date": "", "type": "observation",
+                    "mentioned_at": null, "occurred_start": null, "occurred_end": null, "entities": [],
+                    "document_id": null, "chunk_id": null, "tags": ["drinks"],
+                    "source_memory_ids": ["m1"],
+                    "source_memories": [{"id": "m1", "text": "Alice likes tea", "type": "world", "context": null,
+                                         "occurred_start": null, "mentioned_at": "2024-03-01T00:00:00"}]
+                })),
+            ),
+            (
+                "version",
+                printed::<types::VersionResponse>(json!({
+                    "api_version": "0.4.0",
+                    "features": {"observations": true, "mcp": false, "worker": true, "bank_config_api": true, "file_upload_api": true}
+                })),
+            ),
+            (
+                "operation list",
+                printed::<OperationsResponse>(json!({
+                    "bank_id": "b1",
+                    "operations": [{"id": "op1", "task_type": "retain", "items_count": 2, "document_id": null,
+                                    "created_at": "2024-03-01T00:00:00", "status": "pending", "error_message": null}]
+                })),
+            ),
+            ("bank stats", printed::<AgentStats>(json!({
+                    "bank_id": "b1", "total_nodes": 3, "total_links": 1, "total_documents": 1,
+                    "nodes_by_fact_type": {"world": 3}, "links_by_link_type": {"temporal": 1},
+                    "links_by_fact_type": {"world": 1}, "links_breakdown": {"world": {"temporal": 1}},
+                    "pending_operations": 0, "failed_operations": 0
+                }))),
+        ];
+
+        for (command, sample) in samples {
+            let schema = find(command).unwrap().schema();
+            let definitions = schema.get("definitions").cloned().unwrap_or(Value::Null);
+            if let Err(e) = check(&schema, &definitions, &sample, command) {
+                panic!("'{}' prints output its schema doesn't describe: {}", command, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! Tables for list commands.
+//!
+//! Every list command builds a [`Table`] with a fixed set of columns. The same
+//! table is printed as aligned text in pretty output and as CSV/TSV by
+//! `output::print_list`, with `--columns`, `--sort`, `--wide` and
+//! `--no-header` applied to both.
+
+use anyhow::{anyhow, bail, Result};
+use colored::Colorize;
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Spaces between pretty columns
+const COLUMN_GAP: usize = 2;
+/// Truncation never makes a column narrower than this (or its own width)
+const MIN_COLUMN_WIDTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+    /// Columns shown in pretty output unless `--columns` or `--wide` is given
+    default_columns: Option<Vec<&'static str>>,
+}
+
+/// `--sort <column>[:asc|:desc]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sort {
+    pub column: String,
+    pub descending: bool,
+}
+
+impl FromStr for Sort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (column, descending) = match s.rsplit_once(':') {
+            Some((column, "asc")) => (column, false),
+            Some((column, "desc")) => (column, true),
+            Some((_, order)) => return Err(format!("unknown sort order '{}' (use asc or desc)", order)),
+            None => (s, false),
+        };
+        if column.is_empty() {
+            return Err("missing sort column".to_string());
+        }
+        Ok(Sort { column: column.to_lowercase(), descending })
+    }
+}
+
+/// Table flags given on the command line
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableOptions {
+    pub columns: Option<Vec<String>>,
+    pub sort: Option<Sort>,
+    /// All columns, never truncated
+    pub wide: bool,
+    pub header: bool,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self { columns: None, sort: None, wide: false, header: true }
+    }
+}
+
+static OPTIONS: OnceLock<TableOptions> = OnceLock::new();
+
+/// Set the table flags for this run (first call wins)
+pub fn set_options(options: TableOptions) {
+    let _ = OPTIONS.set(options);
+}
+
+pub fn options() -> TableOptions {
+    OPTIONS.get().cloned().unwrap_or_default()
+}
+
+impl Table {
+    pub fn new(columns: &[&'static str]) -> Self {
+        Self { columns: columns.to_vec(), rows: Vec::new(), default_columns: None }
+    }
+
+    /// One row per JSON object, reading each column from the key of the same name
+    pub fn from_objects<'a>(columns: &[&'static str], objects: impl IntoIterator<Item = &'a Map<String, Value>>) -> Self {
+        let mut table = Self::new(columns);
+        for object in objects {
+            table.push(columns.iter().map(|column| cell(object.get(*column))).collect());
+        }
+        table
+    }
+
+    /// Limit pretty output to `columns` by default
+    pub fn with_default_columns(mut self, columns: &[&'static str]) -> Self {
+        self.default_columns = Some(columns.to_vec());
+        self
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn column_index(&self, name: &str) -> Result<usize> {
+        self.columns
+            .iter()
+            .position(|column| column.eq_ignore_ascii_case(name))
+            .ok_or_else(|| anyhow!("Unknown column '{}'. Available columns: {}", name, self.columns.join(", ")))
+    }
+
+    /// The table as printed: sorted, then narrowed to the selected columns.
+    /// Pretty output starts from the command's default columns.
+    pub fn view(&self, options: &TableOptions, pretty: bool) -> Result<Table> {
+        let mut rows = self.rows.clone();
+        if let Some(sort) = &options.sort {
+            let index = self.column_index(&sort.column)?;
+            rows.sort_by(|a, b| {
+                let order = compare_cells(&a[index], &b[index]);
+                if sort.descending { order.reverse() } else { order }
+            });
+        }
+
+        let selected: Vec<usize> = match (&options.columns, &self.default_columns) {
+            (Some(names), _) => names.iter().map(|name| self.column_index(name)).collect::<Result<_>>()?,
+            (None, Some(defaults)) if pretty && !options.wide => {
+                defaults.iter().map(|name| self.column_index(name)).collect::<Result<_>>()?
+            }
+            _ => (0..self.columns.len()).collect(),
+        };
+        if selected.is_empty() {
+            bail!("No columns selected");
+        }
+
+        Ok(Table {
+            columns: selected.iter().map(|&i| self.columns[i]).collect(),
+            rows: rows.iter().map(|row| selected.iter().map(|&i| row[i].clone()).collect()).collect(),
+            default_columns: None,
+        })
+    }
+}
+
+/// Text of a JSON value in a table cell: empty for null, lists joined with ", "
+pub fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(items)) => items.iter().map(|item| cell(Some(item))).collect::<Vec<_>>().join(", "),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Numbers sort numerically, everything else as text (ISO timestamps sort correctly)
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+/// Shrink the widest column one character at a time until the row fits
+pub fn fit_widths(natural: &[usize], width: usize) -> Vec<usize> {
+    let mut widths = natural.to_vec();
+    let minimums: Vec<usize> = natural.iter().map(|&w| w.min(MIN_COLUMN_WIDTH)).collect();
+    let gaps = COLUMN_GAP * natural.len().saturating_sub(1);
+    while widths.iter().sum::<usize>() + gaps > width {
+        let widest = (0..widths.len())
+            .filter(|&i| widths[i] > minimums[i])
+            .max_by_key(|&i| (widths[i], std::cmp::Reverse(i)));
+        match widest {
+            Some(i) => widths[i] -= 1,
+            None => break,
+        }
+    }
+    widths
+}
+
+/// Fit `text` into `width` terminal columns, marking cut text with an ellipsis
+pub fn truncate(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let mut cut = String::new();
+    let mut used = 0;
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width + 1 > width {
+            break;
+        }
+        cut.push(ch);
+        used += ch_width;
+    }
+    cut.push('…');
+    cut
+}
+
+/// Left-align `text` in `width` terminal columns (`format!` pads by `char`s,
+/// which misaligns wide characters such as CJK)
+pub fn pad(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(text.width())))
+}
+
+/// Lay out a table as aligned lines of plain text. With a `width`, columns are
+/// truncated to fit; line breaks in cells are flattened to spaces.
+pub fn render(table: &Table, width: Option<usize>, header: bool) -> Vec<String> {
+    let flatten = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let header_row: Vec<String> = table.columns.iter().map(|c| c.to_uppercase()).collect();
+    let rows: Vec<Vec<String>> = header
+        .then_some(header_row)
+        .into_iter()
+        .chain(table.rows.iter().map(|row| row.iter().map(|c| flatten(c)).collect()))
+        .collect();
+
+    let natural: Vec<usize> = (0..table.columns.len())
+        .map(|i| rows.iter().map(|row| row[i].width()).max().unwrap_or(0))
+        .collect();
+    let widths = match width {
+        Some(width) => fit_widths(&natural, width),
+        None => natural,
+    };
+
+    rows.iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &w)| pad(&truncate(cell, w), w))
+                .collect();
+            cells.join(&" ".repeat(COLUMN_GAP)).trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Print a table for pretty output, fitted to the terminal unless `--wide`
+pub fn print(table: &Table) -> Result<()> {
+    let options = options();
+    let view = table.view(&options, true)?;
+    let width = if options.wide || !std::io::stdout().is_terminal() {
+        None
+    } else {
+        crossterm::terminal::size().ok().map(|(columns, _)| columns as usize)
+    };
+
+    let lines = render(&view, width, options.header);
+    for (i, line) in lines.iter().enumerate() {
+        if i == 0 && options.header {
+            println!("{}", line.bold());
+        } else {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(&["id", "count", "text"]).with_default_columns(&["id", "text"]);
+        table.push(vec!["b".to_string(), "10".to_string(), "second\nline".to_string()]);
+        table.push(vec!["a".to_string(), "9".to_string(), "first".to_string()]);
+        table
+    }
+
+    #[test]
+    fn test_sort_parse() {
+        assert_eq!("Count".parse(), Ok(Sort { column: "count".to_string(), descending: false }));
+        assert_eq!("count:desc".parse(), Ok(Sort { column: "count".to_string(), descending: true }));
+        assert!("count:up".parse::<Sort>().is_err());
+        assert!(":desc".parse::<Sort>().is_err());
+    }
+
+    #[test]
+    fn test_view_selects_and_sorts() {
+        let options = TableOptions {
+            sort: Some("count".parse().unwrap()),
+            ..TableOptions::default()
+        };
+        // Pretty output uses the default columns; the numeric sort puts 9 before 10
+        let view = table().view(&options, true).unwrap();
+        assert_eq!(view.columns, vec!["id", "text"]);
+        assert_eq!(view.rows[0], vec!["a", "first"]);
+
+        // CSV keeps every column unless asked otherwise
+        assert_eq!(table().view(&options, false).unwrap().columns.len(), 3);
+
+        let options = TableOptions { columns: Some(vec!["TEXT".to_string(), "id".to_string()]), ..options };
+        assert_eq!(table().view(&options, true).unwrap().columns, vec!["text", "id"]);
+
+        let options = TableOptions { columns: Some(vec!["nope".to_string()]), ..TableOptions::default() };
+        let err = table().view(&options, true).unwrap_err().to_string();
+        assert_eq!(err, "Unknown column 'nope'. Available columns: id, count, text");
+    }
+
+    #[test]
+    fn test_render_aligns_and_truncates() {
+        let view = table().view(&TableOptions { wide: true, ..TableOptions::default() }, true).unwrap();
+        assert_eq!(
+            render(&view, None, true),
+            vec!["ID  COUNT  TEXT", "b   10     second line", "a   9      first"]
+        );
+
+        let mut wide = Table::new(&["id", "text"]);
+        wide.push(vec!["m1".to_string(), "a fairly long memory text".to_string()]);
+        assert_eq!(render(&wide, Some(16), false), vec!["m1  a fairly lo…"]);
+        // Columns stop shrinking at their minimum width
+        assert_eq!(render(&wide, Some(4), false), vec!["m1  a fairl…"]);
+    }
+
+    #[test]
+    fn test_render_uses_display_width() {
+        let mut table = Table::new(&["name", "id"]);
+        table.push(vec!["東京タワー".to_string(), "1".to_string()]);
+        table.push(vec!["tea".to_string(), "2".to_string()]);
+        assert_eq!(render(&table, None, false), vec!["東京タワー  1", "tea         2"]);
+        // Each kana is two columns wide, so only three fit before the ellipsis
+        assert_eq!(truncate("東京タワー", 8), "東京タ…");
+    }
+
+    #[test]
+    fn test_table_from_objects() {
+        let objects: Vec<Map<String, Value>> = serde_json::from_str(
+            r#"[{"id": "d1", "tags": ["a", "b"], "count": 3, "extra": true}, {"id": "d2", "count": null}]"#,
+        )
+        .unwrap();
+        let table = Table::from_objects(&["id", "count", "tags"], &objects);
+
+        assert_eq!(table.columns, vec!["id", "count", "tags"]);
+        assert_eq!(
+            table.rows,
+            vec![
+                vec!["d1".to_string(), "3".to_string(), "a, b".to_string()],
+                vec!["d2".to_string(), String::new(), String::new()],
+            ]
+        );
+    }
+}
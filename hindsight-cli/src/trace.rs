@@ -0,0 +1,490 @@
+//! Structured rendering of recall and reflect traces.
+//!
+//! `RecallResponse.trace` is the server's search trace as free-form JSON
+//! (query, per-strategy retrieval, RRF fusion, reranking and phase timings),
+//! and `ReflectResponse.trace` lists the agent's tool and LLM calls. Both are
+//! turned into a [`TraceNode`] tree: `memory recall --trace` prints it as an
+//! indented report and the explorer shows it as a collapsible tree.
+
+use hindsight_client::types::ReflectTrace;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// One node of a trace tree: a short label, a dimmed detail and children
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceNode {
+    pub label: String,
+    pub detail: String,
+    pub children: Vec<TraceNode>,
+}
+
+impl TraceNode {
+    pub fn new(label: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self { label: label.into(), detail: detail.into(), children: Vec::new() }
+    }
+
+    fn with_children(mut self, children: Vec<TraceNode>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SearchTrace {
+    query: QueryInfo,
+    retrieval_results: Vec<RetrievalMethod>,
+    rrf_merged: Vec<RrfResult>,
+    reranked: Vec<RerankedResult>,
+    visits: Vec<NodeVisit>,
+    summary: SearchSummary,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct QueryInfo {
+    query_text: String,
+    budget: Option<i64>,
+    max_tokens: Option<i64>,
+    tags: Option<Vec<String>>,
+    tags_match: Option<String>,
+    temporal_constraint: Option<TemporalConstraint>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct TemporalConstraint {
+    start: Option<String>,
+    end: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RetrievalMethod {
+    method_name: String,
+    fact_type: Option<String>,
+    results: Vec<RetrievalResult>,
+    duration_seconds: f64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RetrievalResult {
+    rank: i64,
+    text: String,
+    score: f64,
+    score_name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RrfResult {
+    node_id: String,
+    text: String,
+    rrf_score: f64,
+    source_ranks: BTreeMap<String, i64>,
+    final_rrf_rank: i64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RerankedResult {
+    node_id: String,
+    text: String,
+    rerank_score: f64,
+    rerank_rank: i64,
+    rrf_rank: i64,
+    rank_change: i64,
+    score_components: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct NodeVisit {
+    node_id: String,
+    weights: Option<WeightComponents>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct WeightComponents {
+    activation: f64,
+    semantic_similarity: f64,
+    recency: f64,
+    frequency: f64,
+    final_weight: f64,
+    activation_contribution: f64,
+    semantic_contribution: f64,
+    recency_contribution: f64,
+    frequency_contribution: f64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SearchSummary {
+    total_nodes_visited: i64,
+    total_nodes_pruned: i64,
+    entry_points_found: i64,
+    budget_used: i64,
+    budget_remaining: i64,
+    total_duration_seconds: f64,
+    results_returned: i64,
+    temporal_links_followed: i64,
+    semantic_links_followed: i64,
+    entity_links_followed: i64,
+    phase_metrics: Vec<PhaseMetric>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PhaseMetric {
+    phase_name: String,
+    duration_seconds: f64,
+    details: Map<String, Value>,
+}
+
+fn millis(seconds: f64) -> String {
+    format!("{:.1} ms", seconds * 1000.0)
+}
+
+fn plural(count: usize, word: &str) -> String {
+    format!("{} {}{}", count, word, if count == 1 { "" } else { "s" })
+}
+
+/// Compact single-line form of a JSON value
+fn compact(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Generic tree of a JSON value, used for parts of a trace without a known shape
+pub fn json_tree(label: &str, value: &Value) -> TraceNode {
+    match value {
+        Value::Object(map) => TraceNode::new(label, plural(map.len(), "field"))
+            .with_children(map.iter().map(|(k, v)| json_tree(k, v)).collect()),
+        Value::Array(items) if items.iter().any(|v| v.is_object() || v.is_array()) => {
+            TraceNode::new(label, plural(items.len(), "item"))
+                .with_children(items.iter().enumerate().map(|(i, v)| json_tree(&format!("[{}]", i), v)).collect())
+        }
+        other => TraceNode::new(label, compact(other)),
+    }
+}
+
+/// Build the tree for a recall trace. Traces without the search trace shape
+/// (older servers) are shown as a plain JSON tree.
+pub fn recall_tree(trace: &Map<String, Value>) -> TraceNode {
+    let structured = trace.contains_key("summary") || trace.contains_key("retrieval_results");
+    let parsed = structured
+        .then(|| serde_json::from_value::<SearchTrace>(Value::Object(trace.clone())).ok())
+        .flatten();
+    match parsed {
+        Some(trace) => search_tree(&trace),
+        None => json_tree("Recall trace", &Value::Object(trace.clone())),
+    }
+}
+
+fn search_tree(trace: &SearchTrace) -> TraceNode {
+    let summary = &trace.summary;
+    let mut children = vec![query_node(&trace.query), stages_node(summary)];
+
+    if !trace.retrieval_results.is_empty() {
+        children.push(retrieval_node(&trace.retrieval_results));
+    }
+    if !trace.rrf_merged.is_empty() {
+        let merged = trace
+            .rrf_merged
+            .iter()
+            .map(|r| {
+                TraceNode::new(
+                    format!("#{} rrf {:.4} [{}]", r.final_rrf_rank, r.rrf_score, source_ranks(&r.source_ranks)),
+                    r.text.clone(),
+                )
+            })
+            .collect();
+        children.push(
+            TraceNode::new("Fusion (RRF)", plural(trace.rrf_merged.len(), "candidate")).with_children(merged),
+        );
+    }
+    if !trace.reranked.is_empty() {
+        let reranked = trace
+            .reranked
+            .iter()
+            .map(|r| {
+                TraceNode::new(
+                    format!("#{} score {:.4} (rrf #{}, {})", r.rerank_rank, r.rerank_score, r.rrf_rank, rank_change(r.rank_change)),
+                    r.text.clone(),
+                )
+            })
+            .collect();
+        children.push(TraceNode::new("Reranking", plural(trace.reranked.len(), "candidate")).with_children(reranked));
+    }
+    if summary.total_nodes_visited > 0 || summary.entry_points_found > 0 {
+        children.push(graph_node(summary));
+    }
+    children.push(results_node(trace));
+
+    TraceNode::new(
+        "Recall trace",
+        format!("{} · {}", millis(summary.total_duration_seconds), plural(summary.results_returned as usize, "result")),
+    )
+    .with_children(children)
+}
+
+fn query_node(query: &QueryInfo) -> TraceNode {
+    let mut children = Vec::new();
+    if let Some(budget) = query.budget {
+        children.push(TraceNode::new("budget", budget.to_string()));
+    }
+    if let Some(max_tokens) = query.max_tokens {
+        children.push(TraceNode::new("max tokens", max_tokens.to_string()));
+    }
+    if let Some(tags) = query.tags.as_ref().filter(|t| !t.is_empty()) {
+        let mode = query.tags_match.as_deref().unwrap_or("any");
+        children.push(TraceNode::new("tags", format!("{} ({})", tags.join(", "), mode)));
+    }
+    if let Some(range) = &query.temporal_constraint {
+        children.push(TraceNode::new(
+            "temporal range",
+            format!("{} → {}", range.start.as_deref().unwrap_or("…"), range.end.as_deref().unwrap_or("…")),
+        ));
+    }
+    TraceNode::new("Query", format!("\"{}\"", query.query_text)).with_children(children)
+}
+
+fn stages_node(summary: &SearchSummary) -> TraceNode {
+    let phases = summary
+        .phase_metrics
+        .iter()
+        .map(|phase| {
+            TraceNode::new(phase.phase_name.replace('_', " "), millis(phase.duration_seconds))
+                .with_children(phase.details.iter().map(|(k, v)| json_tree(&k.replace('_', " "), v)).collect())
+        })
+        .collect();
+    TraceNode::new("Stages", millis(summary.total_duration_seconds)).with_children(phases)
+}
+
+fn retrieval_node(methods: &[RetrievalMethod]) -> TraceNode {
+    let candidates: usize = methods.iter().map(|m| m.results.len()).sum();
+    let strategies = methods
+        .iter()
+        .map(|method| {
+            let label = match &method.fact_type {
+                Some(fact_type) => format!("{} ({})", method.method_name, fact_type),
+                None => method.method_name.clone(),
+            };
+            let results = method
+                .results
+                .iter()
+                .map(|r| TraceNode::new(format!("#{} {} {:.4}", r.rank, r.score_name, r.score), r.text.clone()))
+                .collect();
+            TraceNode::new(label, format!("{} · {}", plural(method.results.len(), "candidate"), millis(method.duration_seconds)))
+                .with_children(results)
+        })
+        .collect();
+    TraceNode::new("Retrieval", format!("{} · {}", plural(methods.len(), "strategy run"), plural(candidates, "candidate")))
+        .with_children(strategies)
+}
+
+fn graph_node(summary: &SearchSummary) -> TraceNode {
+    TraceNode::new("Graph", format!("{} visited · {} pruned", summary.total_nodes_visited, summary.total_nodes_pruned))
+        .with_children(vec![
+            TraceNode::new("entry points", summary.entry_points_found.to_string()),
+            TraceNode::new("budget", format!("{} used · {} remaining", summary.budget_used, summary.budget_remaining)),
+            TraceNode::new(
+                "links followed",
+                format!(
+                    "{} semantic · {} temporal · {} entity",
+                    summary.semantic_links_followed, summary.temporal_links_followed, summary.entity_links_followed
+                ),
+            ),
+        ])
+}
+
+/// Returned facts with the contribution of each retrieval strategy and score
+fn results_node(trace: &SearchTrace) -> TraceNode {
+    let rrf: HashMap<&str, &RrfResult> = trace.rrf_merged.iter().map(|r| (r.node_id.as_str(), r)).collect();
+    let visits: HashMap<&str, &WeightComponents> = trace
+        .visits
+        .iter()
+        .filter_map(|v| v.weights.as_ref().map(|w| (v.node_id.as_str(), w)))
+        .collect();
+    let returned = trace.summary.results_returned.max(0) as usize;
+
+    let facts = trace
+        .reranked
+        .iter()
+        .take(returned)
+        .map(|fact| {
+            let mut children = Vec::new();
+            if let Some(merged) = rrf.get(fact.node_id.as_str()) {
+                children.push(TraceNode::new("retrieved by", source_ranks(&merged.source_ranks)));
+                children.push(TraceNode::new("rrf score", format!("{:.4} (#{})", merged.rrf_score, merged.final_rrf_rank)));
+            }
+            children.extend(
+                fact.score_components
+                    .iter()
+                    .map(|(name, value)| TraceNode::new(name.replace('_', " "), format!("{:.4}", value))),
+            );
+            if let Some(w) = visits.get(fact.node_id.as_str()) {
+                children.push(
+                    TraceNode::new("activation", format!("final weight {:.4}", w.final_weight)).with_children(vec![
+                        TraceNode::new("activation", format!("{:.4} → {:.4}", w.activation, w.activation_contribution)),
+                        TraceNode::new("semantic", format!("{:.4} → {:.4}", w.semantic_similarity, w.semantic_contribution)),
+                        TraceNode::new("recency", format!("{:.4} → {:.4}", w.recency, w.recency_contribution)),
+                        TraceNode::new("frequency", format!("{:.4} → {:.4}", w.frequency, w.frequency_contribution)),
+                    ]),
+                );
+            }
+            TraceNode::new(format!("#{} score {:.4}", fact.rerank_rank, fact.rerank_score), fact.text.clone())
+                .with_children(children)
+        })
+        .collect();
+    TraceNode::new("Results", format!("{} returned", returned)).with_children(facts)
+}
+
+fn source_ranks(ranks: &BTreeMap<String, i64>) -> String {
+    ranks.iter().map(|(method, rank)| format!("{} #{}", method, rank)).collect::<Vec<_>>().join(", ")
+}
+
+fn rank_change(change: i64) -> String {
+    match change {
+        0 => "=".to_string(),
+        up if up > 0 => format!("↑{}", up),
+        down => format!("↓{}", -down),
+    }
+}
+
+/// Build the tree for a reflect trace: LLM calls, then tool calls by iteration
+pub fn reflect_tree(trace: &ReflectTrace) -> TraceNode {
+    let llm_ms: i64 = trace.llm_calls.iter().map(|c| c.duration_ms).sum();
+    let tool_ms: i64 = trace.tool_calls.iter().map(|c| c.duration_ms).sum();
+
+    let llm_calls = trace
+        .llm_calls
+        .iter()
+        .map(|call| TraceNode::new(call.scope.clone(), format!("{} ms", call.duration_ms)))
+        .collect();
+
+    let mut iterations: BTreeMap<i64, Vec<TraceNode>> = BTreeMap::new();
+    for call in &trace.tool_calls {
+        let mut children: Vec<TraceNode> = call.input.iter().map(|(k, v)| TraceNode::new(k.clone(), compact(v))).collect();
+        if let Some(output) = &call.output {
+            children.push(json_tree("output", &Value::Object(output.clone())));
+        }
+        iterations
+            .entry(call.iteration)
+            .or_default()
+            .push(TraceNode::new(call.tool.clone(), format!("{} ms", call.duration_ms)).with_children(children));
+    }
+    let tool_calls = iterations
+        .into_iter()
+        .map(|(iteration, calls)| {
+            TraceNode::new(format!("iteration {}", iteration), plural(calls.len(), "call")).with_children(calls)
+        })
+        .collect();
+
+    TraceNode::new(
+        "Reflect trace",
+        format!("{} · {}", plural(trace.llm_calls.len(), "LLM call"), plural(trace.tool_calls.len(), "tool call")),
+    )
+    .with_children(vec![
+        TraceNode::new("LLM calls", format!("{} ms", llm_ms)).with_children(llm_calls),
+        TraceNode::new("Tool calls", format!("{} ms", tool_ms)).with_children(tool_calls),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn child<'a>(node: &'a TraceNode, label: &str) -> &'a TraceNode {
+        node.children
+            .iter()
+            .find(|c| c.label == label)
+            .unwrap_or_else(|| panic!("no child {} under {}", label, node.label))
+    }
+
+    fn search_trace() -> Map<String, Value> {
+        json!({
+            "query": {"query_text": "alice", "budget": 100, "max_tokens": 4096},
+            "retrieval_results": [
+                {"method_name": "semantic", "fact_type": "world", "duration_seconds": 0.012,
+                 "results": [{"rank": 1, "node_id": "a", "text": "Alice works at Google", "score": 0.91, "score_name": "similarity"}]},
+                {"method_name": "bm25", "fact_type": "world", "duration_seconds": 0.004, "results": []}
+            ],
+            "rrf_merged": [{"node_id": "a", "text": "Alice works at Google", "rrf_score": 0.0164,
+                            "source_ranks": {"semantic": 1}, "final_rrf_rank": 1}],
+            "reranked": [{"node_id": "a", "text": "Alice works at Google", "rerank_score": 0.87, "rerank_rank": 1,
+                          "rrf_rank": 1, "rank_change": 0, "score_components": {"cross_encoder_score": 3.2}}],
+            "summary": {"total_duration_seconds": 0.05, "results_returned": 1,
+                        "phase_metrics": [{"phase_name": "rrf_merge", "duration_seconds": 0.001, "details": {"candidates_merged": 1}}]}
+        })
+        .as_object()
+        .unwrap()
+        .clone()
+    }
+
+    #[test]
+    fn test_recall_tree_structure() {
+        let tree = recall_tree(&search_trace());
+        assert_eq!(tree.label, "Recall trace");
+        assert_eq!(tree.detail, "50.0 ms · 1 result");
+        assert_eq!(child(&tree, "Query").detail, "\"alice\"");
+
+        let stages = child(&tree, "Stages");
+        assert_eq!(child(stages, "rrf merge").detail, "1.0 ms");
+        assert_eq!(child(child(stages, "rrf merge"), "candidates merged").detail, "1");
+
+        let retrieval = child(&tree, "Retrieval");
+        assert_eq!(retrieval.detail, "2 strategy runs · 1 candidate");
+        assert_eq!(child(retrieval, "semantic (world)").children[0].label, "#1 similarity 0.9100");
+
+        // No graph visits were recorded
+        assert!(tree.children.iter().all(|c| c.label != "Graph"));
+    }
+
+    #[test]
+    fn test_recall_tree_fact_contributions() {
+        let tree = recall_tree(&search_trace());
+        let fact = &child(&tree, "Results").children[0];
+        assert_eq!(fact.label, "#1 score 0.8700");
+        assert_eq!(child(fact, "retrieved by").detail, "semantic #1");
+        assert_eq!(child(fact, "rrf score").detail, "0.0164 (#1)");
+        assert_eq!(child(fact, "cross encoder score").detail, "3.2000");
+    }
+
+    #[test]
+    fn test_unknown_trace_falls_back_to_json() {
+        let trace = json!({"total_time": 12.5, "activation_count": 3}).as_object().unwrap().clone();
+        let tree = recall_tree(&trace);
+        assert_eq!(tree.detail, "2 fields");
+        assert_eq!(child(&tree, "total_time").detail, "12.5");
+    }
+
+    #[test]
+    fn test_reflect_tree_groups_tool_calls() {
+        let trace: ReflectTrace = serde_json::from_value(json!({
+            "llm_calls": [{"scope": "agent_1", "duration_ms": 1200}, {"scope": "final", "duration_ms": 300}],
+            "tool_calls": [
+                {"tool": "recall", "input": {"query": "AI"}, "duration_ms": 150, "iteration": 1},
+                {"tool": "expand", "input": {"id": "x"}, "duration_ms": 20, "iteration": 2},
+                {"tool": "recall", "input": {"query": "ML"}, "duration_ms": 100, "iteration": 1}
+            ]
+        }))
+        .unwrap();
+        let tree = reflect_tree(&trace);
+        assert_eq!(tree.detail, "2 LLM calls · 3 tool calls");
+        assert_eq!(child(&tree, "LLM calls").detail, "1500 ms");
+
+        let tools = child(&tree, "Tool calls");
+        assert_eq!(tools.detail, "270 ms");
+        let first = child(tools, "iteration 1");
+        assert_eq!(first.detail, "2 calls");
+        assert_eq!(child(&first.children[1], "query").detail, "ML");
+    }
+}
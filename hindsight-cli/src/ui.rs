@@ -1,8 +1,12 @@
 use crate::api::{BankProfileResponse, RecallResult, RecallResponse, ReflectResponse};
+use crate::recall::{self, GroupBy};
+use crate::render;
+use crate::trace::{self, TraceNode};
 use colored::*;
 use hindsight_client::types::ChunkData;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// The logo as ANSI-colored text, generated by test-logo.py
 const LOGO: &str = include_str!("logo.ansi");
@@ -11,6 +15,60 @@ const LOGO: &str = include_str!("logo.ansi");
 const GRADIENT_START: (u8, u8, u8) = (0, 116, 217);  // #0074d9
 const GRADIENT_END: (u8, u8, u8) = (0, 146, 150);    // #009296
 
+/// `--quiet`: no status messages, spinners or progress bars
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// `--no-color`: no colours on either stream
+static NO_COLOR: AtomicBool = AtomicBool::new(false);
+
+/// Set up terminal output for this run.
+///
+/// Results go to stdout; status messages, spinners and progress go to stderr
+/// so machine-readable output stays clean. Colours are off with `--no-color`,
+/// when `NO_COLOR` is set, or when the stream written to is not a terminal.
+pub fn configure(quiet: bool, no_color: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+    NO_COLOR.store(no_color, Ordering::Relaxed);
+    if no_color {
+        colored::control::set_override(false);
+    }
+}
+
+/// Whether stdout output is coloured
+pub fn colors_enabled() -> bool {
+    colored::control::SHOULD_COLORIZE.should_colorize()
+}
+
+/// Whether stderr output is coloured. `colored` only looks at stdout, so
+/// `hindsight ... > out.json` would otherwise lose colour on the terminal.
+fn stderr_colors_enabled() -> bool {
+    let env_set = |name: &str| std::env::var_os(name).map(|v| !v.is_empty() && v != "0").unwrap_or(false);
+    if NO_COLOR.load(Ordering::Relaxed) || env_set("NO_COLOR") {
+        return false;
+    }
+    if env_set("CLICOLOR_FORCE") {
+        return true;
+    }
+    std::env::var_os("CLICOLOR").map(|v| v != "0").unwrap_or(true) && io::stderr().is_terminal()
+}
+
+/// Format text for stderr, colouring it only if stderr is a terminal
+pub fn for_stderr<T>(format: impl FnOnce() -> T) -> T {
+    colored::control::set_override(stderr_colors_enabled());
+    let text = format();
+    if NO_COLOR.load(Ordering::Relaxed) {
+        colored::control::set_override(false);
+    } else {
+        colored::control::unset_override();
+    }
+    text
+}
+
+/// Spinners and progress bars are drawn only on an interactive stderr
+fn progress_enabled() -> bool {
+    !QUIET.load(Ordering::Relaxed) && io::stderr().is_terminal()
+}
+
 /// Interpolate between two RGB colors
 fn interpolate_color(start: (u8, u8, u8), end: (u8, u8, u8), t: f32) -> (u8, u8, u8) {
     (
@@ -22,6 +80,9 @@ fn interpolate_color(start: (u8, u8, u8), end: (u8, u8, u8), t: f32) -> (u8, u8,
 
 /// Color text using gradient position (0.0 = start, 1.0 = end)
 pub fn gradient(text: &str, t: f32) -> String {
+    if !colors_enabled() {
+        return text.to_string();
+    }
     let (r, g, b) = interpolate_color(GRADIENT_START, GRADIENT_END, t);
     format!("\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, text)
 }
@@ -45,8 +106,8 @@ pub fn gradient_mid(text: &str) -> String {
 pub fn gradient_text(text: &str) -> String {
     let chars: Vec<char> = text.chars().collect();
     let len = chars.len();
-    if len == 0 {
-        return String::new();
+    if len == 0 || !colors_enabled() {
+        return text.to_string();
     }
     let mut result = String::new();
     for (i, ch) in chars.iter().enumerate() {
@@ -64,11 +125,14 @@ pub fn gradient_text(text: &str) -> String {
 
 /// Dim/gray text
 pub fn dim(text: &str) -> String {
+    if !colors_enabled() {
+        return text.to_string();
+    }
     format!("\x1b[38;2;128;128;128m{}\x1b[0m", text)
 }
 
 pub fn get_logo() -> &'static str {
-    LOGO
+    if colors_enabled() { LOGO } else { "" }
 }
 
 pub fn print_section_header(title: &str) {
@@ -77,18 +141,21 @@ pub fn print_section_header(title: &str) {
     println!();
 }
 
-pub fn print_fact(fact: &RecallResult, _show_activation: bool) {
-    let fact_type = fact.type_.as_deref().unwrap_or("unknown");
-
-    // Use gradient positions for different fact types
+/// `[WORLD]`-style badge, each fact type at its own gradient position
+fn fact_type_badge(fact_type: &str) -> String {
     let type_t = match fact_type {
         "world" => 0.0,
         "agent" => 0.5,
         "opinion" => 1.0,
         _ => 0.5,
     };
+    gradient(&format!("[{}]", fact_type.to_uppercase()), type_t)
+}
 
-    println!("{}", gradient(&format!("[{}]", fact_type.to_uppercase()), type_t));
+pub fn print_fact(fact: &RecallResult, _show_activation: bool) {
+    let fact_type = fact.type_.as_deref().unwrap_or("unknown");
+
+    println!("{}", fact_type_badge(fact_type));
     println!("  {}", fact.text);
 
     // Show context if available
@@ -161,27 +228,120 @@ pub fn print_search_results(response: &RecallResponse, show_trace: bool, show_ch
 
     if show_trace {
         if let Some(trace) = &response.trace {
-            print_trace_info(trace);
+            print_trace(&trace::recall_tree(trace));
+        }
+    }
+}
+
+/// Characters of source chunk shown under a fact in the grouped view
+const EXCERPT_CHARS: usize = 160;
+
+/// Recall results clustered by document or entity (`--group-by`), with query
+/// terms highlighted, relative times and, with chunks, an excerpt of the source
+pub fn print_grouped_results(query: &str, response: &RecallResponse, by: GroupBy, show_trace: bool, show_chunks: bool) {
+    let results = &response.results;
+    print_section_header(&format!("Search Results ({})", results.len()));
+
+    if results.is_empty() {
+        println!("  {}", dim("No results found."));
+    }
+
+    let terms = recall::query_terms(query);
+    let mark = |word: &str| word.bold().yellow().to_string();
+    let now = chrono::Utc::now();
+    for group in recall::group(results, by) {
+        let title = match (&group.key, by) {
+            (Some(key), _) => key.clone(),
+            (None, GroupBy::Document) => "No document".to_string(),
+            (None, GroupBy::Entity) => "No entity".to_string(),
+        };
+        let count = match group.results.len() {
+            1 => "1 result".to_string(),
+            n => format!("{} results", n),
+        };
+        println!("  {} {}", gradient_start(&title).bold(), dim(&format!("({})", count)));
+
+        for fact in &group.results {
+            let fact_type = fact.type_.as_deref().unwrap_or("unknown");
+            println!("    {} {}", fact_type_badge(fact_type), recall::highlight(&fact.text, &terms, mark));
+
+            // Times, plus whichever of document and entities isn't the group
+            let mut details = Vec::new();
+            if let Some(when) = fact.occurred_start.as_deref().and_then(|t| recall::relative_time(t, now)) {
+                details.push(format!("occurred {}", when));
+            }
+            if let Some(when) = fact.mentioned_at.as_deref().and_then(|t| recall::relative_time(t, now)) {
+                details.push(format!("mentioned {}", when));
+            }
+            match by {
+                GroupBy::Document => match fact.entities.as_deref() {
+                    Some(entities) if !entities.is_empty() => details.push(entities.join(", ")),
+                    _ => {}
+                },
+                GroupBy::Entity => details.extend(fact.document_id.as_ref().map(|id| format!("document {}", id))),
+            }
+            if !details.is_empty() {
+                println!("      {}", dim(&details.join(" · ")));
+            }
+
+            let chunk = fact.chunk_id.as_ref().and_then(|id| response.chunks.as_ref()?.get(id));
+            if let Some(chunk) = chunk.filter(|_| show_chunks) {
+                let excerpt = recall::excerpt(&chunk.text, &terms, EXCERPT_CHARS);
+                println!("      {} {}", dim("│"), recall::highlight(&excerpt, &terms, mark));
+            }
+        }
+        println!();
+    }
+
+    if show_trace {
+        if let Some(trace) = &response.trace {
+            print_trace(&trace::recall_tree(trace));
         }
     }
 }
 
+/// Print a reflect answer rendered from Markdown, then the facts it was based
+/// on, numbered to match the citation markers in the answer
 pub fn print_think_response(response: &ReflectResponse) {
     print_section_header("Reflection");
 
-    println!("{}", response.text);
+    let width = render::terminal_width();
+    let sources = response.based_on.as_ref().map(render::sources).unwrap_or_default();
+    for line in render::render(&response.text, width, &sources) {
+        println!("{}", line);
+    }
     println!();
 
     if let Some(based_on) = &response.based_on {
-        let count = based_on.memories.len();
-        if count > 0 {
-            println!("{}", dim(&format!("Based on {} memory units", count)));
+        if !sources.is_empty() {
+            println!("{}", gradient_text("─── Based on ───"));
+            println!();
+            let mut numbers = sources.iter().map(|source| format!("[{}]", source.number));
+            for memory in &based_on.memories {
+                let mut details: Vec<&str> = memory.type_.iter().map(String::as_str).collect();
+                details.extend(memory.occurred_start.as_deref());
+                let detail = (!details.is_empty()).then(|| format!("({})", details.join(", ")));
+                for line in render::list_item(&numbers.next().unwrap_or_default(), &memory.text, detail.as_deref(), width) {
+                    println!("{}", line);
+                }
+            }
+            for model in &based_on.mental_models {
+                for line in render::list_item(&numbers.next().unwrap_or_default(), &model.text, Some("(mental model)"), width) {
+                    println!("{}", line);
+                }
+            }
+            for directive in &based_on.directives {
+                let text = format!("{}: {}", directive.name, directive.content);
+                for line in render::list_item(&numbers.next().unwrap_or_default(), &text, Some("(directive)"), width) {
+                    println!("{}", line);
+                }
+            }
+            println!();
         }
     }
 
     // Display structured output if present
     if let Some(structured) = &response.structured_output {
-        println!();
         println!("{}", gradient_text("─── Structured Output ───"));
         println!();
         if let Ok(json) = serde_json::to_string_pretty(structured) {
@@ -190,37 +350,57 @@ pub fn print_think_response(response: &ReflectResponse) {
     }
 }
 
-pub fn print_trace_info(trace: &serde_json::Map<String, serde_json::Value>) {
+/// Children shown per node in the printed trace report
+const TRACE_REPORT_CHILDREN: usize = 10;
+
+/// Print a recall or reflect trace as an indented tree
+pub fn print_trace(root: &TraceNode) {
     print_section_header("Trace");
+    println!("  {} {}", gradient_start(&root.label), dim(&root.detail));
+    print_trace_children(&root.children, "  ");
+    println!();
+}
 
-    if let Some(time) = trace.get("total_time").and_then(|v| v.as_f64()) {
-        println!("  {} {}", dim("total time:"), gradient_start(&format!("{:.2}ms", time)));
+fn print_trace_children(children: &[TraceNode], prefix: &str) {
+    let shown = children.len().min(TRACE_REPORT_CHILDREN);
+    let hidden = children.len() - shown;
+    for (i, child) in children.iter().take(shown).enumerate() {
+        let last = i + 1 == shown && hidden == 0;
+        let (branch, indent) = if last { ("└─ ", "   ") } else { ("├─ ", "│  ") };
+        println!("{}{}{} {}", prefix, dim(branch), child.label, dim(&child.detail));
+        print_trace_children(&child.children, &format!("{}{}", prefix, dim(indent)));
     }
-
-    if let Some(count) = trace.get("activation_count").and_then(|v| v.as_i64()) {
-        println!("  {} {}", dim("activation count:"), gradient_end(&count.to_string()));
+    if hidden > 0 {
+        println!("{}{}", prefix, dim(&format!("└─ … {} more", hidden)));
     }
-
-    println!();
 }
 
+/// Status message on stderr, unless `--quiet`
 pub fn print_success(message: &str) {
-    println!("{}", gradient_start(message));
+    if !QUIET.load(Ordering::Relaxed) {
+        eprintln!("{}", for_stderr(|| gradient_start(message)));
+    }
 }
 
+/// Errors are printed even with `--quiet`
 pub fn print_error(message: &str) {
-    eprintln!("{} {}", "error:".bright_red().bold(), message.bright_red());
+    eprintln!("{}", for_stderr(|| format!("{} {}", "error:".bright_red().bold(), message.bright_red())));
 }
 
 pub fn print_warning(message: &str) {
-    println!("{} {}", gradient_end("warning:"), message);
+    if !QUIET.load(Ordering::Relaxed) {
+        eprintln!("{} {}", for_stderr(|| gradient_end("warning:")), message);
+    }
 }
 
 pub fn print_info(message: &str) {
-    println!("{}", gradient_start(message));
+    if !QUIET.load(Ordering::Relaxed) {
+        eprintln!("{}", for_stderr(|| gradient_start(message)));
+    }
 }
 
-/// Animated gradient spinner that shows text with moving gradient colors
+/// Animated gradient spinner that shows text with moving gradient colors.
+/// Drawn on stderr, and not at all when stderr isn't a terminal.
 pub struct GradientSpinner {
     message: String,
     running: std::sync::Arc<std::sync::atomic::AtomicBool>,
@@ -230,6 +410,14 @@ pub struct GradientSpinner {
 impl GradientSpinner {
     pub fn new(message: &str) -> Self {
         let message = message.to_string();
+        if !progress_enabled() {
+            return Self {
+                message,
+                running: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+                handle: None,
+            };
+        }
+        let colors = stderr_colors_enabled();
         let running = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
 
         let msg_clone = message.clone();
@@ -248,8 +436,8 @@ impl GradientSpinner {
                 // Build the gradient string
                 let mut result = String::from("\r");
                 for (i, ch) in chars.iter().enumerate() {
-                    if *ch == ' ' {
-                        result.push(' ');
+                    if *ch == ' ' || !colors {
+                        result.push(*ch);
                     } else {
                         let base_t = if len > 1 { i as f32 / (len - 1) as f32 } else { 0.0 };
                         let t = (base_t + offset) % 1.0;
@@ -257,10 +445,12 @@ impl GradientSpinner {
                         result.push_str(&format!("\x1b[38;2;{};{};{}m{}", r, g, b, ch));
                     }
                 }
-                result.push_str("\x1b[0m");
+                if colors {
+                    result.push_str("\x1b[0m");
+                }
 
-                print!("{}", result);
-                let _ = io::stdout().flush();
+                eprint!("{}", result);
+                let _ = io::stderr().flush();
 
                 std::thread::sleep(std::time::Duration::from_millis(80));
             }
@@ -277,10 +467,10 @@ impl GradientSpinner {
         self.running.store(false, std::sync::atomic::Ordering::Relaxed);
         if let Some(handle) = self.handle.take() {
             let _ = handle.join();
+            // Clear the line
+            eprint!("\r{}\r", " ".repeat(self.message.len() + 10));
+            let _ = io::stderr().flush();
         }
-        // Clear the line
-        print!("\r{}\r", " ".repeat(self.message.len() + 10));
-        let _ = io::stdout().flush();
     }
 }
 
@@ -297,10 +487,14 @@ pub fn create_spinner(message: &str) -> GradientSpinner {
 }
 
 pub fn create_progress_bar(total: u64, message: &str) -> ProgressBar {
+    if !progress_enabled() {
+        return ProgressBar::hidden();
+    }
     let pb = ProgressBar::new(total);
+    let bar = if stderr_colors_enabled() { "{bar:40.cyan/blue}" } else { "{bar:40}" };
     pb.set_style(
         ProgressStyle::default_bar()
-            .template("{msg} [{bar:40.cyan/blue}] {pos}/{len} ({percent}%)")
+            .template(&format!("{{msg}} [{}] {{pos}}/{{len}} ({{percent}}%)", bar))
             .unwrap()
             .progress_chars("█▓▒░ "),
     );
@@ -309,8 +503,8 @@ pub fn create_progress_bar(total: u64, message: &str) -> ProgressBar {
 }
 
 pub fn prompt_confirmation(message: &str) -> io::Result<bool> {
-    print!("{} [y/N]: ", gradient_start(message));
-    io::stdout().flush()?;
+    eprint!("{} [y/N]: ", for_stderr(|| gradient_start(message)));
+    io::stderr().flush()?;
 
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
@@ -482,6 +482,54 @@ fn test_json_yaml_output_formats() {
     }
 }
 
+#[test]
+fn test_delimited_and_ndjson_output_formats() {
+    skip_if_no_server!();
+
+    let output = run_hindsight(&["bank", "list", "-o", "csv"]);
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(stdout.lines().next(), Some("bank_id,name,mission,created_at,updated_at"));
+    }
+
+    let output = run_hindsight(&["tag", "list", "default", "-o", "tsv", "--no-header"]);
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.lines().all(|line| line.split('\t').count() == 2), "Expected two TSV columns, got: {}", stdout);
+    }
+
+    // NDJSON: one JSON object per line
+    let output = run_hindsight(&["bank", "list", "-o", "ndjson"]);
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for line in stdout.lines() {
+            let record: serde_json::Value = serde_json::from_str(line).expect("Expected one JSON record per line");
+            assert!(record.get("bank_id").is_some());
+        }
+    }
+
+    // Commands without a table shape reject CSV before doing anything
+    let output = run_hindsight(&["bank", "stats", "default", "-o", "csv"]);
+    assert!(!output.status.success(), "Expected CSV to be rejected for bank stats");
+}
+
+#[test]
+fn test_query_projection() {
+    skip_if_no_server!();
+
+    let output = run_hindsight(&["bank", "list", "-o", "json", "--query", "[].bank_id", "--raw"]);
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.lines().all(|line| !line.starts_with('"')), "Expected unquoted bank IDs, got: {}", stdout);
+    }
+
+    let output = run_hindsight(&["bank", "list", "-o", "json", "--query", "[?"]);
+    assert!(!output.status.success(), "Expected an invalid query to be rejected");
+
+    let output = run_hindsight(&["bank", "list", "--query", "[0]"]);
+    assert!(!output.status.success(), "Expected --query to require a structured format");
+}
+
 // ============================================================================
 // Directive Tests
 // ============================================================================
@@ -887,3 +935,35 @@ fn test_version_json() {
         assert!(result.get("features").is_some(), "Expected features field");
     }
 }
+
+#[test]
+fn test_version_envelope() {
+    skip_if_no_server!();
+
+    let output = run_hindsight(&["version", "-o", "json", "--envelope"]);
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let result: serde_json::Value = serde_json::from_str(&stdout)
+            .unwrap_or_else(|_| panic!("Expected valid JSON output, got: {}", stdout));
+
+        assert_eq!(result["apiVersion"], "hindsight.cli/v1");
+        assert_eq!(result["kind"], "Version");
+        assert!(result["data"].get("api_version").is_some(), "Expected the version inside data");
+    }
+
+    let output = run_hindsight(&["version", "--envelope"]);
+    assert!(!output.status.success(), "Expected --envelope to require JSON or YAML");
+}
+
+#[test]
+fn test_schema_errors_are_reported() {
+    // schema never contacts the server
+    let output = run_hindsight(&["schema", "nope"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No output schema for 'nope'"));
+
+    let output = run_hindsight(&["schema", "--columns", "nope"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown column 'nope'"));
+}
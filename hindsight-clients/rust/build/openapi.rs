@@ -0,0 +1,478 @@
+//! OpenAPI 3.1 to 3.0.3 conversion, since progenitor only reads 3.0.
+//!
+//! FastAPI emits 3.1 specs whose schemas are JSON Schema 2020-12. Each
+//! construct that 3.0 lacks or spells differently becomes its 3.0 equivalent:
+//!
+//! - `anyOf`/`oneOf` with a `{"type": "null"}` variant: the other variants, plus `nullable: true`
+//! - `type: ["string", "null"]`: `type: "string"`, plus `nullable: true`
+//! - `const: x`: `enum: [x]`
+//! - numeric `exclusiveMinimum`/`exclusiveMaximum`: `minimum`/`maximum` with the boolean flag set
+//! - `$ref` with sibling keywords, which 3.0 ignores: `allOf: [{"$ref": ..}]` next to the siblings
+//! - `examples: [..]` in a schema: `example`, with the first one
+//!
+//! Optional header parameters also lose `nullable`. An absent header and a
+//! null one look the same on the wire, and progenitor generates code that does
+//! not compile for an `Option<Option<&str>>` header.
+//!
+//! `lib.rs` compiles this file into its tests, which is where the unit tests
+//! below run.
+
+use serde_json::{json, Map, Value};
+
+/// Convert a 3.1 spec in place; specs of other versions are left alone
+pub fn convert_31_to_30(spec: &mut Value) {
+    let is_31 = spec
+        .get("openapi")
+        .and_then(Value::as_str)
+        .is_some_and(|v| v.starts_with("3.1"));
+    if !is_31 {
+        return;
+    }
+    spec["openapi"] = json!("3.0.3");
+    convert_node(spec);
+    strip_nullable_optional_headers(spec);
+}
+
+/// Walk the parts of the spec that are not schemas, converting every schema
+/// found below them
+fn convert_node(value: &mut Value) {
+    match value {
+        Value::Object(obj) => {
+            for (key, child) in obj.iter_mut() {
+                match key.as_str() {
+                    "schema" => convert_schema(child),
+                    // components.schemas
+                    "schemas" => schema_map(child).for_each(convert_schema),
+                    // Example payloads are data, not schemas
+                    "example" | "examples" => {}
+                    _ => convert_node(child),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(convert_node),
+        _ => {}
+    }
+}
+
+/// Values of a map from names to schemas, such as `properties`
+fn schema_map(value: &mut Value) -> impl Iterator<Item = &mut Value> {
+    value
+        .as_object_mut()
+        .into_iter()
+        .flat_map(|map| map.values_mut())
+}
+
+/// Convert one schema, then its subschemas
+fn convert_schema(schema: &mut Value) {
+    let Value::Object(obj) = schema else {
+        // `true` and `false` are schemas too, with nothing to convert
+        return;
+    };
+
+    // A variant merged into this schema may bring another union with it
+    while remove_null_variants(obj, "anyOf") || remove_null_variants(obj, "oneOf") {}
+    convert_type(obj);
+    if let Some(value) = obj.remove("const") {
+        obj.entry("enum").or_insert_with(|| json!([value]));
+    }
+    convert_exclusive_bound(obj, "exclusiveMinimum", "minimum", |bound, limit| {
+        bound >= limit
+    });
+    convert_exclusive_bound(obj, "exclusiveMaximum", "maximum", |bound, limit| {
+        bound <= limit
+    });
+    if let Some(Value::Array(examples)) = obj.remove("examples") {
+        if let Some(first) = examples.into_iter().next() {
+            obj.entry("example").or_insert(first);
+        }
+    }
+    wrap_ref_with_siblings(obj);
+
+    for (key, child) in obj.iter_mut() {
+        match key.as_str() {
+            "properties" | "patternProperties" | "$defs" | "definitions" => {
+                schema_map(child).for_each(convert_schema)
+            }
+            "allOf" | "anyOf" | "oneOf" | "prefixItems" => child
+                .as_array_mut()
+                .into_iter()
+                .flatten()
+                .for_each(convert_schema),
+            // `items` may also be a list of schemas (before 2020-12)
+            "items"
+            | "additionalProperties"
+            | "not"
+            | "contains"
+            | "propertyNames"
+            | "if"
+            | "then"
+            | "else" => match child {
+                Value::Array(items) => items.iter_mut().for_each(convert_schema),
+                child => convert_schema(child),
+            },
+            _ => {}
+        }
+    }
+}
+
+/// `{"type": "null"}`, or a schema that only allows `null` some other way
+fn is_null_schema(schema: &Value) -> bool {
+    match schema.get("type") {
+        Some(Value::String(t)) => t == "null",
+        Some(Value::Array(types)) => types.iter().all(|t| t == "null"),
+        _ => {
+            schema.get("const") == Some(&Value::Null) || schema.get("enum") == Some(&json!([null]))
+        }
+    }
+}
+
+/// Drop the null variants of a union at `key` and mark the schema nullable.
+/// A single remaining variant replaces the union. Returns whether anything
+/// changed.
+fn remove_null_variants(obj: &mut Map<String, Value>, key: &str) -> bool {
+    let has_null = obj
+        .get(key)
+        .and_then(Value::as_array)
+        .is_some_and(|variants| variants.iter().any(is_null_schema));
+    if !has_null {
+        return false;
+    }
+    let Some(Value::Array(variants)) = obj.remove(key) else {
+        return false;
+    };
+    let mut rest: Vec<Value> = variants
+        .into_iter()
+        .filter(|v| !is_null_schema(v))
+        .collect();
+    obj.insert("nullable".to_string(), json!(true));
+    match rest.pop() {
+        Some(variant) if rest.is_empty() => match variant {
+            // A reference can't take siblings in 3.0, so it stays wrapped
+            Value::Object(variant) if variant.contains_key("$ref") => {
+                obj.remove("default");
+                obj.insert("allOf".to_string(), json!([variant]));
+            }
+            // The outer schema's title, description and default win
+            Value::Object(variant) => {
+                for (k, v) in variant {
+                    obj.entry(k).or_insert(v);
+                }
+            }
+            variant => {
+                obj.insert(key.to_string(), json!([variant]));
+            }
+        },
+        Some(variant) => {
+            rest.push(variant);
+            obj.insert(key.to_string(), Value::Array(rest));
+        }
+        // Only null allowed
+        None => {
+            obj.insert("enum".to_string(), json!([null]));
+        }
+    }
+    true
+}
+
+/// `type` as a list of types, or `"null"`, which 3.0 spells with `nullable`
+fn convert_type(obj: &mut Map<String, Value>) {
+    let types = match obj.get("type") {
+        Some(Value::Array(types)) => types.clone(),
+        Some(t) if t == "null" => vec![t.clone()],
+        _ => return,
+    };
+    obj.remove("type");
+    if types.iter().any(|t| t == "null") {
+        obj.insert("nullable".to_string(), json!(true));
+    }
+    let mut rest: Vec<Value> = types.into_iter().filter(|t| t != "null").collect();
+    match rest.len() {
+        0 => {
+            obj.insert("enum".to_string(), json!([null]));
+        }
+        1 => {
+            obj.insert("type".to_string(), rest.remove(0));
+        }
+        _ => {
+            let variants: Vec<Value> = rest.into_iter().map(|t| json!({ "type": t })).collect();
+            obj.insert("anyOf".to_string(), Value::Array(variants));
+        }
+    }
+}
+
+/// A numeric `exclusive` bound (3.1) becomes the `inclusive` keyword with
+/// `exclusive: true` (3.0), unless the inclusive bound already there is
+/// stricter. Boolean bounds are already 3.0 and are left alone.
+fn convert_exclusive_bound(
+    obj: &mut Map<String, Value>,
+    exclusive: &str,
+    inclusive: &str,
+    at_least_as_strict: fn(f64, f64) -> bool,
+) {
+    let Some(bound) = obj.get(exclusive).and_then(Value::as_f64) else {
+        return;
+    };
+    let Some(bound_value) = obj.remove(exclusive) else {
+        return;
+    };
+    match obj.get(inclusive).and_then(Value::as_f64) {
+        Some(limit) if !at_least_as_strict(bound, limit) => {}
+        _ => {
+            obj.insert(inclusive.to_string(), bound_value);
+            obj.insert(exclusive.to_string(), json!(true));
+        }
+    }
+}
+
+/// 3.0 ignores keywords next to `$ref`, so move the reference into `allOf`.
+///
+/// A `default` next to a reference is dropped, here and for nullable
+/// references, so the field stays optional and the server applies the default
+/// when it is omitted. progenitor would build the default from each field's
+/// `Default` instead of the spec's value, and send something else.
+fn wrap_ref_with_siblings(obj: &mut Map<String, Value>) {
+    if !obj.contains_key("$ref") {
+        return;
+    }
+    obj.remove("default");
+    if obj.len() < 2 {
+        return;
+    }
+    let Some(reference) = obj.remove("$ref") else {
+        return;
+    };
+    match obj.entry("allOf").or_insert_with(|| json!([])) {
+        Value::Array(all_of) => all_of.insert(0, json!({ "$ref": reference })),
+        _ => unreachable!("allOf is always a list"),
+    }
+}
+
+/// Make optional header parameters plain optional strings rather than nullable ones
+fn strip_nullable_optional_headers(spec: &mut Value) {
+    fn strip(parameter: &mut Value) {
+        let optional_header = parameter.get("in").and_then(Value::as_str) == Some("header")
+            && parameter.get("required").and_then(Value::as_bool) != Some(true);
+        if let Some(schema) = parameter
+            .get_mut("schema")
+            .and_then(Value::as_object_mut)
+            .filter(|_| optional_header)
+        {
+            schema.remove("nullable");
+        }
+    }
+    fn strip_all(parameters: Option<&mut Value>) {
+        parameters
+            .and_then(Value::as_array_mut)
+            .into_iter()
+            .flatten()
+            .for_each(strip);
+    }
+
+    if let Some(paths) = spec.get_mut("paths").and_then(Value::as_object_mut) {
+        for path_item in paths.values_mut() {
+            strip_all(path_item.get_mut("parameters"));
+            for operation in path_item
+                .as_object_mut()
+                .into_iter()
+                .flat_map(|item| item.values_mut())
+            {
+                strip_all(operation.get_mut("parameters"));
+            }
+        }
+    }
+    if let Some(parameters) = spec.pointer_mut("/components/parameters") {
+        schema_map(parameters).for_each(strip);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Convert `schema` as the only schema of a 3.1 spec
+    fn convert(schema: Value) -> Value {
+        let mut spec = json!({ "openapi": "3.1.0", "components": { "schemas": { "S": schema } } });
+        convert_31_to_30(&mut spec);
+        assert_eq!(spec["openapi"], "3.0.3");
+        spec["components"]["schemas"]["S"].take()
+    }
+
+    #[test]
+    fn test_nullable_unions() {
+        assert_eq!(
+            convert(json!({ "anyOf": [{ "type": "string" }, { "type": "null" }], "title": "Q" })),
+            json!({ "type": "string", "nullable": true, "title": "Q" })
+        );
+        assert_eq!(
+            convert(
+                json!({ "oneOf": [{ "$ref": "#/components/schemas/T" }, { "type": "null" }], "default": {} })
+            ),
+            json!({ "allOf": [{ "$ref": "#/components/schemas/T" }], "nullable": true })
+        );
+        assert_eq!(
+            convert(
+                json!({ "anyOf": [{ "type": "integer" }, { "type": "string" }, { "type": "null" }] })
+            ),
+            json!({ "anyOf": [{ "type": "integer" }, { "type": "string" }], "nullable": true })
+        );
+        // Unions without null are left alone
+        let union = json!({ "oneOf": [{ "type": "integer" }, { "type": "string" }] });
+        assert_eq!(convert(union.clone()), union);
+    }
+
+    #[test]
+    fn test_type_lists_and_const() {
+        assert_eq!(
+            convert(json!({ "type": ["integer", "null"], "minimum": 1 })),
+            json!({ "type": "integer", "nullable": true, "minimum": 1 })
+        );
+        assert_eq!(
+            convert(json!({ "type": ["string", "number"] })),
+            json!({ "anyOf": [{ "type": "string" }, { "type": "number" }] })
+        );
+        assert_eq!(
+            convert(json!({ "type": "null" })),
+            json!({ "nullable": true, "enum": [null] })
+        );
+        assert_eq!(
+            convert(json!({ "type": "string", "const": "a" })),
+            json!({ "type": "string", "enum": ["a"] })
+        );
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        assert_eq!(
+            convert(json!({ "type": "number", "exclusiveMinimum": 0, "exclusiveMaximum": 1.5 })),
+            json!({ "type": "number", "minimum": 0, "exclusiveMinimum": true, "maximum": 1.5, "exclusiveMaximum": true })
+        );
+        // A stricter inclusive bound makes the exclusive one redundant
+        assert_eq!(
+            convert(json!({ "type": "integer", "minimum": 5, "exclusiveMinimum": 0 })),
+            json!({ "type": "integer", "minimum": 5 })
+        );
+        // Already 3.0
+        let schema = json!({ "type": "integer", "minimum": 0, "exclusiveMinimum": true });
+        assert_eq!(convert(schema.clone()), schema);
+    }
+
+    #[test]
+    fn test_ref_siblings_and_examples() {
+        assert_eq!(
+            convert(
+                json!({ "$ref": "#/components/schemas/T", "description": "d", "default": "mid" })
+            ),
+            json!({ "allOf": [{ "$ref": "#/components/schemas/T" }], "description": "d" })
+        );
+        assert_eq!(
+            convert(json!({ "$ref": "#/components/schemas/T" })),
+            json!({ "$ref": "#/components/schemas/T" })
+        );
+        assert_eq!(
+            convert(json!({ "type": "string", "examples": ["a", "b"] })),
+            json!({ "type": "string", "example": "a" })
+        );
+    }
+
+    #[test]
+    fn test_subschemas() {
+        let converted = convert(json!({
+            "type": "object",
+            "properties": {
+                // Properties named like keywords are schemas, not keywords
+                "const": { "type": ["string", "null"] },
+                "type": { "const": 1 },
+                "tags": { "type": "array", "items": { "anyOf": [{ "type": "string" }, { "type": "null" }] } },
+            },
+            "additionalProperties": { "type": ["integer", "null"] },
+        }));
+        assert_eq!(
+            converted["properties"]["const"],
+            json!({ "type": "string", "nullable": true })
+        );
+        assert_eq!(converted["properties"]["type"], json!({ "enum": [1] }));
+        assert_eq!(
+            converted["properties"]["tags"]["items"],
+            json!({ "type": "string", "nullable": true })
+        );
+        assert_eq!(
+            converted["additionalProperties"],
+            json!({ "type": "integer", "nullable": true })
+        );
+    }
+
+    #[test]
+    fn test_parameters_and_examples() {
+        let nullable_string = json!({ "anyOf": [{ "type": "string" }, { "type": "null" }] });
+        let mut spec = json!({
+            "openapi": "3.1.0",
+            "paths": { "/banks": { "get": {
+                "parameters": [
+                    { "name": "authorization", "in": "header", "required": false, "schema": nullable_string },
+                    { "name": "x-required", "in": "header", "required": true, "schema": nullable_string },
+                    { "name": "q", "in": "query", "schema": nullable_string },
+                ],
+                "responses": { "200": { "content": { "application/json": {
+                    "schema": { "type": ["object", "null"] },
+                    "example": { "type": ["not", "a", "schema"] },
+                } } } },
+            } } },
+        });
+        convert_31_to_30(&mut spec);
+        let operation = &spec["paths"]["/banks"]["get"];
+        assert_eq!(
+            operation["parameters"][0]["schema"],
+            json!({ "type": "string" })
+        );
+        assert_eq!(
+            operation["parameters"][1]["schema"],
+            json!({ "type": "string", "nullable": true })
+        );
+        assert_eq!(
+            operation["parameters"][2]["schema"],
+            json!({ "type": "string", "nullable": true })
+        );
+        let media = &operation["responses"]["200"]["content"]["application/json"];
+        assert_eq!(
+            media["schema"],
+            json!({ "type": "object", "nullable": true })
+        );
+        assert_eq!(media["example"], json!({ "type": ["not", "a", "schema"] }));
+    }
+
+    /// Whether any schema-like object below `value` still uses a 3.1 construct
+    fn find_31_construct(value: &Value) -> Option<String> {
+        match value {
+            Value::Object(obj) => {
+                if obj.get("type").is_some_and(|t| t.is_array() || t == "null") {
+                    return Some(format!("type {}", obj["type"]));
+                }
+                if obj.contains_key("const")
+                    || obj.get("exclusiveMinimum").is_some_and(Value::is_number)
+                {
+                    return Some(format!("{:?}", obj.keys().collect::<Vec<_>>()));
+                }
+                if obj.contains_key("$ref") && obj.len() > 1 {
+                    return Some(format!(
+                        "$ref with siblings {:?}",
+                        obj.keys().collect::<Vec<_>>()
+                    ));
+                }
+                obj.values().find_map(find_31_construct)
+            }
+            Value::Array(items) => items.iter().find_map(find_31_construct),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_vendored_spec_converts() {
+        let mut spec: Value = serde_json::from_str(include_str!("../openapi.json")).unwrap();
+        convert_31_to_30(&mut spec);
+        assert_eq!(find_31_construct(&spec), None);
+        // Leaves 3.0 specs alone
+        let converted = spec.clone();
+        convert_31_to_30(&mut spec);
+        assert_eq!(spec, converted);
+    }
+}
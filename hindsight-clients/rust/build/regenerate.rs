@@ -0,0 +1,144 @@
+//! Generates `src/generated.rs` with progenitor; only built with the
+//! `regenerate` feature.
+
+use crate::openapi::convert_31_to_30;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Remove paths with multipart/form-data content type (not supported by progenitor)
+fn filter_multipart_endpoints(spec: &mut serde_json::Value) {
+    if let Some(paths) = spec.get_mut("paths").and_then(|v| v.as_object_mut()) {
+        let mut paths_to_remove = Vec::new();
+
+        for (path_name, path_item) in paths.iter() {
+            if let Some(operations) = path_item.as_object() {
+                for (_method, operation) in operations.iter() {
+                    if let Some(request_body) = operation.get("requestBody") {
+                        if let Some(content) = request_body.get("content") {
+                            if let Some(content_obj) = content.as_object() {
+                                if content_obj.contains_key("multipart/form-data") {
+                                    eprintln!("Filtering out endpoint with multipart/form-data: {}", path_name);
+                                    paths_to_remove.push(path_name.clone());
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Remove the paths
+        for path in paths_to_remove {
+            paths.remove(&path);
+        }
+    }
+}
+
+/// 64-bit FNV-1a of the spec, ignoring `\r` so checkouts with CRLF line
+/// endings hash the same. The drift test in `lib.rs` uses the same function.
+fn spec_hash(spec: &str) -> u64 {
+    spec.bytes().filter(|&b| b != b'\r').fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Refresh `openapi.json` from the docs copy (inside the monorepo), then
+/// regenerate `src/generated.rs` from it
+pub fn regenerate() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let vendored_path = manifest_dir.join("openapi.json");
+    // hindsight-docs/static is the single source of truth for the spec
+    let docs_path = manifest_dir.join("../../hindsight-docs/static/openapi.json");
+
+    if docs_path.exists() {
+        println!("cargo:rerun-if-changed={}", docs_path.display());
+        let docs_spec = fs::read_to_string(&docs_path).expect("Failed to read the docs openapi.json");
+        if fs::read_to_string(&vendored_path).ok().as_deref() != Some(docs_spec.as_str()) {
+            fs::write(&vendored_path, &docs_spec).expect("Failed to update the vendored openapi.json");
+        }
+    }
+    println!("cargo:rerun-if-changed={}", vendored_path.display());
+
+    // Read the OpenAPI spec
+    let spec_content = fs::read_to_string(&vendored_path)
+        .expect("Failed to read openapi.json. Make sure it exists next to Cargo.toml.");
+
+    // Parse as generic JSON first to convert 3.1 to 3.0
+    let mut spec_json: serde_json::Value = serde_json::from_str(&spec_content)
+        .expect("Failed to parse openapi.json");
+
+    // Convert OpenAPI 3.1.0 to 3.0.3 for progenitor compatibility
+    convert_31_to_30(&mut spec_json);
+
+    // Filter out multipart/form-data endpoints (progenitor doesn't support them)
+    filter_multipart_endpoints(&mut spec_json);
+
+    // Now parse as OpenAPI struct
+    let spec: openapiv3::OpenAPI = serde_json::from_value(spec_json)
+        .expect("Failed to parse converted OpenAPI spec");
+
+    // Derive JsonSchema on every type; the derives are put behind the
+    // `schemars` feature below
+    let mut settings = progenitor::GenerationSettings::default();
+    settings.with_derive("schemars::JsonSchema");
+    let mut generator = progenitor::Generator::new(&settings);
+
+    // Generate code
+    let tokens = generator.generate_tokens(&spec)
+        .expect("Failed to generate client code from OpenAPI spec");
+
+    let mut syntax_tree: syn::File = syn::parse2(tokens)
+        .expect("Failed to parse generated tokens");
+    gate_schemars_derives(&mut syntax_tree.items);
+    let formatted = prettyplease::unparse(&syntax_tree);
+
+    let generated = format!(
+        "// @generated from openapi.json by `cargo build --features regenerate`; do not edit.\n\
+         // spec-hash: {:016x}\n\n{}",
+        spec_hash(&spec_content),
+        formatted
+    );
+
+    let dest_path = manifest_dir.join("src").join("generated.rs");
+    if fs::read_to_string(&dest_path).ok().as_deref() != Some(generated.as_str()) {
+        fs::write(&dest_path, generated).expect("Failed to write generated client code");
+        println!("cargo:warning=Regenerated {}", dest_path.display());
+    }
+}
+
+/// Move `schemars::JsonSchema` out of each `#[derive(..)]` into a
+/// `#[cfg_attr(feature = "schemars", derive(..))]` next to it, so the
+/// checked-in code builds with and without the feature
+fn gate_schemars_derives(items: &mut [syn::Item]) {
+    use syn::punctuated::Punctuated;
+
+    for item in items {
+        let attrs = match item {
+            syn::Item::Struct(item) => &mut item.attrs,
+            syn::Item::Enum(item) => &mut item.attrs,
+            syn::Item::Mod(item) => {
+                if let Some((_, items)) = &mut item.content {
+                    gate_schemars_derives(items);
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        let Some(index) = attrs.iter().position(|attr| attr.path().is_ident("derive")) else {
+            continue;
+        };
+        let derives = attrs[index]
+            .parse_args_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+            .expect("Failed to parse derive list");
+        let is_json_schema = |path: &syn::Path| path.segments.last().is_some_and(|s| s.ident == "JsonSchema");
+        if !derives.iter().any(is_json_schema) {
+            continue;
+        }
+        let kept: Punctuated<syn::Path, syn::Token![,]> =
+            derives.into_iter().filter(|path| !is_json_schema(path)).collect();
+        attrs[index] = syn::parse_quote!(#[derive(#kept)]);
+        attrs.insert(index + 1, syn::parse_quote!(#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]));
+    }
+}
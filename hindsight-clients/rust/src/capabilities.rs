@@ -0,0 +1,443 @@
+//! Server version negotiation and feature detection.
+//!
+//! The client is generated from the spec it was built with, but the server it
+//! talks to may be older or newer, or have optional APIs turned off.
+//! [`Client::capabilities`] asks `/version` once per base URL and caches the
+//! answer (a failed lookup is retried after [`VERSION_RETRY`]), and
+//! [`Client::guard`] turns a call to an API the server does not
+//! offer into an [`Unsupported`] error instead of a bare 404.
+
+use crate::{types, Client, ClientInfo, Error, ResponseValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// How long guarded calls skip `/version` after it could not be fetched
+pub const VERSION_RETRY: Duration = Duration::from_secs(30);
+
+/// An API that a server may not offer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// File upload and conversion (`POST /files/retain`)
+    FileRetain,
+    /// Per-bank configuration overrides
+    BankConfig,
+    /// Mental models, added in API 0.4.0
+    MentalModels,
+    /// Directives, added in API 0.4.0
+    Directives,
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Capability::FileRetain => "file retain API",
+            Capability::BankConfig => "bank config API",
+            Capability::MentalModels => "mental models API",
+            Capability::Directives => "directives API",
+        })
+    }
+}
+
+/// `major.minor.patch`; a missing patch is 0 and pre-release suffixes are ignored
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches('v');
+    let mut parts = version.split(['.', '-', '+']).map(|p| p.parse::<u64>());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) => Some((major, minor, patch)),
+        (Some(Ok(major)), Some(Ok(minor)), None) => Some((major, minor, 0)),
+        _ => None,
+    }
+}
+
+/// How a server's API version relates to the spec a client was generated from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compatibility {
+    /// Same major and minor version; only patch releases differ
+    Exact,
+    /// Semver-compatible, but one side has endpoints the other lacks
+    Compatible,
+    /// A breaking release apart (before 1.0, any minor release is breaking)
+    Incompatible,
+    /// One of the versions is not `major.minor[.patch]`
+    Unknown,
+}
+
+/// Compare `server` against the `client` spec version
+pub fn compatibility(client: &str, server: &str) -> Compatibility {
+    let (Some(ours), Some(theirs)) = (parse_version(client), parse_version(server)) else {
+        return Compatibility::Unknown;
+    };
+    let breaking = |v: (u64, u64, u64)| if v.0 == 0 { (0, v.1) } else { (v.0, 0) };
+    if breaking(ours) != breaking(theirs) {
+        Compatibility::Incompatible
+    } else if (ours.0, ours.1) != (theirs.0, theirs.1) {
+        Compatibility::Compatible
+    } else {
+        Compatibility::Exact
+    }
+}
+
+/// What a server reported about itself on `/version`
+#[derive(Debug, Clone)]
+pub struct Capabilities {
+    pub api_version: String,
+    pub features: types::FeaturesInfo,
+}
+
+impl From<types::VersionResponse> for Capabilities {
+    fn from(version: types::VersionResponse) -> Self {
+        Capabilities {
+            api_version: version.api_version,
+            features: version.features,
+        }
+    }
+}
+
+impl Capabilities {
+    /// Why `capability` is not available on this server, or `None` if it is
+    pub fn unsupported(&self, capability: Capability) -> Option<Unsupported> {
+        let reason = match capability {
+            Capability::FileRetain if !self.features.file_upload_api => {
+                "disabled on the server; set HINDSIGHT_API_ENABLE_FILE_UPLOAD_API=true to enable it"
+                    .to_string()
+            }
+            Capability::BankConfig if !self.features.bank_config_api => {
+                "disabled on the server; set HINDSIGHT_API_ENABLE_BANK_CONFIG_API=true to enable it"
+                    .to_string()
+            }
+            Capability::MentalModels | Capability::Directives
+                if parse_version(&self.api_version).is_some_and(|v| v < (0, 4, 0)) =>
+            {
+                "added in API 0.4.0; upgrade the server".to_string()
+            }
+            _ => return None,
+        };
+        Some(Unsupported {
+            capability,
+            api_version: self.api_version.clone(),
+            reason,
+        })
+    }
+
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.unsupported(capability).is_none()
+    }
+
+    /// How the server's version relates to [`Client::spec_version`]
+    pub fn compatibility(&self) -> Compatibility {
+        compatibility(Client::spec_version(), &self.api_version)
+    }
+}
+
+/// A call to an API the server does not offer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unsupported {
+    pub capability: Capability,
+    pub api_version: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the {} is not available on this server (API {}): {}",
+            self.capability, self.api_version, self.reason
+        )
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
+/// Error of a [`Client::guard`]ed call
+pub enum CallError<E = types::HttpValidationError> {
+    Unsupported(Unsupported),
+    Api(Error<E>),
+}
+
+impl<E> From<Unsupported> for CallError<E> {
+    fn from(unsupported: Unsupported) -> Self {
+        CallError::Unsupported(unsupported)
+    }
+}
+
+impl<E> fmt::Display for CallError<E>
+where
+    Error<E>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallError::Unsupported(unsupported) => unsupported.fmt(f),
+            CallError::Api(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<E> fmt::Debug for CallError<E>
+where
+    Error<E>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallError::Unsupported(unsupported) => {
+                f.debug_tuple("Unsupported").field(unsupported).finish()
+            }
+            CallError::Api(error) => f.debug_tuple("Api").field(error).finish(),
+        }
+    }
+}
+
+impl<E> std::error::Error for CallError<E>
+where
+    Error<E>: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CallError::Unsupported(_) => None,
+            CallError::Api(error) => error.source(),
+        }
+    }
+}
+
+/// What is known about the server at one base URL
+#[derive(Clone)]
+enum Cached {
+    Known(Capabilities),
+    /// `/version` could not be fetched at this time
+    Unavailable(Instant),
+}
+
+/// Capabilities by base URL, shared by every client in the process
+fn cache() -> &'static Mutex<HashMap<String, Cached>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Cached>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+impl Client {
+    /// Version of the OpenAPI spec this client was generated from
+    pub fn spec_version() -> &'static str {
+        <Client as ClientInfo<()>>::api_version()
+    }
+
+    /// The server's version and features, fetched from `/version` on first use
+    /// and cached for every client with the same base URL
+    pub async fn capabilities(&self) -> Result<Capabilities, Error<()>> {
+        let cached = cache().lock().unwrap().get(self.baseurl()).cloned();
+        match cached {
+            Some(Cached::Known(capabilities)) => Ok(capabilities),
+            _ => self.refresh_capabilities().await,
+        }
+    }
+
+    /// Fetch `/version` again, e.g. after the server was reconfigured
+    pub async fn refresh_capabilities(&self) -> Result<Capabilities, Error<()>> {
+        let capabilities = Capabilities::from(self.get_version().await?.into_inner());
+        cache()
+            .lock()
+            .unwrap()
+            .insert(self.baseurl().to_string(), Cached::Known(capabilities.clone()));
+        Ok(capabilities)
+    }
+
+    /// Fail if the server is known not to offer `capability`. A server whose
+    /// version cannot be fetched gets the benefit of the doubt, and is not
+    /// asked again for [`VERSION_RETRY`].
+    pub async fn require(&self, capability: Capability) -> Result<(), Unsupported> {
+        let cached = cache().lock().unwrap().get(self.baseurl()).cloned();
+        if let Some(Cached::Unavailable(at)) = cached {
+            if at.elapsed() < VERSION_RETRY {
+                return Ok(());
+            }
+        }
+        match self.capabilities().await {
+            Ok(capabilities) => capabilities.unsupported(capability).map_or(Ok(()), Err),
+            Err(_) => {
+                cache()
+                    .lock()
+                    .unwrap()
+                    .insert(self.baseurl().to_string(), Cached::Unavailable(Instant::now()));
+                Ok(())
+            }
+        }
+    }
+
+    /// Run `call`, an API method of `capability`, only if the server offers it.
+    ///
+    /// A 404 from the call re-checks `/version` in case the server changed
+    /// since it was cached, and becomes [`CallError::Unsupported`] if the API
+    /// is now off; otherwise (e.g. an unknown bank) the 404 is returned as is.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: &hindsight_client::Client) {
+    /// use hindsight_client::{CallError, Capability};
+    ///
+    /// match client.guard(Capability::BankConfig, client.get_bank_config("my-bank", None)).await {
+    ///     Ok(config) => println!("{:?}", config.into_inner()),
+    ///     Err(CallError::Unsupported(e)) => eprintln!("{}", e),
+    ///     Err(CallError::Api(e)) => eprintln!("request failed: {}", e),
+    /// }
+    /// # }
+    /// ```
+    pub async fn guard<T, E, F>(
+        &self,
+        capability: Capability,
+        call: F,
+    ) -> Result<ResponseValue<T>, CallError<E>>
+    where
+        F: Future<Output = Result<ResponseValue<T>, Error<E>>>,
+    {
+        self.require(capability).await?;
+        match call.await {
+            Err(error) if error.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+                if let Ok(capabilities) = self.refresh_capabilities().await {
+                    if let Some(unsupported) = capabilities.unsupported(capability) {
+                        return Err(unsupported.into());
+                    }
+                }
+                Err(CallError::Api(error))
+            }
+            result => result.map_err(CallError::Api),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    fn capabilities(api_version: &str, bank_config_api: bool) -> Capabilities {
+        Capabilities {
+            api_version: api_version.to_string(),
+            features: types::FeaturesInfo {
+                bank_config_api,
+                file_upload_api: true,
+                mcp: false,
+                observations: true,
+                worker: true,
+            },
+        }
+    }
+
+    #[test]
+    fn test_version_compatibility() {
+        assert_eq!(parse_version("v0.4.13"), Some((0, 4, 13)));
+        assert_eq!(parse_version("1.2.0-rc.1"), Some((1, 2, 0)));
+        assert_eq!(parse_version("1.2"), Some((1, 2, 0)));
+        assert_eq!(parse_version("dev"), None);
+
+        assert_eq!(compatibility("0.4.13", "0.4.2"), Compatibility::Exact);
+        assert_eq!(
+            compatibility("0.4.13", "0.5.0"),
+            Compatibility::Incompatible
+        );
+        assert_eq!(compatibility("1.2.0", "1.4.1"), Compatibility::Compatible);
+        assert_eq!(compatibility("2.0.0", "1.9.0"), Compatibility::Incompatible);
+        assert_eq!(compatibility("1.0.0", "unknown"), Compatibility::Unknown);
+    }
+
+    #[test]
+    fn test_unsupported_capabilities() {
+        let old = capabilities("0.3.2", false);
+        assert!(old.supports(Capability::FileRetain));
+        let unsupported = old.unsupported(Capability::BankConfig).unwrap();
+        assert_eq!(
+            unsupported.to_string(),
+            "the bank config API is not available on this server (API 0.3.2): \
+             disabled on the server; set HINDSIGHT_API_ENABLE_BANK_CONFIG_API=true to enable it"
+        );
+        assert!(!old.supports(Capability::MentalModels));
+        assert!(capabilities("0.4.0", true).supports(Capability::MentalModels));
+        assert!(capabilities("dev", true).supports(Capability::Directives));
+    }
+
+    /// Serve `/version` with the bank config API off, and 404 everything else
+    fn serve_once_per_request(requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(&stream);
+                reader.read_line(&mut request_line).unwrap();
+                while reader.read_line(&mut String::new()).unwrap() > 2 {}
+                let (status, body) = if request_line.starts_with("GET /version ") {
+                    (
+                        "200 OK",
+                        r#"{"api_version":"0.4.13","features":{"bank_config_api":false,"file_upload_api":true,"mcp":true,"observations":true,"worker":true}}"#,
+                    )
+                } else {
+                    ("404 Not Found", r#"{"detail":"Not Found"}"#)
+                };
+                let response = format!(
+                    "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_guard_reports_unsupported() {
+        // One request for /version; the guarded call itself is never sent
+        let client = Client::new(&serve_once_per_request(1));
+        let capabilities = client.capabilities().await.unwrap();
+        assert_eq!(
+            capabilities.compatibility(),
+            compatibility(Client::spec_version(), "0.4.13")
+        );
+
+        match client
+            .guard(Capability::BankConfig, client.get_bank_config("b1", None))
+            .await
+        {
+            Err(CallError::Unsupported(e)) => assert_eq!(e.capability, Capability::BankConfig),
+            other => panic!(
+                "expected Unsupported, got {:?}",
+                other.map(|r| r.into_inner())
+            ),
+        }
+        // Cached: a second client for the same server does not ask again
+        let again = Client::new(client.baseurl());
+        assert!(again.require(Capability::FileRetain).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_failed_version_lookup_is_cached() {
+        // A server without /version, counting the requests it gets
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                while reader.read_line(&mut String::new()).unwrap() > 2 {}
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let body = r#"{"detail":"Not Found"}"#;
+                let response = format!(
+                    "HTTP/1.1 404 Not Found\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let client = Client::new(&url);
+        assert!(client.require(Capability::BankConfig).await.is_ok());
+        assert!(client.require(Capability::MentalModels).await.is_ok());
+        assert!(Client::new(&url).require(Capability::Directives).await.is_ok());
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+}
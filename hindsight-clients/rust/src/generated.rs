@@ -0,0 +1,8214 @@
+// @generated from openapi.json by `cargo build --features regenerate`; do not edit.
+// spec-hash: f54ae83c078f5afd
+
+#[allow(unused_imports)]
+pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+#[allow(unused_imports)]
+use progenitor_client::{encode_path, ClientHooks, OperationInfo, RequestBuilderExt};
+/// Types used as operation parameters and responses.
+#[allow(clippy::all)]
+pub mod types {
+    /// Error types.
+    pub mod error {
+        /// Error from a `TryFrom` or `FromStr` implementation.
+        pub struct ConversionError(::std::borrow::Cow<'static, str>);
+        impl ::std::error::Error for ConversionError {}
+        impl ::std::fmt::Display for ConversionError {
+            fn fmt(
+                &self,
+                f: &mut ::std::fmt::Formatter<'_>,
+            ) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+        impl ::std::fmt::Debug for ConversionError {
+            fn fmt(
+                &self,
+                f: &mut ::std::fmt::Formatter<'_>,
+            ) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+        impl From<&'static str> for ConversionError {
+            fn from(value: &'static str) -> Self {
+                Self(value.into())
+            }
+        }
+        impl From<String> for ConversionError {
+            fn from(value: String) -> Self {
+                Self(value.into())
+            }
+        }
+    }
+    ///Request model for adding/merging background information. Deprecated: use SetMissionRequest instead.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "AddBackgroundRequest",
+    ///  "description": "Request model for adding/merging background information. Deprecated: use SetMissionRequest instead.",
+    ///  "examples": [
+    ///    {
+    ///      "content": "I was born in Texas",
+    ///      "update_disposition": true
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "content"
+    ///  ],
+    ///  "properties": {
+    ///    "content": {
+    ///      "title": "Content",
+    ///      "description": "New background information to add or merge",
+    ///      "type": "string"
+    ///    },
+    ///    "update_disposition": {
+    ///      "title": "Update Disposition",
+    ///      "description": "Deprecated - disposition is no longer auto-inferred from mission",
+    ///      "default": true,
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct AddBackgroundRequest {
+        ///New background information to add or merge
+        pub content: ::std::string::String,
+        ///Deprecated - disposition is no longer auto-inferred from mission
+        #[serde(default = "defaults::default_bool::<true>")]
+        pub update_disposition: bool,
+    }
+    impl ::std::convert::From<&AddBackgroundRequest> for AddBackgroundRequest {
+        fn from(value: &AddBackgroundRequest) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for submitting an async operation.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "AsyncOperationSubmitResponse",
+    ///  "description": "Response model for submitting an async operation.",
+    ///  "examples": [
+    ///    {
+    ///      "operation_id": "550e8400-e29b-41d4-a716-446655440000",
+    ///      "status": "queued"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "operation_id",
+    ///    "status"
+    ///  ],
+    ///  "properties": {
+    ///    "operation_id": {
+    ///      "title": "Operation Id",
+    ///      "type": "string"
+    ///    },
+    ///    "status": {
+    ///      "title": "Status",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct AsyncOperationSubmitResponse {
+        pub operation_id: ::std::string::String,
+        pub status: ::std::string::String,
+    }
+    impl ::std::convert::From<&AsyncOperationSubmitResponse>
+    for AsyncOperationSubmitResponse {
+        fn from(value: &AsyncOperationSubmitResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for background update. Deprecated: use MissionResponse instead.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "BackgroundResponse",
+    ///  "description": "Response model for background update. Deprecated: use MissionResponse instead.",
+    ///  "examples": [
+    ///    {
+    ///      "mission": "I was born in Texas. I am a software engineer with 10 years of experience."
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "mission"
+    ///  ],
+    ///  "properties": {
+    ///    "background": {
+    ///      "title": "Background",
+    ///      "description": "Deprecated: same as mission",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "disposition": {
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/DispositionTraits"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    },
+    ///    "mission": {
+    ///      "title": "Mission",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BackgroundResponse {
+        ///Deprecated: same as mission
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub background: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub disposition: ::std::option::Option<DispositionTraits>,
+        pub mission: ::std::string::String,
+    }
+    impl ::std::convert::From<&BackgroundResponse> for BackgroundResponse {
+        fn from(value: &BackgroundResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for bank configuration.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "BankConfigResponse",
+    ///  "description": "Response model for bank configuration.",
+    ///  "examples": [
+    ///    {
+    ///      "bank_id": "my-bank",
+    ///      "config": {
+    ///        "llm_model": "gpt-4",
+    ///        "llm_provider": "openai",
+    ///        "retain_extraction_mode": "verbose"
+    ///      },
+    ///      "overrides": {
+    ///        "llm_model": "gpt-4",
+    ///        "retain_extraction_mode": "verbose"
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "config",
+    ///    "overrides"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "description": "Bank identifier",
+    ///      "type": "string"
+    ///    },
+    ///    "config": {
+    ///      "title": "Config",
+    ///      "description": "Fully resolved configuration with all hierarchical overrides applied (Python field names)",
+    ///      "type": "object",
+    ///      "additionalProperties": true
+    ///    },
+    ///    "overrides": {
+    ///      "title": "Overrides",
+    ///      "description": "Bank-specific configuration overrides only (Python field names)",
+    ///      "type": "object",
+    ///      "additionalProperties": true
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BankConfigResponse {
+        ///Bank identifier
+        pub bank_id: ::std::string::String,
+        ///Fully resolved configuration with all hierarchical overrides applied (Python field names)
+        pub config: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        ///Bank-specific configuration overrides only (Python field names)
+        pub overrides: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    }
+    impl ::std::convert::From<&BankConfigResponse> for BankConfigResponse {
+        fn from(value: &BankConfigResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Request model for updating bank configuration.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "BankConfigUpdate",
+    ///  "description": "Request model for updating bank configuration.",
+    ///  "examples": [
+    ///    {
+    ///      "updates": {
+    ///        "llm_model": "claude-sonnet-4-5",
+    ///        "retain_custom_instructions": "Extract technical details carefully",
+    ///        "retain_extraction_mode": "verbose"
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "updates"
+    ///  ],
+    ///  "properties": {
+    ///    "updates": {
+    ///      "title": "Updates",
+    ///      "description": "Configuration overrides. Keys can be in Python field format (llm_provider) or environment variable format (HINDSIGHT_API_LLM_PROVIDER). Only hierarchical fields can be overridden per-bank.",
+    ///      "type": "object",
+    ///      "additionalProperties": true
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BankConfigUpdate {
+        ///Configuration overrides. Keys can be in Python field format (llm_provider) or environment variable format (HINDSIGHT_API_LLM_PROVIDER). Only hierarchical fields can be overridden per-bank.
+        pub updates: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    }
+    impl ::std::convert::From<&BankConfigUpdate> for BankConfigUpdate {
+        fn from(value: &BankConfigUpdate) -> Self {
+            value.clone()
+        }
+    }
+    ///Bank list item with profile summary.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "BankListItem",
+    ///  "description": "Bank list item with profile summary.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "disposition"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "created_at": {
+    ///      "title": "Created At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "disposition": {
+    ///      "$ref": "#/components/schemas/DispositionTraits"
+    ///    },
+    ///    "mission": {
+    ///      "title": "Mission",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "updated_at": {
+    ///      "title": "Updated At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BankListItem {
+        pub bank_id: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub created_at: ::std::option::Option<::std::string::String>,
+        pub disposition: DispositionTraits,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub mission: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub name: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub updated_at: ::std::option::Option<::std::string::String>,
+    }
+    impl ::std::convert::From<&BankListItem> for BankListItem {
+        fn from(value: &BankListItem) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for listing all banks.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "BankListResponse",
+    ///  "description": "Response model for listing all banks.",
+    ///  "examples": [
+    ///    {
+    ///      "banks": [
+    ///        {
+    ///          "bank_id": "user123",
+    ///          "created_at": "2024-01-15T10:30:00Z",
+    ///          "disposition": {
+    ///            "empathy": 3,
+    ///            "literalism": 3,
+    ///            "skepticism": 3
+    ///          },
+    ///          "mission": "I am a software engineer helping my team ship quality code",
+    ///          "name": "Alice",
+    ///          "updated_at": "2024-01-16T14:20:00Z"
+    ///        }
+    ///      ]
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "banks"
+    ///  ],
+    ///  "properties": {
+    ///    "banks": {
+    ///      "title": "Banks",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/BankListItem"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BankListResponse {
+        pub banks: ::std::vec::Vec<BankListItem>,
+    }
+    impl ::std::convert::From<&BankListResponse> for BankListResponse {
+        fn from(value: &BankListResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for bank profile.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "BankProfileResponse",
+    ///  "description": "Response model for bank profile.",
+    ///  "examples": [
+    ///    {
+    ///      "bank_id": "user123",
+    ///      "disposition": {
+    ///        "empathy": 3,
+    ///        "literalism": 3,
+    ///        "skepticism": 3
+    ///      },
+    ///      "mission": "I am a software engineer helping my team stay organized and ship quality code",
+    ///      "name": "Alice"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "disposition",
+    ///    "mission",
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "background": {
+    ///      "title": "Background",
+    ///      "description": "Deprecated: use mission instead",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "disposition": {
+    ///      "$ref": "#/components/schemas/DispositionTraits"
+    ///    },
+    ///    "mission": {
+    ///      "title": "Mission",
+    ///      "description": "The agent's mission - who they are and what they're trying to accomplish",
+    ///      "type": "string"
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BankProfileResponse {
+        ///Deprecated: use mission instead
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub background: ::std::option::Option<::std::string::String>,
+        pub bank_id: ::std::string::String,
+        pub disposition: DispositionTraits,
+        ///The agent's mission - who they are and what they're trying to accomplish
+        pub mission: ::std::string::String,
+        pub name: ::std::string::String,
+    }
+    impl ::std::convert::From<&BankProfileResponse> for BankProfileResponse {
+        fn from(value: &BankProfileResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for bank statistics endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "BankStatsResponse",
+    ///  "description": "Response model for bank statistics endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "bank_id": "user123",
+    ///      "failed_operations": 0,
+    ///      "last_consolidated_at": "2024-01-15T10:30:00Z",
+    ///      "links_breakdown": {
+    ///        "fact": {
+    ///          "entity": 40,
+    ///          "semantic": 60,
+    ///          "temporal": 100
+    ///        }
+    ///      },
+    ///      "links_by_fact_type": {
+    ///        "fact": 200,
+    ///        "observation": 40,
+    ///        "preference": 60
+    ///      },
+    ///      "links_by_link_type": {
+    ///        "entity": 50,
+    ///        "semantic": 100,
+    ///        "temporal": 150
+    ///      },
+    ///      "nodes_by_fact_type": {
+    ///        "fact": 100,
+    ///        "observation": 20,
+    ///        "preference": 30
+    ///      },
+    ///      "pending_consolidation": 0,
+    ///      "pending_operations": 2,
+    ///      "total_documents": 10,
+    ///      "total_links": 300,
+    ///      "total_nodes": 150,
+    ///      "total_observations": 45
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "failed_operations",
+    ///    "links_breakdown",
+    ///    "links_by_fact_type",
+    ///    "links_by_link_type",
+    ///    "nodes_by_fact_type",
+    ///    "pending_operations",
+    ///    "total_documents",
+    ///    "total_links",
+    ///    "total_nodes"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "failed_operations": {
+    ///      "title": "Failed Operations",
+    ///      "type": "integer"
+    ///    },
+    ///    "last_consolidated_at": {
+    ///      "title": "Last Consolidated At",
+    ///      "description": "When consolidation last ran (ISO format)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "links_breakdown": {
+    ///      "title": "Links Breakdown",
+    ///      "type": "object",
+    ///      "additionalProperties": {
+    ///        "type": "object",
+    ///        "additionalProperties": {
+    ///          "type": "integer"
+    ///        }
+    ///      }
+    ///    },
+    ///    "links_by_fact_type": {
+    ///      "title": "Links By Fact Type",
+    ///      "type": "object",
+    ///      "additionalProperties": {
+    ///        "type": "integer"
+    ///      }
+    ///    },
+    ///    "links_by_link_type": {
+    ///      "title": "Links By Link Type",
+    ///      "type": "object",
+    ///      "additionalProperties": {
+    ///        "type": "integer"
+    ///      }
+    ///    },
+    ///    "nodes_by_fact_type": {
+    ///      "title": "Nodes By Fact Type",
+    ///      "type": "object",
+    ///      "additionalProperties": {
+    ///        "type": "integer"
+    ///      }
+    ///    },
+    ///    "pending_consolidation": {
+    ///      "title": "Pending Consolidation",
+    ///      "description": "Number of memories not yet processed into observations",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    },
+    ///    "pending_operations": {
+    ///      "title": "Pending Operations",
+    ///      "type": "integer"
+    ///    },
+    ///    "total_documents": {
+    ///      "title": "Total Documents",
+    ///      "type": "integer"
+    ///    },
+    ///    "total_links": {
+    ///      "title": "Total Links",
+    ///      "type": "integer"
+    ///    },
+    ///    "total_nodes": {
+    ///      "title": "Total Nodes",
+    ///      "type": "integer"
+    ///    },
+    ///    "total_observations": {
+    ///      "title": "Total Observations",
+    ///      "description": "Total number of observations",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BankStatsResponse {
+        pub bank_id: ::std::string::String,
+        pub failed_operations: i64,
+        ///When consolidation last ran (ISO format)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub last_consolidated_at: ::std::option::Option<::std::string::String>,
+        pub links_breakdown: ::std::collections::HashMap<
+            ::std::string::String,
+            ::std::collections::HashMap<::std::string::String, i64>,
+        >,
+        pub links_by_fact_type: ::std::collections::HashMap<::std::string::String, i64>,
+        pub links_by_link_type: ::std::collections::HashMap<::std::string::String, i64>,
+        pub nodes_by_fact_type: ::std::collections::HashMap<::std::string::String, i64>,
+        ///Number of memories not yet processed into observations
+        #[serde(default)]
+        pub pending_consolidation: i64,
+        pub pending_operations: i64,
+        pub total_documents: i64,
+        pub total_links: i64,
+        pub total_nodes: i64,
+        ///Total number of observations
+        #[serde(default)]
+        pub total_observations: i64,
+    }
+    impl ::std::convert::From<&BankStatsResponse> for BankStatsResponse {
+        fn from(value: &BankStatsResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///`BodyFileRetain`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "Body_file_retain",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "files",
+    ///    "request"
+    ///  ],
+    ///  "properties": {
+    ///    "files": {
+    ///      "title": "Files",
+    ///      "description": "Files to upload and convert",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string",
+    ///        "format": "binary"
+    ///      }
+    ///    },
+    ///    "request": {
+    ///      "title": "Request",
+    ///      "description": "JSON string with FileRetainRequest model",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct BodyFileRetain {
+        ///Files to upload and convert
+        pub files: ::std::vec::Vec<::std::string::String>,
+        ///JSON string with FileRetainRequest model
+        pub request: ::std::string::String,
+    }
+    impl ::std::convert::From<&BodyFileRetain> for BodyFileRetain {
+        fn from(value: &BodyFileRetain) -> Self {
+            value.clone()
+        }
+    }
+    ///Budget levels for recall/reflect operations.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "Budget",
+    ///  "description": "Budget levels for recall/reflect operations.",
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "low",
+    ///    "mid",
+    ///    "high"
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(
+        ::serde::Deserialize,
+        ::serde::Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd
+    )]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub enum Budget {
+        #[serde(rename = "low")]
+        Low,
+        #[serde(rename = "mid")]
+        Mid,
+        #[serde(rename = "high")]
+        High,
+    }
+    impl ::std::convert::From<&Self> for Budget {
+        fn from(value: &Budget) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::fmt::Display for Budget {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Low => f.write_str("low"),
+                Self::Mid => f.write_str("mid"),
+                Self::High => f.write_str("high"),
+            }
+        }
+    }
+    impl ::std::str::FromStr for Budget {
+        type Err = self::error::ConversionError;
+        fn from_str(
+            value: &str,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "low" => Ok(Self::Low),
+                "mid" => Ok(Self::Mid),
+                "high" => Ok(Self::High),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<&str> for Budget {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &str,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl ::std::convert::TryFrom<&::std::string::String> for Budget {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl ::std::convert::TryFrom<::std::string::String> for Budget {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    ///Response model for cancel operation endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "CancelOperationResponse",
+    ///  "description": "Response model for cancel operation endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "message": "Operation 550e8400-e29b-41d4-a716-446655440000 cancelled",
+    ///      "operation_id": "550e8400-e29b-41d4-a716-446655440000",
+    ///      "success": true
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "message",
+    ///    "operation_id",
+    ///    "success"
+    ///  ],
+    ///  "properties": {
+    ///    "message": {
+    ///      "title": "Message",
+    ///      "type": "string"
+    ///    },
+    ///    "operation_id": {
+    ///      "title": "Operation Id",
+    ///      "type": "string"
+    ///    },
+    ///    "success": {
+    ///      "title": "Success",
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct CancelOperationResponse {
+        pub message: ::std::string::String,
+        pub operation_id: ::std::string::String,
+        pub success: bool,
+    }
+    impl ::std::convert::From<&CancelOperationResponse> for CancelOperationResponse {
+        fn from(value: &CancelOperationResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Status of a child operation (for batch operations).
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ChildOperationStatus",
+    ///  "description": "Status of a child operation (for batch operations).",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "operation_id",
+    ///    "status"
+    ///  ],
+    ///  "properties": {
+    ///    "error_message": {
+    ///      "title": "Error Message",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "items_count": {
+    ///      "title": "Items Count",
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "operation_id": {
+    ///      "title": "Operation Id",
+    ///      "type": "string"
+    ///    },
+    ///    "status": {
+    ///      "title": "Status",
+    ///      "type": "string"
+    ///    },
+    ///    "sub_batch_index": {
+    ///      "title": "Sub Batch Index",
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ChildOperationStatus {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub error_message: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub items_count: ::std::option::Option<i64>,
+        pub operation_id: ::std::string::String,
+        pub status: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub sub_batch_index: ::std::option::Option<i64>,
+    }
+    impl ::std::convert::From<&ChildOperationStatus> for ChildOperationStatus {
+        fn from(value: &ChildOperationStatus) -> Self {
+            value.clone()
+        }
+    }
+    ///Chunk data for a single chunk.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ChunkData",
+    ///  "description": "Chunk data for a single chunk.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "chunk_index",
+    ///    "id",
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "chunk_index": {
+    ///      "title": "Chunk Index",
+    ///      "type": "integer"
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "text": {
+    ///      "title": "Text",
+    ///      "type": "string"
+    ///    },
+    ///    "truncated": {
+    ///      "title": "Truncated",
+    ///      "description": "Whether the chunk text was truncated due to token limits",
+    ///      "default": false,
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ChunkData {
+        pub chunk_index: i64,
+        pub id: ::std::string::String,
+        pub text: ::std::string::String,
+        ///Whether the chunk text was truncated due to token limits
+        #[serde(default)]
+        pub truncated: bool,
+    }
+    impl ::std::convert::From<&ChunkData> for ChunkData {
+        fn from(value: &ChunkData) -> Self {
+            value.clone()
+        }
+    }
+    ///Options for including chunks in recall results.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ChunkIncludeOptions",
+    ///  "description": "Options for including chunks in recall results.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "description": "Maximum tokens for chunks (chunks may be truncated)",
+    ///      "default": 8192,
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ChunkIncludeOptions {
+        ///Maximum tokens for chunks (chunks may be truncated)
+        #[serde(default = "defaults::default_u64::<i64, 8192>")]
+        pub max_tokens: i64,
+    }
+    impl ::std::convert::From<&ChunkIncludeOptions> for ChunkIncludeOptions {
+        fn from(value: &ChunkIncludeOptions) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for ChunkIncludeOptions {
+        fn default() -> Self {
+            Self {
+                max_tokens: defaults::default_u64::<i64, 8192>(),
+            }
+        }
+    }
+    ///Response model for get chunk endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ChunkResponse",
+    ///  "description": "Response model for get chunk endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "bank_id": "user123",
+    ///      "chunk_id": "user123_session_1_0",
+    ///      "chunk_index": 0,
+    ///      "chunk_text": "This is the first chunk of the document...",
+    ///      "created_at": "2024-01-15T10:30:00Z",
+    ///      "document_id": "session_1"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "chunk_id",
+    ///    "chunk_index",
+    ///    "chunk_text",
+    ///    "created_at",
+    ///    "document_id"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "chunk_id": {
+    ///      "title": "Chunk Id",
+    ///      "type": "string"
+    ///    },
+    ///    "chunk_index": {
+    ///      "title": "Chunk Index",
+    ///      "type": "integer"
+    ///    },
+    ///    "chunk_text": {
+    ///      "title": "Chunk Text",
+    ///      "type": "string"
+    ///    },
+    ///    "created_at": {
+    ///      "title": "Created At",
+    ///      "type": "string"
+    ///    },
+    ///    "document_id": {
+    ///      "title": "Document Id",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ChunkResponse {
+        pub bank_id: ::std::string::String,
+        pub chunk_id: ::std::string::String,
+        pub chunk_index: i64,
+        pub chunk_text: ::std::string::String,
+        pub created_at: ::std::string::String,
+        pub document_id: ::std::string::String,
+    }
+    impl ::std::convert::From<&ChunkResponse> for ChunkResponse {
+        fn from(value: &ChunkResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for consolidation trigger endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ConsolidationResponse",
+    ///  "description": "Response model for consolidation trigger endpoint.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "operation_id"
+    ///  ],
+    ///  "properties": {
+    ///    "deduplicated": {
+    ///      "title": "Deduplicated",
+    ///      "description": "True if an existing pending task was reused",
+    ///      "default": false,
+    ///      "type": "boolean"
+    ///    },
+    ///    "operation_id": {
+    ///      "title": "Operation Id",
+    ///      "description": "ID of the async consolidation operation",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ConsolidationResponse {
+        ///True if an existing pending task was reused
+        #[serde(default)]
+        pub deduplicated: bool,
+        ///ID of the async consolidation operation
+        pub operation_id: ::std::string::String,
+    }
+    impl ::std::convert::From<&ConsolidationResponse> for ConsolidationResponse {
+        fn from(value: &ConsolidationResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Request model for creating/updating a bank.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "CreateBankRequest",
+    ///  "description": "Request model for creating/updating a bank.",
+    ///  "examples": [
+    ///    {
+    ///      "disposition": {
+    ///        "empathy": 3,
+    ///        "literalism": 3,
+    ///        "skepticism": 3
+    ///      },
+    ///      "mission": "I am a PM helping my engineering team stay organized",
+    ///      "name": "Alice"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "background": {
+    ///      "title": "Background",
+    ///      "description": "Deprecated: use mission instead",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "disposition": {
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/DispositionTraits"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    },
+    ///    "mission": {
+    ///      "title": "Mission",
+    ///      "description": "The agent's mission",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct CreateBankRequest {
+        ///Deprecated: use mission instead
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub background: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub disposition: ::std::option::Option<DispositionTraits>,
+        ///The agent's mission
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub mission: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub name: ::std::option::Option<::std::string::String>,
+    }
+    impl ::std::convert::From<&CreateBankRequest> for CreateBankRequest {
+        fn from(value: &CreateBankRequest) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for CreateBankRequest {
+        fn default() -> Self {
+            Self {
+                background: Default::default(),
+                disposition: Default::default(),
+                mission: Default::default(),
+                name: Default::default(),
+            }
+        }
+    }
+    ///Request model for creating a directive.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "CreateDirectiveRequest",
+    ///  "description": "Request model for creating a directive.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "content",
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "content": {
+    ///      "title": "Content",
+    ///      "description": "The directive text to inject into prompts",
+    ///      "type": "string"
+    ///    },
+    ///    "is_active": {
+    ///      "title": "Is Active",
+    ///      "description": "Whether this directive is active",
+    ///      "default": true,
+    ///      "type": "boolean"
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "description": "Human-readable name for the directive",
+    ///      "type": "string"
+    ///    },
+    ///    "priority": {
+    ///      "title": "Priority",
+    ///      "description": "Higher priority directives are injected first",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "Tags for filtering",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct CreateDirectiveRequest {
+        ///The directive text to inject into prompts
+        pub content: ::std::string::String,
+        ///Whether this directive is active
+        #[serde(default = "defaults::default_bool::<true>")]
+        pub is_active: bool,
+        ///Human-readable name for the directive
+        pub name: ::std::string::String,
+        ///Higher priority directives are injected first
+        #[serde(default)]
+        pub priority: i64,
+        ///Tags for filtering
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub tags: ::std::vec::Vec<::std::string::String>,
+    }
+    impl ::std::convert::From<&CreateDirectiveRequest> for CreateDirectiveRequest {
+        fn from(value: &CreateDirectiveRequest) -> Self {
+            value.clone()
+        }
+    }
+    ///Request model for creating a mental model.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "CreateMentalModelRequest",
+    ///  "description": "Request model for creating a mental model.",
+    ///  "examples": [
+    ///    {
+    ///      "id": "team-communication",
+    ///      "max_tokens": 2048,
+    ///      "name": "Team Communication Preferences",
+    ///      "source_query": "How does the team prefer to communicate?",
+    ///      "tags": [
+    ///        "team"
+    ///      ],
+    ///      "trigger": {
+    ///        "refresh_after_consolidation": false
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "name",
+    ///    "source_query"
+    ///  ],
+    ///  "properties": {
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "description": "Optional custom ID for the mental model (alphanumeric lowercase with hyphens)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "description": "Maximum tokens for generated content",
+    ///      "default": 2048,
+    ///      "type": "integer",
+    ///      "maximum": 8192.0,
+    ///      "minimum": 256.0
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "description": "Human-readable name for the mental model",
+    ///      "type": "string"
+    ///    },
+    ///    "source_query": {
+    ///      "title": "Source Query",
+    ///      "description": "The query to run to generate content",
+    ///      "type": "string"
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "Tags for scoped visibility",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "trigger": {
+    ///      "description": "Trigger settings",
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/MentalModelTrigger"
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct CreateMentalModelRequest {
+        ///Optional custom ID for the mental model (alphanumeric lowercase with hyphens)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub id: ::std::option::Option<::std::string::String>,
+        ///Maximum tokens for generated content
+        #[serde(default = "defaults::default_u64::<i64, 2048>")]
+        pub max_tokens: i64,
+        ///Human-readable name for the mental model
+        pub name: ::std::string::String,
+        ///The query to run to generate content
+        pub source_query: ::std::string::String,
+        ///Tags for scoped visibility
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub tags: ::std::vec::Vec<::std::string::String>,
+        ///Trigger settings
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub trigger: ::std::option::Option<MentalModelTrigger>,
+    }
+    impl ::std::convert::From<&CreateMentalModelRequest> for CreateMentalModelRequest {
+        fn from(value: &CreateMentalModelRequest) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for mental model creation.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "CreateMentalModelResponse",
+    ///  "description": "Response model for mental model creation.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "operation_id"
+    ///  ],
+    ///  "properties": {
+    ///    "mental_model_id": {
+    ///      "title": "Mental Model Id",
+    ///      "description": "ID of the created mental model",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "operation_id": {
+    ///      "title": "Operation Id",
+    ///      "description": "Operation ID to track refresh progress",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct CreateMentalModelResponse {
+        ///ID of the created mental model
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub mental_model_id: ::std::option::Option<::std::string::String>,
+        ///Operation ID to track refresh progress
+        pub operation_id: ::std::string::String,
+    }
+    impl ::std::convert::From<&CreateMentalModelResponse> for CreateMentalModelResponse {
+        fn from(value: &CreateMentalModelResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for delete document endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "DeleteDocumentResponse",
+    ///  "description": "Response model for delete document endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "document_id": "session_1",
+    ///      "memory_units_deleted": 5,
+    ///      "message": "Document 'session_1' and 5 associated memory units deleted successfully",
+    ///      "success": true
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "document_id",
+    ///    "memory_units_deleted",
+    ///    "message",
+    ///    "success"
+    ///  ],
+    ///  "properties": {
+    ///    "document_id": {
+    ///      "title": "Document Id",
+    ///      "type": "string"
+    ///    },
+    ///    "memory_units_deleted": {
+    ///      "title": "Memory Units Deleted",
+    ///      "type": "integer"
+    ///    },
+    ///    "message": {
+    ///      "title": "Message",
+    ///      "type": "string"
+    ///    },
+    ///    "success": {
+    ///      "title": "Success",
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct DeleteDocumentResponse {
+        pub document_id: ::std::string::String,
+        pub memory_units_deleted: i64,
+        pub message: ::std::string::String,
+        pub success: bool,
+    }
+    impl ::std::convert::From<&DeleteDocumentResponse> for DeleteDocumentResponse {
+        fn from(value: &DeleteDocumentResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for delete operations.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "DeleteResponse",
+    ///  "description": "Response model for delete operations.",
+    ///  "examples": [
+    ///    {
+    ///      "deleted_count": 10,
+    ///      "message": "Deleted successfully",
+    ///      "success": true
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "success"
+    ///  ],
+    ///  "properties": {
+    ///    "deleted_count": {
+    ///      "title": "Deleted Count",
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "message": {
+    ///      "title": "Message",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "success": {
+    ///      "title": "Success",
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct DeleteResponse {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub deleted_count: ::std::option::Option<i64>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub message: ::std::option::Option<::std::string::String>,
+        pub success: bool,
+    }
+    impl ::std::convert::From<&DeleteResponse> for DeleteResponse {
+        fn from(value: &DeleteResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for listing directives.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "DirectiveListResponse",
+    ///  "description": "Response model for listing directives.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "title": "Items",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/DirectiveResponse"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct DirectiveListResponse {
+        pub items: ::std::vec::Vec<DirectiveResponse>,
+    }
+    impl ::std::convert::From<&DirectiveListResponse> for DirectiveListResponse {
+        fn from(value: &DirectiveListResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for a directive.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "DirectiveResponse",
+    ///  "description": "Response model for a directive.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "content",
+    ///    "id",
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "content": {
+    ///      "title": "Content",
+    ///      "type": "string"
+    ///    },
+    ///    "created_at": {
+    ///      "title": "Created At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "is_active": {
+    ///      "title": "Is Active",
+    ///      "default": true,
+    ///      "type": "boolean"
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "type": "string"
+    ///    },
+    ///    "priority": {
+    ///      "title": "Priority",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "updated_at": {
+    ///      "title": "Updated At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct DirectiveResponse {
+        pub bank_id: ::std::string::String,
+        pub content: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub created_at: ::std::option::Option<::std::string::String>,
+        pub id: ::std::string::String,
+        #[serde(default = "defaults::default_bool::<true>")]
+        pub is_active: bool,
+        pub name: ::std::string::String,
+        #[serde(default)]
+        pub priority: i64,
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub tags: ::std::vec::Vec<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub updated_at: ::std::option::Option<::std::string::String>,
+    }
+    impl ::std::convert::From<&DirectiveResponse> for DirectiveResponse {
+        fn from(value: &DirectiveResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Disposition traits that influence how memories are formed and interpreted.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "DispositionTraits",
+    ///  "description": "Disposition traits that influence how memories are formed and interpreted.",
+    ///  "examples": [
+    ///    {
+    ///      "empathy": 3,
+    ///      "literalism": 3,
+    ///      "skepticism": 3
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "empathy",
+    ///    "literalism",
+    ///    "skepticism"
+    ///  ],
+    ///  "properties": {
+    ///    "empathy": {
+    ///      "title": "Empathy",
+    ///      "description": "How much to consider emotional context (1=detached, 5=empathetic)",
+    ///      "type": "integer",
+    ///      "maximum": 5.0,
+    ///      "minimum": 1.0
+    ///    },
+    ///    "literalism": {
+    ///      "title": "Literalism",
+    ///      "description": "How literally to interpret information (1=flexible, 5=literal)",
+    ///      "type": "integer",
+    ///      "maximum": 5.0,
+    ///      "minimum": 1.0
+    ///    },
+    ///    "skepticism": {
+    ///      "title": "Skepticism",
+    ///      "description": "How skeptical vs trusting (1=trusting, 5=skeptical)",
+    ///      "type": "integer",
+    ///      "maximum": 5.0,
+    ///      "minimum": 1.0
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct DispositionTraits {
+        ///How much to consider emotional context (1=detached, 5=empathetic)
+        pub empathy: ::std::num::NonZeroU64,
+        ///How literally to interpret information (1=flexible, 5=literal)
+        pub literalism: ::std::num::NonZeroU64,
+        ///How skeptical vs trusting (1=trusting, 5=skeptical)
+        pub skepticism: ::std::num::NonZeroU64,
+    }
+    impl ::std::convert::From<&DispositionTraits> for DispositionTraits {
+        fn from(value: &DispositionTraits) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for get document endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "DocumentResponse",
+    ///  "description": "Response model for get document endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "bank_id": "user123",
+    ///      "content_hash": "abc123",
+    ///      "created_at": "2024-01-15T10:30:00Z",
+    ///      "id": "session_1",
+    ///      "memory_unit_count": 15,
+    ///      "original_text": "Full document text here...",
+    ///      "tags": [
+    ///        "user_a",
+    ///        "session_123"
+    ///      ],
+    ///      "updated_at": "2024-01-15T10:30:00Z"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "content_hash",
+    ///    "created_at",
+    ///    "id",
+    ///    "memory_unit_count",
+    ///    "original_text",
+    ///    "updated_at"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "content_hash": {
+    ///      "title": "Content Hash",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "created_at": {
+    ///      "title": "Created At",
+    ///      "type": "string"
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "memory_unit_count": {
+    ///      "title": "Memory Unit Count",
+    ///      "type": "integer"
+    ///    },
+    ///    "original_text": {
+    ///      "title": "Original Text",
+    ///      "type": "string"
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "Tags associated with this document",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "updated_at": {
+    ///      "title": "Updated At",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct DocumentResponse {
+        pub bank_id: ::std::string::String,
+        pub content_hash: ::std::option::Option<::std::string::String>,
+        pub created_at: ::std::string::String,
+        pub id: ::std::string::String,
+        pub memory_unit_count: i64,
+        pub original_text: ::std::string::String,
+        ///Tags associated with this document
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub tags: ::std::vec::Vec<::std::string::String>,
+        pub updated_at: ::std::string::String,
+    }
+    impl ::std::convert::From<&DocumentResponse> for DocumentResponse {
+        fn from(value: &DocumentResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for entity detail endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "EntityDetailResponse",
+    ///  "description": "Response model for entity detail endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "canonical_name": "John",
+    ///      "first_seen": "2024-01-15T10:30:00Z",
+    ///      "id": "123e4567-e89b-12d3-a456-426614174000",
+    ///      "last_seen": "2024-02-01T14:00:00Z",
+    ///      "mention_count": 15,
+    ///      "observations": [
+    ///        {
+    ///          "mentioned_at": "2024-01-15T10:30:00Z",
+    ///          "text": "John works at Google"
+    ///        }
+    ///      ]
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "canonical_name",
+    ///    "id",
+    ///    "mention_count",
+    ///    "observations"
+    ///  ],
+    ///  "properties": {
+    ///    "canonical_name": {
+    ///      "title": "Canonical Name",
+    ///      "type": "string"
+    ///    },
+    ///    "first_seen": {
+    ///      "title": "First Seen",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "last_seen": {
+    ///      "title": "Last Seen",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "mention_count": {
+    ///      "title": "Mention Count",
+    ///      "type": "integer"
+    ///    },
+    ///    "metadata": {
+    ///      "title": "Metadata",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    },
+    ///    "observations": {
+    ///      "title": "Observations",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/EntityObservationResponse"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct EntityDetailResponse {
+        pub canonical_name: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub first_seen: ::std::option::Option<::std::string::String>,
+        pub id: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub last_seen: ::std::option::Option<::std::string::String>,
+        pub mention_count: i64,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub metadata: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub observations: ::std::vec::Vec<EntityObservationResponse>,
+    }
+    impl ::std::convert::From<&EntityDetailResponse> for EntityDetailResponse {
+        fn from(value: &EntityDetailResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Options for including entity observations in recall results.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "EntityIncludeOptions",
+    ///  "description": "Options for including entity observations in recall results.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "description": "Maximum tokens for entity observations",
+    ///      "default": 500,
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct EntityIncludeOptions {
+        ///Maximum tokens for entity observations
+        #[serde(default = "defaults::default_u64::<i64, 500>")]
+        pub max_tokens: i64,
+    }
+    impl ::std::convert::From<&EntityIncludeOptions> for EntityIncludeOptions {
+        fn from(value: &EntityIncludeOptions) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for EntityIncludeOptions {
+        fn default() -> Self {
+            Self {
+                max_tokens: defaults::default_u64::<i64, 500>(),
+            }
+        }
+    }
+    ///Entity to associate with retained content.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "EntityInput",
+    ///  "description": "Entity to associate with retained content.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "text": {
+    ///      "title": "Text",
+    ///      "description": "The entity name/text",
+    ///      "type": "string"
+    ///    },
+    ///    "type": {
+    ///      "title": "Type",
+    ///      "description": "Optional entity type (e.g., 'PERSON', 'ORG', 'CONCEPT')",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct EntityInput {
+        ///The entity name/text
+        pub text: ::std::string::String,
+        ///Optional entity type (e.g., 'PERSON', 'ORG', 'CONCEPT')
+        #[serde(
+            rename = "type",
+            default,
+            skip_serializing_if = "::std::option::Option::is_none"
+        )]
+        pub type_: ::std::option::Option<::std::string::String>,
+    }
+    impl ::std::convert::From<&EntityInput> for EntityInput {
+        fn from(value: &EntityInput) -> Self {
+            value.clone()
+        }
+    }
+    ///Entity list item with summary.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "EntityListItem",
+    ///  "description": "Entity list item with summary.",
+    ///  "examples": [
+    ///    {
+    ///      "canonical_name": "John",
+    ///      "first_seen": "2024-01-15T10:30:00Z",
+    ///      "id": "123e4567-e89b-12d3-a456-426614174000",
+    ///      "last_seen": "2024-02-01T14:00:00Z",
+    ///      "mention_count": 15
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "canonical_name",
+    ///    "id",
+    ///    "mention_count"
+    ///  ],
+    ///  "properties": {
+    ///    "canonical_name": {
+    ///      "title": "Canonical Name",
+    ///      "type": "string"
+    ///    },
+    ///    "first_seen": {
+    ///      "title": "First Seen",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "last_seen": {
+    ///      "title": "Last Seen",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "mention_count": {
+    ///      "title": "Mention Count",
+    ///      "type": "integer"
+    ///    },
+    ///    "metadata": {
+    ///      "title": "Metadata",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct EntityListItem {
+        pub canonical_name: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub first_seen: ::std::option::Option<::std::string::String>,
+        pub id: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub last_seen: ::std::option::Option<::std::string::String>,
+        pub mention_count: i64,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub metadata: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+    }
+    impl ::std::convert::From<&EntityListItem> for EntityListItem {
+        fn from(value: &EntityListItem) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for entity list endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "EntityListResponse",
+    ///  "description": "Response model for entity list endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "items": [
+    ///        {
+    ///          "canonical_name": "John",
+    ///          "first_seen": "2024-01-15T10:30:00Z",
+    ///          "id": "123e4567-e89b-12d3-a456-426614174000",
+    ///          "last_seen": "2024-02-01T14:00:00Z",
+    ///          "mention_count": 15
+    ///        }
+    ///      ],
+    ///      "limit": 100,
+    ///      "offset": 0,
+    ///      "total": 150
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items",
+    ///    "limit",
+    ///    "offset",
+    ///    "total"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "title": "Items",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/EntityListItem"
+    ///      }
+    ///    },
+    ///    "limit": {
+    ///      "title": "Limit",
+    ///      "type": "integer"
+    ///    },
+    ///    "offset": {
+    ///      "title": "Offset",
+    ///      "type": "integer"
+    ///    },
+    ///    "total": {
+    ///      "title": "Total",
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct EntityListResponse {
+        pub items: ::std::vec::Vec<EntityListItem>,
+        pub limit: i64,
+        pub offset: i64,
+        pub total: i64,
+    }
+    impl ::std::convert::From<&EntityListResponse> for EntityListResponse {
+        fn from(value: &EntityListResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///An observation about an entity.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "EntityObservationResponse",
+    ///  "description": "An observation about an entity.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "mentioned_at": {
+    ///      "title": "Mentioned At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "text": {
+    ///      "title": "Text",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct EntityObservationResponse {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub mentioned_at: ::std::option::Option<::std::string::String>,
+        pub text: ::std::string::String,
+    }
+    impl ::std::convert::From<&EntityObservationResponse> for EntityObservationResponse {
+        fn from(value: &EntityObservationResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Current mental model of an entity.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "EntityStateResponse",
+    ///  "description": "Current mental model of an entity.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "canonical_name",
+    ///    "entity_id",
+    ///    "observations"
+    ///  ],
+    ///  "properties": {
+    ///    "canonical_name": {
+    ///      "title": "Canonical Name",
+    ///      "type": "string"
+    ///    },
+    ///    "entity_id": {
+    ///      "title": "Entity Id",
+    ///      "type": "string"
+    ///    },
+    ///    "observations": {
+    ///      "title": "Observations",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/EntityObservationResponse"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct EntityStateResponse {
+        pub canonical_name: ::std::string::String,
+        pub entity_id: ::std::string::String,
+        pub observations: ::std::vec::Vec<EntityObservationResponse>,
+    }
+    impl ::std::convert::From<&EntityStateResponse> for EntityStateResponse {
+        fn from(value: &EntityStateResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Options for including facts (based_on) in reflect results.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "FactsIncludeOptions",
+    ///  "description": "Options for including facts (based_on) in reflect results.",
+    ///  "type": "object"
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(transparent)]
+    pub struct FactsIncludeOptions(
+        pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    );
+    impl ::std::ops::Deref for FactsIncludeOptions {
+        type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
+        fn deref(
+            &self,
+        ) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+            &self.0
+        }
+    }
+    impl ::std::convert::From<FactsIncludeOptions>
+    for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+        fn from(value: FactsIncludeOptions) -> Self {
+            value.0
+        }
+    }
+    impl ::std::convert::From<&FactsIncludeOptions> for FactsIncludeOptions {
+        fn from(value: &FactsIncludeOptions) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::convert::From<
+        ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    > for FactsIncludeOptions {
+        fn from(
+            value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        ) -> Self {
+            Self(value)
+        }
+    }
+    ///Feature flags indicating which capabilities are enabled.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "FeaturesInfo",
+    ///  "description": "Feature flags indicating which capabilities are enabled.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_config_api",
+    ///    "file_upload_api",
+    ///    "mcp",
+    ///    "observations",
+    ///    "worker"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_config_api": {
+    ///      "title": "Bank Config Api",
+    ///      "description": "Whether per-bank configuration API is enabled",
+    ///      "type": "boolean"
+    ///    },
+    ///    "file_upload_api": {
+    ///      "title": "File Upload Api",
+    ///      "description": "Whether file upload/conversion API is enabled",
+    ///      "type": "boolean"
+    ///    },
+    ///    "mcp": {
+    ///      "title": "Mcp",
+    ///      "description": "Whether MCP (Model Context Protocol) server is enabled",
+    ///      "type": "boolean"
+    ///    },
+    ///    "observations": {
+    ///      "title": "Observations",
+    ///      "description": "Whether observations (auto-consolidation) are enabled",
+    ///      "type": "boolean"
+    ///    },
+    ///    "worker": {
+    ///      "title": "Worker",
+    ///      "description": "Whether the background worker is enabled",
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct FeaturesInfo {
+        ///Whether per-bank configuration API is enabled
+        pub bank_config_api: bool,
+        ///Whether file upload/conversion API is enabled
+        pub file_upload_api: bool,
+        ///Whether MCP (Model Context Protocol) server is enabled
+        pub mcp: bool,
+        ///Whether observations (auto-consolidation) are enabled
+        pub observations: bool,
+        ///Whether the background worker is enabled
+        pub worker: bool,
+    }
+    impl ::std::convert::From<&FeaturesInfo> for FeaturesInfo {
+        fn from(value: &FeaturesInfo) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for file upload endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "FileRetainResponse",
+    ///  "description": "Response model for file upload endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "operation_ids": [
+    ///        "550e8400-e29b-41d4-a716-446655440000",
+    ///        "550e8400-e29b-41d4-a716-446655440001",
+    ///        "550e8400-e29b-41d4-a716-446655440002"
+    ///      ]
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "operation_ids"
+    ///  ],
+    ///  "properties": {
+    ///    "operation_ids": {
+    ///      "title": "Operation Ids",
+    ///      "description": "Operation IDs for tracking file conversion operations. Use GET /v1/default/banks/{bank_id}/operations to list operations.",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct FileRetainResponse {
+        ///Operation IDs for tracking file conversion operations. Use GET /v1/default/banks/{bank_id}/operations to list operations.
+        pub operation_ids: ::std::vec::Vec<::std::string::String>,
+    }
+    impl ::std::convert::From<&FileRetainResponse> for FileRetainResponse {
+        fn from(value: &FileRetainResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for graph data endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "GraphDataResponse",
+    ///  "description": "Response model for graph data endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "edges": [
+    ///        {
+    ///          "from": "1",
+    ///          "to": "2",
+    ///          "type": "semantic",
+    ///          "weight": 0.8
+    ///        }
+    ///      ],
+    ///      "limit": 1000,
+    ///      "nodes": [
+    ///        {
+    ///          "id": "1",
+    ///          "label": "Alice works at Google",
+    ///          "type": "world"
+    ///        },
+    ///        {
+    ///          "id": "2",
+    ///          "label": "Bob went hiking",
+    ///          "type": "world"
+    ///        }
+    ///      ],
+    ///      "table_rows": [
+    ///        {
+    ///          "context": "Work info",
+    ///          "date": "2024-01-15 10:30",
+    ///          "entities": "Alice (PERSON), Google (ORGANIZATION)",
+    ///          "id": "abc12345...",
+    ///          "text": "Alice works at Google"
+    ///        }
+    ///      ],
+    ///      "total_units": 2
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "edges",
+    ///    "limit",
+    ///    "nodes",
+    ///    "table_rows",
+    ///    "total_units"
+    ///  ],
+    ///  "properties": {
+    ///    "edges": {
+    ///      "title": "Edges",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "object",
+    ///        "additionalProperties": true
+    ///      }
+    ///    },
+    ///    "limit": {
+    ///      "title": "Limit",
+    ///      "type": "integer"
+    ///    },
+    ///    "nodes": {
+    ///      "title": "Nodes",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "object",
+    ///        "additionalProperties": true
+    ///      }
+    ///    },
+    ///    "table_rows": {
+    ///      "title": "Table Rows",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "object",
+    ///        "additionalProperties": true
+    ///      }
+    ///    },
+    ///    "total_units": {
+    ///      "title": "Total Units",
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct GraphDataResponse {
+        pub edges: ::std::vec::Vec<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub limit: i64,
+        pub nodes: ::std::vec::Vec<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub table_rows: ::std::vec::Vec<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub total_units: i64,
+    }
+    impl ::std::convert::From<&GraphDataResponse> for GraphDataResponse {
+        fn from(value: &GraphDataResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///`HttpValidationError`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "HTTPValidationError",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "detail": {
+    ///      "title": "Detail",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ValidationError"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct HttpValidationError {
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub detail: ::std::vec::Vec<ValidationError>,
+    }
+    impl ::std::convert::From<&HttpValidationError> for HttpValidationError {
+        fn from(value: &HttpValidationError) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for HttpValidationError {
+        fn default() -> Self {
+            Self { detail: Default::default() }
+        }
+    }
+    ///Options for including additional data in recall results.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "IncludeOptions",
+    ///  "description": "Options for including additional data in recall results.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "chunks": {
+    ///      "description": "Include raw chunks. Set to {} to enable, null to disable (default: disabled).",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/ChunkIncludeOptions"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    },
+    ///    "entities": {
+    ///      "description": "Include entity observations. Set to null to disable entity inclusion.",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/EntityIncludeOptions"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    },
+    ///    "source_facts": {
+    ///      "description": "Include source facts for observation-type results. Set to {} to enable, null to disable (default: disabled).",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/SourceFactsIncludeOptions"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct IncludeOptions {
+        ///Include raw chunks. Set to {} to enable, null to disable (default: disabled).
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub chunks: ::std::option::Option<ChunkIncludeOptions>,
+        ///Include entity observations. Set to null to disable entity inclusion.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub entities: ::std::option::Option<EntityIncludeOptions>,
+        ///Include source facts for observation-type results. Set to {} to enable, null to disable (default: disabled).
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub source_facts: ::std::option::Option<SourceFactsIncludeOptions>,
+    }
+    impl ::std::convert::From<&IncludeOptions> for IncludeOptions {
+        fn from(value: &IncludeOptions) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for IncludeOptions {
+        fn default() -> Self {
+            Self {
+                chunks: Default::default(),
+                entities: Default::default(),
+                source_facts: Default::default(),
+            }
+        }
+    }
+    ///Response model for list documents endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ListDocumentsResponse",
+    ///  "description": "Response model for list documents endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "items": [
+    ///        {
+    ///          "bank_id": "user123",
+    ///          "content_hash": "abc123",
+    ///          "created_at": "2024-01-15T10:30:00Z",
+    ///          "id": "session_1",
+    ///          "memory_unit_count": 15,
+    ///          "tags": [
+    ///            "user_a",
+    ///            "session_123"
+    ///          ],
+    ///          "text_length": 5420,
+    ///          "updated_at": "2024-01-15T10:30:00Z"
+    ///        }
+    ///      ],
+    ///      "limit": 100,
+    ///      "offset": 0,
+    ///      "total": 50
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items",
+    ///    "limit",
+    ///    "offset",
+    ///    "total"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "title": "Items",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "object",
+    ///        "additionalProperties": true
+    ///      }
+    ///    },
+    ///    "limit": {
+    ///      "title": "Limit",
+    ///      "type": "integer"
+    ///    },
+    ///    "offset": {
+    ///      "title": "Offset",
+    ///      "type": "integer"
+    ///    },
+    ///    "total": {
+    ///      "title": "Total",
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ListDocumentsResponse {
+        pub items: ::std::vec::Vec<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub limit: i64,
+        pub offset: i64,
+        pub total: i64,
+    }
+    impl ::std::convert::From<&ListDocumentsResponse> for ListDocumentsResponse {
+        fn from(value: &ListDocumentsResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for list memory units endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ListMemoryUnitsResponse",
+    ///  "description": "Response model for list memory units endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "items": [
+    ///        {
+    ///          "context": "Work conversation",
+    ///          "date": "2024-01-15T10:30:00Z",
+    ///          "entities": "Alice (PERSON), Google (ORGANIZATION)",
+    ///          "id": "550e8400-e29b-41d4-a716-446655440000",
+    ///          "text": "Alice works at Google on the AI team",
+    ///          "type": "world"
+    ///        }
+    ///      ],
+    ///      "limit": 100,
+    ///      "offset": 0,
+    ///      "total": 150
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items",
+    ///    "limit",
+    ///    "offset",
+    ///    "total"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "title": "Items",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "object",
+    ///        "additionalProperties": true
+    ///      }
+    ///    },
+    ///    "limit": {
+    ///      "title": "Limit",
+    ///      "type": "integer"
+    ///    },
+    ///    "offset": {
+    ///      "title": "Offset",
+    ///      "type": "integer"
+    ///    },
+    ///    "total": {
+    ///      "title": "Total",
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ListMemoryUnitsResponse {
+        pub items: ::std::vec::Vec<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub limit: i64,
+        pub offset: i64,
+        pub total: i64,
+    }
+    impl ::std::convert::From<&ListMemoryUnitsResponse> for ListMemoryUnitsResponse {
+        fn from(value: &ListMemoryUnitsResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for list tags endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ListTagsResponse",
+    ///  "description": "Response model for list tags endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "items": [
+    ///        {
+    ///          "count": 42,
+    ///          "tag": "user:alice"
+    ///        },
+    ///        {
+    ///          "count": 15,
+    ///          "tag": "user:bob"
+    ///        },
+    ///        {
+    ///          "count": 8,
+    ///          "tag": "session:abc123"
+    ///        }
+    ///      ],
+    ///      "limit": 100,
+    ///      "offset": 0,
+    ///      "total": 25
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items",
+    ///    "limit",
+    ///    "offset",
+    ///    "total"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "title": "Items",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/TagItem"
+    ///      }
+    ///    },
+    ///    "limit": {
+    ///      "title": "Limit",
+    ///      "type": "integer"
+    ///    },
+    ///    "offset": {
+    ///      "title": "Offset",
+    ///      "type": "integer"
+    ///    },
+    ///    "total": {
+    ///      "title": "Total",
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ListTagsResponse {
+        pub items: ::std::vec::Vec<TagItem>,
+        pub limit: i64,
+        pub offset: i64,
+        pub total: i64,
+    }
+    impl ::std::convert::From<&ListTagsResponse> for ListTagsResponse {
+        fn from(value: &ListTagsResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///`LocationItem`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "anyOf": [
+    ///    {
+    ///      "type": "string"
+    ///    },
+    ///    {
+    ///      "type": "integer"
+    ///    }
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(untagged)]
+    pub enum LocationItem {
+        Variant0(::std::string::String),
+        Variant1(i64),
+    }
+    impl ::std::convert::From<&Self> for LocationItem {
+        fn from(value: &LocationItem) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::fmt::Display for LocationItem {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match self {
+                Self::Variant0(x) => x.fmt(f),
+                Self::Variant1(x) => x.fmt(f),
+            }
+        }
+    }
+    impl ::std::convert::From<i64> for LocationItem {
+        fn from(value: i64) -> Self {
+            Self::Variant1(value)
+        }
+    }
+    ///Single memory item for retain.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "MemoryItem",
+    ///  "description": "Single memory item for retain.",
+    ///  "examples": [
+    ///    {
+    ///      "content": "Alice mentioned she's working on a new ML model",
+    ///      "context": "team meeting",
+    ///      "document_id": "meeting_notes_2024_01_15",
+    ///      "entities": [
+    ///        {
+    ///          "text": "Alice"
+    ///        },
+    ///        {
+    ///          "text": "ML model",
+    ///          "type": "CONCEPT"
+    ///        }
+    ///      ],
+    ///      "metadata": {
+    ///        "channel": "engineering",
+    ///        "source": "slack"
+    ///      },
+    ///      "tags": [
+    ///        "user_a",
+    ///        "user_b"
+    ///      ],
+    ///      "timestamp": "2024-01-15T10:30:00Z"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "content"
+    ///  ],
+    ///  "properties": {
+    ///    "content": {
+    ///      "title": "Content",
+    ///      "type": "string"
+    ///    },
+    ///    "context": {
+    ///      "title": "Context",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "document_id": {
+    ///      "title": "Document Id",
+    ///      "description": "Optional document ID for this memory item.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "entities": {
+    ///      "title": "Entities",
+    ///      "description": "Optional entities to combine with auto-extracted entities.",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/EntityInput"
+    ///      }
+    ///    },
+    ///    "metadata": {
+    ///      "title": "Metadata",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "Optional tags for visibility scoping. Memories with tags can be filtered during recall.",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "timestamp": {
+    ///      "title": "Timestamp",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct MemoryItem {
+        pub content: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub context: ::std::option::Option<::std::string::String>,
+        ///Optional document ID for this memory item.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub document_id: ::std::option::Option<::std::string::String>,
+        ///Optional entities to combine with auto-extracted entities.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub entities: ::std::option::Option<::std::vec::Vec<EntityInput>>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub metadata: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+        >,
+        ///Optional tags for visibility scoping. Memories with tags can be filtered during recall.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub timestamp: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+    }
+    impl ::std::convert::From<&MemoryItem> for MemoryItem {
+        fn from(value: &MemoryItem) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for listing mental models.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "MentalModelListResponse",
+    ///  "description": "Response model for listing mental models.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items"
+    ///  ],
+    ///  "properties": {
+    ///    "items": {
+    ///      "title": "Items",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/MentalModelResponse"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct MentalModelListResponse {
+        pub items: ::std::vec::Vec<MentalModelResponse>,
+    }
+    impl ::std::convert::From<&MentalModelListResponse> for MentalModelListResponse {
+        fn from(value: &MentalModelListResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for a mental model (stored reflect response).
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "MentalModelResponse",
+    ///  "description": "Response model for a mental model (stored reflect response).",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "content",
+    ///    "id",
+    ///    "name",
+    ///    "source_query"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "content": {
+    ///      "title": "Content",
+    ///      "description": "The mental model content as well-formatted markdown (auto-generated from reflect endpoint)",
+    ///      "type": "string"
+    ///    },
+    ///    "created_at": {
+    ///      "title": "Created At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "last_refreshed_at": {
+    ///      "title": "Last Refreshed At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "default": 2048,
+    ///      "type": "integer"
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "type": "string"
+    ///    },
+    ///    "reflect_response": {
+    ///      "title": "Reflect Response",
+    ///      "description": "Full reflect API response payload including based_on facts and observations",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    },
+    ///    "source_query": {
+    ///      "title": "Source Query",
+    ///      "type": "string"
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "trigger": {
+    ///      "$ref": "#/components/schemas/MentalModelTrigger"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct MentalModelResponse {
+        pub bank_id: ::std::string::String,
+        ///The mental model content as well-formatted markdown (auto-generated from reflect endpoint)
+        pub content: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub created_at: ::std::option::Option<::std::string::String>,
+        pub id: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub last_refreshed_at: ::std::option::Option<::std::string::String>,
+        #[serde(default = "defaults::default_u64::<i64, 2048>")]
+        pub max_tokens: i64,
+        pub name: ::std::string::String,
+        ///Full reflect API response payload including based_on facts and observations
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub reflect_response: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub source_query: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub tags: ::std::vec::Vec<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub trigger: ::std::option::Option<MentalModelTrigger>,
+    }
+    impl ::std::convert::From<&MentalModelResponse> for MentalModelResponse {
+        fn from(value: &MentalModelResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Trigger settings for a mental model.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "MentalModelTrigger",
+    ///  "description": "Trigger settings for a mental model.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "refresh_after_consolidation": {
+    ///      "title": "Refresh After Consolidation",
+    ///      "description": "If true, refresh this mental model after observations consolidation (real-time mode)",
+    ///      "default": false,
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct MentalModelTrigger {
+        ///If true, refresh this mental model after observations consolidation (real-time mode)
+        #[serde(default)]
+        pub refresh_after_consolidation: bool,
+    }
+    impl ::std::convert::From<&MentalModelTrigger> for MentalModelTrigger {
+        fn from(value: &MentalModelTrigger) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for MentalModelTrigger {
+        fn default() -> Self {
+            Self {
+                refresh_after_consolidation: Default::default(),
+            }
+        }
+    }
+    ///Response model for a single async operation.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "OperationResponse",
+    ///  "description": "Response model for a single async operation.",
+    ///  "examples": [
+    ///    {
+    ///      "created_at": "2024-01-15T10:30:00Z",
+    ///      "id": "550e8400-e29b-41d4-a716-446655440000",
+    ///      "items_count": 5,
+    ///      "status": "pending",
+    ///      "task_type": "retain"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "created_at",
+    ///    "error_message",
+    ///    "id",
+    ///    "items_count",
+    ///    "status",
+    ///    "task_type"
+    ///  ],
+    ///  "properties": {
+    ///    "created_at": {
+    ///      "title": "Created At",
+    ///      "type": "string"
+    ///    },
+    ///    "document_id": {
+    ///      "title": "Document Id",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "error_message": {
+    ///      "title": "Error Message",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "items_count": {
+    ///      "title": "Items Count",
+    ///      "type": "integer"
+    ///    },
+    ///    "status": {
+    ///      "title": "Status",
+    ///      "type": "string"
+    ///    },
+    ///    "task_type": {
+    ///      "title": "Task Type",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct OperationResponse {
+        pub created_at: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub document_id: ::std::option::Option<::std::string::String>,
+        pub error_message: ::std::option::Option<::std::string::String>,
+        pub id: ::std::string::String,
+        pub items_count: i64,
+        pub status: ::std::string::String,
+        pub task_type: ::std::string::String,
+    }
+    impl ::std::convert::From<&OperationResponse> for OperationResponse {
+        fn from(value: &OperationResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for getting a single operation status.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "OperationStatusResponse",
+    ///  "description": "Response model for getting a single operation status.",
+    ///  "examples": [
+    ///    {
+    ///      "completed_at": "2024-01-15T10:31:30Z",
+    ///      "created_at": "2024-01-15T10:30:00Z",
+    ///      "operation_id": "550e8400-e29b-41d4-a716-446655440000",
+    ///      "operation_type": "refresh_mental_models",
+    ///      "status": "completed",
+    ///      "updated_at": "2024-01-15T10:31:30Z"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "operation_id",
+    ///    "status"
+    ///  ],
+    ///  "properties": {
+    ///    "child_operations": {
+    ///      "title": "Child Operations",
+    ///      "description": "Child operations for batch operations (if applicable)",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ChildOperationStatus"
+    ///      }
+    ///    },
+    ///    "completed_at": {
+    ///      "title": "Completed At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "created_at": {
+    ///      "title": "Created At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "error_message": {
+    ///      "title": "Error Message",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "operation_id": {
+    ///      "title": "Operation Id",
+    ///      "type": "string"
+    ///    },
+    ///    "operation_type": {
+    ///      "title": "Operation Type",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "result_metadata": {
+    ///      "title": "Result Metadata",
+    ///      "description": "Internal metadata for debugging. Structure may change without notice. Not for production use.",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    },
+    ///    "status": {
+    ///      "title": "Status",
+    ///      "type": "string",
+    ///      "enum": [
+    ///        "pending",
+    ///        "completed",
+    ///        "failed",
+    ///        "not_found"
+    ///      ]
+    ///    },
+    ///    "updated_at": {
+    ///      "title": "Updated At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct OperationStatusResponse {
+        ///Child operations for batch operations (if applicable)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub child_operations: ::std::option::Option<
+            ::std::vec::Vec<ChildOperationStatus>,
+        >,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub completed_at: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub created_at: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub error_message: ::std::option::Option<::std::string::String>,
+        pub operation_id: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub operation_type: ::std::option::Option<::std::string::String>,
+        ///Internal metadata for debugging. Structure may change without notice. Not for production use.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub result_metadata: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        pub status: Status,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub updated_at: ::std::option::Option<::std::string::String>,
+    }
+    impl ::std::convert::From<&OperationStatusResponse> for OperationStatusResponse {
+        fn from(value: &OperationStatusResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for list operations endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "OperationsListResponse",
+    ///  "description": "Response model for list operations endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "bank_id": "user123",
+    ///      "limit": 20,
+    ///      "offset": 0,
+    ///      "operations": [
+    ///        {
+    ///          "created_at": "2024-01-15T10:30:00Z",
+    ///          "id": "550e8400-e29b-41d4-a716-446655440000",
+    ///          "status": "pending",
+    ///          "task_type": "retain"
+    ///        }
+    ///      ],
+    ///      "total": 150
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "bank_id",
+    ///    "limit",
+    ///    "offset",
+    ///    "operations",
+    ///    "total"
+    ///  ],
+    ///  "properties": {
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "limit": {
+    ///      "title": "Limit",
+    ///      "type": "integer"
+    ///    },
+    ///    "offset": {
+    ///      "title": "Offset",
+    ///      "type": "integer"
+    ///    },
+    ///    "operations": {
+    ///      "title": "Operations",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/OperationResponse"
+    ///      }
+    ///    },
+    ///    "total": {
+    ///      "title": "Total",
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct OperationsListResponse {
+        pub bank_id: ::std::string::String,
+        pub limit: i64,
+        pub offset: i64,
+        pub operations: ::std::vec::Vec<OperationResponse>,
+        pub total: i64,
+    }
+    impl ::std::convert::From<&OperationsListResponse> for OperationsListResponse {
+        fn from(value: &OperationsListResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Request model for recall endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "RecallRequest",
+    ///  "description": "Request model for recall endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "budget": "mid",
+    ///      "include": {
+    ///        "entities": {
+    ///          "max_tokens": 500
+    ///        }
+    ///      },
+    ///      "max_tokens": 4096,
+    ///      "query": "What did Alice say about machine learning?",
+    ///      "query_timestamp": "2023-05-30T23:40:00",
+    ///      "tags": [
+    ///        "user_a"
+    ///      ],
+    ///      "tags_match": "any",
+    ///      "trace": true,
+    ///      "types": [
+    ///        "world",
+    ///        "experience"
+    ///      ]
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "query"
+    ///  ],
+    ///  "properties": {
+    ///    "budget": {
+    ///      "$ref": "#/components/schemas/Budget"
+    ///    },
+    ///    "include": {
+    ///      "description": "Options for including additional data (entities are included by default)",
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/IncludeOptions"
+    ///        }
+    ///      ]
+    ///    },
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "default": 4096,
+    ///      "type": "integer"
+    ///    },
+    ///    "query": {
+    ///      "title": "Query",
+    ///      "type": "string"
+    ///    },
+    ///    "query_timestamp": {
+    ///      "title": "Query Timestamp",
+    ///      "description": "ISO format date string (e.g., '2023-05-30T23:40:00')",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "Filter memories by tags. If not specified, all memories are returned.",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "tags_match": {
+    ///      "title": "Tags Match",
+    ///      "description": "How to match tags: 'any' (OR, includes untagged), 'all' (AND, includes untagged), 'any_strict' (OR, excludes untagged), 'all_strict' (AND, excludes untagged).",
+    ///      "default": "any",
+    ///      "type": "string",
+    ///      "enum": [
+    ///        "any",
+    ///        "all",
+    ///        "any_strict",
+    ///        "all_strict"
+    ///      ]
+    ///    },
+    ///    "trace": {
+    ///      "title": "Trace",
+    ///      "default": false,
+    ///      "type": "boolean"
+    ///    },
+    ///    "types": {
+    ///      "title": "Types",
+    ///      "description": "List of fact types to recall: 'world', 'experience', 'observation'. Defaults to world and experience if not specified.",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct RecallRequest {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub budget: ::std::option::Option<Budget>,
+        ///Options for including additional data (entities are included by default)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub include: ::std::option::Option<IncludeOptions>,
+        #[serde(default = "defaults::default_u64::<i64, 4096>")]
+        pub max_tokens: i64,
+        pub query: ::std::string::String,
+        ///ISO format date string (e.g., '2023-05-30T23:40:00')
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub query_timestamp: ::std::option::Option<::std::string::String>,
+        ///Filter memories by tags. If not specified, all memories are returned.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ///How to match tags: 'any' (OR, includes untagged), 'all' (AND, includes untagged), 'any_strict' (OR, excludes untagged), 'all_strict' (AND, excludes untagged).
+        #[serde(default = "defaults::recall_request_tags_match")]
+        pub tags_match: TagsMatch,
+        #[serde(default)]
+        pub trace: bool,
+        ///List of fact types to recall: 'world', 'experience', 'observation'. Defaults to world and experience if not specified.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub types: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+    }
+    impl ::std::convert::From<&RecallRequest> for RecallRequest {
+        fn from(value: &RecallRequest) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for recall endpoints.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "RecallResponse",
+    ///  "description": "Response model for recall endpoints.",
+    ///  "examples": [
+    ///    {
+    ///      "chunks": {
+    ///        "456e7890-e12b-34d5-a678-901234567890": {
+    ///          "chunk_index": 0,
+    ///          "id": "456e7890-e12b-34d5-a678-901234567890",
+    ///          "text": "Alice works at Google on the AI team. She's been there for 3 years..."
+    ///        }
+    ///      },
+    ///      "entities": {
+    ///        "Alice": {
+    ///          "canonical_name": "Alice",
+    ///          "entity_id": "123e4567-e89b-12d3-a456-426614174001",
+    ///          "observations": [
+    ///            {
+    ///              "mentioned_at": "2024-01-15T10:30:00Z",
+    ///              "text": "Alice works at Google on the AI team"
+    ///            }
+    ///          ]
+    ///        }
+    ///      },
+    ///      "results": [
+    ///        {
+    ///          "chunk_id": "456e7890-e12b-34d5-a678-901234567890",
+    ///          "context": "work info",
+    ///          "entities": [
+    ///            "Alice",
+    ///            "Google"
+    ///          ],
+    ///          "id": "123e4567-e89b-12d3-a456-426614174000",
+    ///          "occurred_end": "2024-01-15T10:30:00Z",
+    ///          "occurred_start": "2024-01-15T10:30:00Z",
+    ///          "text": "Alice works at Google on the AI team",
+    ///          "type": "world"
+    ///        }
+    ///      ],
+    ///      "trace": {
+    ///        "num_results": 1,
+    ///        "query": "What did Alice say about machine learning?",
+    ///        "time_seconds": 0.123
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "results"
+    ///  ],
+    ///  "properties": {
+    ///    "chunks": {
+    ///      "title": "Chunks",
+    ///      "description": "Chunks for facts, keyed by chunk_id",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": {
+    ///        "$ref": "#/components/schemas/ChunkData"
+    ///      }
+    ///    },
+    ///    "entities": {
+    ///      "title": "Entities",
+    ///      "description": "Entity states for entities mentioned in results",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": {
+    ///        "$ref": "#/components/schemas/EntityStateResponse"
+    ///      }
+    ///    },
+    ///    "results": {
+    ///      "title": "Results",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/RecallResult"
+    ///      }
+    ///    },
+    ///    "source_facts": {
+    ///      "title": "Source Facts",
+    ///      "description": "Source facts for observation-type results, keyed by fact ID",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": {
+    ///        "$ref": "#/components/schemas/RecallResult"
+    ///      }
+    ///    },
+    ///    "trace": {
+    ///      "title": "Trace",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct RecallResponse {
+        ///Chunks for facts, keyed by chunk_id
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub chunks: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, ChunkData>,
+        >,
+        ///Entity states for entities mentioned in results
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub entities: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, EntityStateResponse>,
+        >,
+        pub results: ::std::vec::Vec<RecallResult>,
+        ///Source facts for observation-type results, keyed by fact ID
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub source_facts: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, RecallResult>,
+        >,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub trace: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+    }
+    impl ::std::convert::From<&RecallResponse> for RecallResponse {
+        fn from(value: &RecallResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Single recall result item.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "RecallResult",
+    ///  "description": "Single recall result item.",
+    ///  "examples": [
+    ///    {
+    ///      "chunk_id": "456e7890-e12b-34d5-a678-901234567890",
+    ///      "context": "work info",
+    ///      "document_id": "session_abc123",
+    ///      "entities": [
+    ///        "Alice",
+    ///        "Google"
+    ///      ],
+    ///      "id": "123e4567-e89b-12d3-a456-426614174000",
+    ///      "mentioned_at": "2024-01-15T10:30:00Z",
+    ///      "metadata": {
+    ///        "source": "slack"
+    ///      },
+    ///      "occurred_end": "2024-01-15T10:30:00Z",
+    ///      "occurred_start": "2024-01-15T10:30:00Z",
+    ///      "tags": [
+    ///        "user_a",
+    ///        "user_b"
+    ///      ],
+    ///      "text": "Alice works at Google on the AI team",
+    ///      "type": "world"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "id",
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "chunk_id": {
+    ///      "title": "Chunk Id",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "context": {
+    ///      "title": "Context",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "document_id": {
+    ///      "title": "Document Id",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "entities": {
+    ///      "title": "Entities",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": "string"
+    ///    },
+    ///    "mentioned_at": {
+    ///      "title": "Mentioned At",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "metadata": {
+    ///      "title": "Metadata",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "occurred_end": {
+    ///      "title": "Occurred End",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "occurred_start": {
+    ///      "title": "Occurred Start",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "source_fact_ids": {
+    ///      "title": "Source Fact Ids",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "text": {
+    ///      "title": "Text",
+    ///      "type": "string"
+    ///    },
+    ///    "type": {
+    ///      "title": "Type",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct RecallResult {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub chunk_id: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub context: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub document_id: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub entities: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        pub id: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub mentioned_at: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub metadata: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+        >,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub occurred_end: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub occurred_start: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub source_fact_ids: ::std::option::Option<
+            ::std::vec::Vec<::std::string::String>,
+        >,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        pub text: ::std::string::String,
+        #[serde(
+            rename = "type",
+            default,
+            skip_serializing_if = "::std::option::Option::is_none"
+        )]
+        pub type_: ::std::option::Option<::std::string::String>,
+    }
+    impl ::std::convert::From<&RecallResult> for RecallResult {
+        fn from(value: &RecallResult) -> Self {
+            value.clone()
+        }
+    }
+    ///Evidence the response is based on: memories, mental models, and directives.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectBasedOn",
+    ///  "description": "Evidence the response is based on: memories, mental models, and directives.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "directives": {
+    ///      "title": "Directives",
+    ///      "description": "Directives applied during reflection",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ReflectDirective"
+    ///      }
+    ///    },
+    ///    "memories": {
+    ///      "title": "Memories",
+    ///      "description": "Memory facts used to generate the response",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ReflectFact"
+    ///      }
+    ///    },
+    ///    "mental_models": {
+    ///      "title": "Mental Models",
+    ///      "description": "Mental models used during reflection",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ReflectMentalModel"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectBasedOn {
+        ///Directives applied during reflection
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub directives: ::std::vec::Vec<ReflectDirective>,
+        ///Memory facts used to generate the response
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub memories: ::std::vec::Vec<ReflectFact>,
+        ///Mental models used during reflection
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub mental_models: ::std::vec::Vec<ReflectMentalModel>,
+    }
+    impl ::std::convert::From<&ReflectBasedOn> for ReflectBasedOn {
+        fn from(value: &ReflectBasedOn) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for ReflectBasedOn {
+        fn default() -> Self {
+            Self {
+                directives: Default::default(),
+                memories: Default::default(),
+                mental_models: Default::default(),
+            }
+        }
+    }
+    ///A directive applied during reflect.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectDirective",
+    ///  "description": "A directive applied during reflect.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "content",
+    ///    "id",
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "content": {
+    ///      "title": "Content",
+    ///      "description": "Directive content",
+    ///      "type": "string"
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "description": "Directive ID",
+    ///      "type": "string"
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "description": "Directive name",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectDirective {
+        ///Directive content
+        pub content: ::std::string::String,
+        ///Directive ID
+        pub id: ::std::string::String,
+        ///Directive name
+        pub name: ::std::string::String,
+    }
+    impl ::std::convert::From<&ReflectDirective> for ReflectDirective {
+        fn from(value: &ReflectDirective) -> Self {
+            value.clone()
+        }
+    }
+    ///A fact used in think response.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectFact",
+    ///  "description": "A fact used in think response.",
+    ///  "examples": [
+    ///    {
+    ///      "context": "healthcare discussion",
+    ///      "id": "123e4567-e89b-12d3-a456-426614174000",
+    ///      "occurred_end": "2024-01-15T10:30:00Z",
+    ///      "occurred_start": "2024-01-15T10:30:00Z",
+    ///      "text": "AI is used in healthcare",
+    ///      "type": "world"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "context": {
+    ///      "title": "Context",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "occurred_end": {
+    ///      "title": "Occurred End",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "occurred_start": {
+    ///      "title": "Occurred Start",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "text": {
+    ///      "title": "Text",
+    ///      "description": "Fact text. When type='observation', this contains markdown-formatted consolidated knowledge",
+    ///      "type": "string"
+    ///    },
+    ///    "type": {
+    ///      "title": "Type",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectFact {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub context: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub id: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub occurred_end: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub occurred_start: ::std::option::Option<::std::string::String>,
+        ///Fact text. When type='observation', this contains markdown-formatted consolidated knowledge
+        pub text: ::std::string::String,
+        #[serde(
+            rename = "type",
+            default,
+            skip_serializing_if = "::std::option::Option::is_none"
+        )]
+        pub type_: ::std::option::Option<::std::string::String>,
+    }
+    impl ::std::convert::From<&ReflectFact> for ReflectFact {
+        fn from(value: &ReflectFact) -> Self {
+            value.clone()
+        }
+    }
+    ///Options for including additional data in reflect results.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectIncludeOptions",
+    ///  "description": "Options for including additional data in reflect results.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "facts": {
+    ///      "description": "Include facts that the answer is based on. Set to {} to enable, null to disable (default: disabled).",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/FactsIncludeOptions"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    },
+    ///    "tool_calls": {
+    ///      "description": "Include tool calls trace. Set to {} for full trace (input+output), {output: false} for inputs only.",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/ToolCallsIncludeOptions"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectIncludeOptions {
+        ///Include facts that the answer is based on. Set to {} to enable, null to disable (default: disabled).
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub facts: ::std::option::Option<FactsIncludeOptions>,
+        ///Include tool calls trace. Set to {} for full trace (input+output), {output: false} for inputs only.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub tool_calls: ::std::option::Option<ToolCallsIncludeOptions>,
+    }
+    impl ::std::convert::From<&ReflectIncludeOptions> for ReflectIncludeOptions {
+        fn from(value: &ReflectIncludeOptions) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for ReflectIncludeOptions {
+        fn default() -> Self {
+            Self {
+                facts: Default::default(),
+                tool_calls: Default::default(),
+            }
+        }
+    }
+    ///An LLM call made during reflect agent execution.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectLLMCall",
+    ///  "description": "An LLM call made during reflect agent execution.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "duration_ms",
+    ///    "scope"
+    ///  ],
+    ///  "properties": {
+    ///    "duration_ms": {
+    ///      "title": "Duration Ms",
+    ///      "description": "Execution time in milliseconds",
+    ///      "type": "integer"
+    ///    },
+    ///    "scope": {
+    ///      "title": "Scope",
+    ///      "description": "Call scope: agent_1, agent_2, final, etc.",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectLlmCall {
+        ///Execution time in milliseconds
+        pub duration_ms: i64,
+        ///Call scope: agent_1, agent_2, final, etc.
+        pub scope: ::std::string::String,
+    }
+    impl ::std::convert::From<&ReflectLlmCall> for ReflectLlmCall {
+        fn from(value: &ReflectLlmCall) -> Self {
+            value.clone()
+        }
+    }
+    ///A mental model used during reflect.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectMentalModel",
+    ///  "description": "A mental model used during reflect.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "id",
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "context": {
+    ///      "title": "Context",
+    ///      "description": "Additional context",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "title": "Id",
+    ///      "description": "Mental model ID",
+    ///      "type": "string"
+    ///    },
+    ///    "text": {
+    ///      "title": "Text",
+    ///      "description": "Mental model content",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectMentalModel {
+        ///Additional context
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub context: ::std::option::Option<::std::string::String>,
+        ///Mental model ID
+        pub id: ::std::string::String,
+        ///Mental model content
+        pub text: ::std::string::String,
+    }
+    impl ::std::convert::From<&ReflectMentalModel> for ReflectMentalModel {
+        fn from(value: &ReflectMentalModel) -> Self {
+            value.clone()
+        }
+    }
+    ///Request model for reflect endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectRequest",
+    ///  "description": "Request model for reflect endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "budget": "low",
+    ///      "include": {
+    ///        "facts": {}
+    ///      },
+    ///      "max_tokens": 4096,
+    ///      "query": "What do you think about artificial intelligence?",
+    ///      "response_schema": {
+    ///        "properties": {
+    ///          "key_points": {
+    ///            "items": {
+    ///              "type": "string"
+    ///            },
+    ///            "type": "array"
+    ///          },
+    ///          "summary": {
+    ///            "type": "string"
+    ///          }
+    ///        },
+    ///        "required": [
+    ///          "summary",
+    ///          "key_points"
+    ///        ],
+    ///        "type": "object"
+    ///      },
+    ///      "tags": [
+    ///        "user_a"
+    ///      ],
+    ///      "tags_match": "any"
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "query"
+    ///  ],
+    ///  "properties": {
+    ///    "budget": {
+    ///      "$ref": "#/components/schemas/Budget"
+    ///    },
+    ///    "context": {
+    ///      "title": "Context",
+    ///      "description": "DEPRECATED: Additional context is now concatenated with the query. Pass context directly in the query field instead. If provided, it will be appended to the query for backward compatibility.",
+    ///      "deprecated": true,
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "include": {
+    ///      "description": "Options for including additional data (disabled by default)",
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/ReflectIncludeOptions"
+    ///        }
+    ///      ]
+    ///    },
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "description": "Maximum tokens for the response",
+    ///      "default": 4096,
+    ///      "type": "integer"
+    ///    },
+    ///    "query": {
+    ///      "title": "Query",
+    ///      "type": "string"
+    ///    },
+    ///    "response_schema": {
+    ///      "title": "Response Schema",
+    ///      "description": "Optional JSON Schema for structured output. When provided, the response will include a 'structured_output' field with the LLM response parsed according to this schema.",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "Filter memories by tags during reflection. If not specified, all memories are considered.",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "tags_match": {
+    ///      "title": "Tags Match",
+    ///      "description": "How to match tags: 'any' (OR, includes untagged), 'all' (AND, includes untagged), 'any_strict' (OR, excludes untagged), 'all_strict' (AND, excludes untagged).",
+    ///      "default": "any",
+    ///      "type": "string",
+    ///      "enum": [
+    ///        "any",
+    ///        "all",
+    ///        "any_strict",
+    ///        "all_strict"
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectRequest {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub budget: ::std::option::Option<Budget>,
+        ///DEPRECATED: Additional context is now concatenated with the query. Pass context directly in the query field instead. If provided, it will be appended to the query for backward compatibility.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub context: ::std::option::Option<::std::string::String>,
+        ///Options for including additional data (disabled by default)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub include: ::std::option::Option<ReflectIncludeOptions>,
+        ///Maximum tokens for the response
+        #[serde(default = "defaults::default_u64::<i64, 4096>")]
+        pub max_tokens: i64,
+        pub query: ::std::string::String,
+        ///Optional JSON Schema for structured output. When provided, the response will include a 'structured_output' field with the LLM response parsed according to this schema.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub response_schema: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        ///Filter memories by tags during reflection. If not specified, all memories are considered.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ///How to match tags: 'any' (OR, includes untagged), 'all' (AND, includes untagged), 'any_strict' (OR, excludes untagged), 'all_strict' (AND, excludes untagged).
+        #[serde(default = "defaults::reflect_request_tags_match")]
+        pub tags_match: TagsMatch,
+    }
+    impl ::std::convert::From<&ReflectRequest> for ReflectRequest {
+        fn from(value: &ReflectRequest) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for think endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectResponse",
+    ///  "description": "Response model for think endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "based_on": {
+    ///        "memories": [
+    ///          {
+    ///            "id": "123",
+    ///            "text": "AI is used in healthcare",
+    ///            "type": "world"
+    ///          },
+    ///          {
+    ///            "id": "456",
+    ///            "text": "I discussed AI applications last week",
+    ///            "type": "experience"
+    ///          }
+    ///        ]
+    ///      },
+    ///      "structured_output": {
+    ///        "key_points": [
+    ///          "Used in healthcare",
+    ///          "Discussed recently"
+    ///        ],
+    ///        "summary": "AI is transformative"
+    ///      },
+    ///      "text": "## AI Overview\n\nBased on my understanding, AI is a **transformative technology**:\n\n- Used extensively in healthcare\n- Discussed in recent conversations\n- Continues to evolve rapidly",
+    ///      "trace": {
+    ///        "llm_calls": [
+    ///          {
+    ///            "duration_ms": 1200,
+    ///            "scope": "agent_1"
+    ///          }
+    ///        ],
+    ///        "observations": [
+    ///          {
+    ///            "id": "obs-1",
+    ///            "name": "AI Technology",
+    ///            "subtype": "structural",
+    ///            "type": "concept"
+    ///          }
+    ///        ],
+    ///        "tool_calls": [
+    ///          {
+    ///            "duration_ms": 150,
+    ///            "input": {
+    ///              "query": "AI"
+    ///            },
+    ///            "tool": "recall"
+    ///          }
+    ///        ]
+    ///      },
+    ///      "usage": {
+    ///        "input_tokens": 1500,
+    ///        "output_tokens": 500,
+    ///        "total_tokens": 2000
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "text"
+    ///  ],
+    ///  "properties": {
+    ///    "based_on": {
+    ///      "description": "Evidence used to generate the response. Only present when include.facts is set.",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/ReflectBasedOn"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    },
+    ///    "structured_output": {
+    ///      "title": "Structured Output",
+    ///      "description": "Structured output parsed according to the request's response_schema. Only present when response_schema was provided in the request.",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    },
+    ///    "text": {
+    ///      "title": "Text",
+    ///      "description": "The reflect response as well-formatted markdown (headers, lists, bold/italic, code blocks, etc.)",
+    ///      "type": "string"
+    ///    },
+    ///    "trace": {
+    ///      "description": "Execution trace of tool and LLM calls. Only present when include.tool_calls is set.",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/ReflectTrace"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    },
+    ///    "usage": {
+    ///      "description": "Token usage metrics for LLM calls during reflection.",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/TokenUsage"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectResponse {
+        ///Evidence used to generate the response. Only present when include.facts is set.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub based_on: ::std::option::Option<ReflectBasedOn>,
+        ///Structured output parsed according to the request's response_schema. Only present when response_schema was provided in the request.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub structured_output: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        ///The reflect response as well-formatted markdown (headers, lists, bold/italic, code blocks, etc.)
+        pub text: ::std::string::String,
+        ///Execution trace of tool and LLM calls. Only present when include.tool_calls is set.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub trace: ::std::option::Option<ReflectTrace>,
+        ///Token usage metrics for LLM calls during reflection.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub usage: ::std::option::Option<TokenUsage>,
+    }
+    impl ::std::convert::From<&ReflectResponse> for ReflectResponse {
+        fn from(value: &ReflectResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///A tool call made during reflect agent execution.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectToolCall",
+    ///  "description": "A tool call made during reflect agent execution.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "duration_ms",
+    ///    "input",
+    ///    "tool"
+    ///  ],
+    ///  "properties": {
+    ///    "duration_ms": {
+    ///      "title": "Duration Ms",
+    ///      "description": "Execution time in milliseconds",
+    ///      "type": "integer"
+    ///    },
+    ///    "input": {
+    ///      "title": "Input",
+    ///      "description": "Tool input parameters",
+    ///      "type": "object",
+    ///      "additionalProperties": true
+    ///    },
+    ///    "iteration": {
+    ///      "title": "Iteration",
+    ///      "description": "Iteration number (1-based) when this tool was called",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    },
+    ///    "output": {
+    ///      "title": "Output",
+    ///      "description": "Tool output (only included when include.tool_calls.output is true)",
+    ///      "type": [
+    ///        "object",
+    ///        "null"
+    ///      ],
+    ///      "additionalProperties": true
+    ///    },
+    ///    "tool": {
+    ///      "title": "Tool",
+    ///      "description": "Tool name: lookup, recall, learn, expand",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectToolCall {
+        ///Execution time in milliseconds
+        pub duration_ms: i64,
+        ///Tool input parameters
+        pub input: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        ///Iteration number (1-based) when this tool was called
+        #[serde(default)]
+        pub iteration: i64,
+        ///Tool output (only included when include.tool_calls.output is true)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub output: ::std::option::Option<
+            ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        >,
+        ///Tool name: lookup, recall, learn, expand
+        pub tool: ::std::string::String,
+    }
+    impl ::std::convert::From<&ReflectToolCall> for ReflectToolCall {
+        fn from(value: &ReflectToolCall) -> Self {
+            value.clone()
+        }
+    }
+    ///Execution trace of LLM and tool calls during reflection.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ReflectTrace",
+    ///  "description": "Execution trace of LLM and tool calls during reflection.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "llm_calls": {
+    ///      "title": "Llm Calls",
+    ///      "description": "LLM calls made during reflection",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ReflectLLMCall"
+    ///      }
+    ///    },
+    ///    "tool_calls": {
+    ///      "title": "Tool Calls",
+    ///      "description": "Tool calls made during reflection",
+    ///      "default": [],
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ReflectToolCall"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ReflectTrace {
+        ///LLM calls made during reflection
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub llm_calls: ::std::vec::Vec<ReflectLlmCall>,
+        ///Tool calls made during reflection
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub tool_calls: ::std::vec::Vec<ReflectToolCall>,
+    }
+    impl ::std::convert::From<&ReflectTrace> for ReflectTrace {
+        fn from(value: &ReflectTrace) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for ReflectTrace {
+        fn default() -> Self {
+            Self {
+                llm_calls: Default::default(),
+                tool_calls: Default::default(),
+            }
+        }
+    }
+    ///Request model for retain endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "RetainRequest",
+    ///  "description": "Request model for retain endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "async": false,
+    ///      "document_tags": [
+    ///        "user_a",
+    ///        "user_b"
+    ///      ],
+    ///      "items": [
+    ///        {
+    ///          "content": "Alice works at Google",
+    ///          "context": "work",
+    ///          "document_id": "conversation_123"
+    ///        },
+    ///        {
+    ///          "content": "Bob went hiking yesterday",
+    ///          "document_id": "conversation_123",
+    ///          "timestamp": "2024-01-15T10:00:00Z"
+    ///        }
+    ///      ]
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "items"
+    ///  ],
+    ///  "properties": {
+    ///    "async": {
+    ///      "title": "Async",
+    ///      "description": "If true, process asynchronously in background. If false, wait for completion (default: false)",
+    ///      "default": false,
+    ///      "type": "boolean"
+    ///    },
+    ///    "document_tags": {
+    ///      "title": "Document Tags",
+    ///      "description": "Tags applied to all items in this request. These are merged with any item-level tags.",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "items": {
+    ///      "title": "Items",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/MemoryItem"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct RetainRequest {
+        ///If true, process asynchronously in background. If false, wait for completion (default: false)
+        #[serde(rename = "async", default)]
+        pub async_: bool,
+        ///Tags applied to all items in this request. These are merged with any item-level tags.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub document_tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        pub items: ::std::vec::Vec<MemoryItem>,
+    }
+    impl ::std::convert::From<&RetainRequest> for RetainRequest {
+        fn from(value: &RetainRequest) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for retain endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "RetainResponse",
+    ///  "description": "Response model for retain endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "async": false,
+    ///      "bank_id": "user123",
+    ///      "items_count": 2,
+    ///      "success": true,
+    ///      "usage": {
+    ///        "input_tokens": 500,
+    ///        "output_tokens": 100,
+    ///        "total_tokens": 600
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "async",
+    ///    "bank_id",
+    ///    "items_count",
+    ///    "success"
+    ///  ],
+    ///  "properties": {
+    ///    "async": {
+    ///      "title": "Async",
+    ///      "description": "Whether the operation was processed asynchronously",
+    ///      "type": "boolean"
+    ///    },
+    ///    "bank_id": {
+    ///      "title": "Bank Id",
+    ///      "type": "string"
+    ///    },
+    ///    "items_count": {
+    ///      "title": "Items Count",
+    ///      "type": "integer"
+    ///    },
+    ///    "operation_id": {
+    ///      "title": "Operation Id",
+    ///      "description": "Operation ID for tracking async operations. Use GET /v1/default/banks/{bank_id}/operations to list operations. Only present when async=true.",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "success": {
+    ///      "title": "Success",
+    ///      "type": "boolean"
+    ///    },
+    ///    "usage": {
+    ///      "description": "Token usage metrics for LLM calls during fact extraction (only present for synchronous operations)",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/TokenUsage"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct RetainResponse {
+        ///Whether the operation was processed asynchronously
+        #[serde(rename = "async")]
+        pub async_: bool,
+        pub bank_id: ::std::string::String,
+        pub items_count: i64,
+        ///Operation ID for tracking async operations. Use GET /v1/default/banks/{bank_id}/operations to list operations. Only present when async=true.
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub operation_id: ::std::option::Option<::std::string::String>,
+        pub success: bool,
+        ///Token usage metrics for LLM calls during fact extraction (only present for synchronous operations)
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub usage: ::std::option::Option<TokenUsage>,
+    }
+    impl ::std::convert::From<&RetainResponse> for RetainResponse {
+        fn from(value: &RetainResponse) -> Self {
+            value.clone()
+        }
+    }
+    ///Options for including source facts for observation-type results.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "SourceFactsIncludeOptions",
+    ///  "description": "Options for including source facts for observation-type results.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "description": "Maximum tokens for source facts",
+    ///      "default": 4096,
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct SourceFactsIncludeOptions {
+        ///Maximum tokens for source facts
+        #[serde(default = "defaults::default_u64::<i64, 4096>")]
+        pub max_tokens: i64,
+    }
+    impl ::std::convert::From<&SourceFactsIncludeOptions> for SourceFactsIncludeOptions {
+        fn from(value: &SourceFactsIncludeOptions) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for SourceFactsIncludeOptions {
+        fn default() -> Self {
+            Self {
+                max_tokens: defaults::default_u64::<i64, 4096>(),
+            }
+        }
+    }
+    ///`Status`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "Status",
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "pending",
+    ///    "completed",
+    ///    "failed",
+    ///    "not_found"
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(
+        ::serde::Deserialize,
+        ::serde::Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd
+    )]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub enum Status {
+        #[serde(rename = "pending")]
+        Pending,
+        #[serde(rename = "completed")]
+        Completed,
+        #[serde(rename = "failed")]
+        Failed,
+        #[serde(rename = "not_found")]
+        NotFound,
+    }
+    impl ::std::convert::From<&Self> for Status {
+        fn from(value: &Status) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::fmt::Display for Status {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Pending => f.write_str("pending"),
+                Self::Completed => f.write_str("completed"),
+                Self::Failed => f.write_str("failed"),
+                Self::NotFound => f.write_str("not_found"),
+            }
+        }
+    }
+    impl ::std::str::FromStr for Status {
+        type Err = self::error::ConversionError;
+        fn from_str(
+            value: &str,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "pending" => Ok(Self::Pending),
+                "completed" => Ok(Self::Completed),
+                "failed" => Ok(Self::Failed),
+                "not_found" => Ok(Self::NotFound),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<&str> for Status {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &str,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl ::std::convert::TryFrom<&::std::string::String> for Status {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl ::std::convert::TryFrom<::std::string::String> for Status {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    ///Single tag with usage count.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "TagItem",
+    ///  "description": "Single tag with usage count.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "count",
+    ///    "tag"
+    ///  ],
+    ///  "properties": {
+    ///    "count": {
+    ///      "title": "Count",
+    ///      "description": "Number of memories with this tag",
+    ///      "type": "integer"
+    ///    },
+    ///    "tag": {
+    ///      "title": "Tag",
+    ///      "description": "The tag value",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct TagItem {
+        ///Number of memories with this tag
+        pub count: i64,
+        ///The tag value
+        pub tag: ::std::string::String,
+    }
+    impl ::std::convert::From<&TagItem> for TagItem {
+        fn from(value: &TagItem) -> Self {
+            value.clone()
+        }
+    }
+    ///How to match tags: 'any' (OR, includes untagged), 'all' (AND, includes untagged), 'any_strict' (OR, excludes untagged), 'all_strict' (AND, excludes untagged).
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "Tags Match",
+    ///  "description": "How to match tags: 'any' (OR, includes untagged), 'all' (AND, includes untagged), 'any_strict' (OR, excludes untagged), 'all_strict' (AND, excludes untagged).",
+    ///  "default": "any",
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "any",
+    ///    "all",
+    ///    "any_strict",
+    ///    "all_strict"
+    ///  ]
+    ///}
+    /// ```
+    /// </details>
+    #[derive(
+        ::serde::Deserialize,
+        ::serde::Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd
+    )]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub enum TagsMatch {
+        #[serde(rename = "any")]
+        Any,
+        #[serde(rename = "all")]
+        All,
+        #[serde(rename = "any_strict")]
+        AnyStrict,
+        #[serde(rename = "all_strict")]
+        AllStrict,
+    }
+    impl ::std::convert::From<&Self> for TagsMatch {
+        fn from(value: &TagsMatch) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::fmt::Display for TagsMatch {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Any => f.write_str("any"),
+                Self::All => f.write_str("all"),
+                Self::AnyStrict => f.write_str("any_strict"),
+                Self::AllStrict => f.write_str("all_strict"),
+            }
+        }
+    }
+    impl ::std::str::FromStr for TagsMatch {
+        type Err = self::error::ConversionError;
+        fn from_str(
+            value: &str,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "any" => Ok(Self::Any),
+                "all" => Ok(Self::All),
+                "any_strict" => Ok(Self::AnyStrict),
+                "all_strict" => Ok(Self::AllStrict),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<&str> for TagsMatch {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &str,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl ::std::convert::TryFrom<&::std::string::String> for TagsMatch {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl ::std::convert::TryFrom<::std::string::String> for TagsMatch {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+    impl ::std::default::Default for TagsMatch {
+        fn default() -> Self {
+            TagsMatch::Any
+        }
+    }
+    /**Token usage metrics for LLM calls.
+
+Tracks input/output tokens for a single request to enable
+per-request cost tracking and monitoring.*/
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "TokenUsage",
+    ///  "description": "Token usage metrics for LLM calls.\n\nTracks input/output tokens for a single request to enable\nper-request cost tracking and monitoring.",
+    ///  "examples": [
+    ///    {
+    ///      "input_tokens": 1500,
+    ///      "output_tokens": 500,
+    ///      "total_tokens": 2000
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "input_tokens": {
+    ///      "title": "Input Tokens",
+    ///      "description": "Number of input/prompt tokens consumed",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    },
+    ///    "output_tokens": {
+    ///      "title": "Output Tokens",
+    ///      "description": "Number of output/completion tokens generated",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    },
+    ///    "total_tokens": {
+    ///      "title": "Total Tokens",
+    ///      "description": "Total tokens (input + output)",
+    ///      "default": 0,
+    ///      "type": "integer"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct TokenUsage {
+        ///Number of input/prompt tokens consumed
+        #[serde(default)]
+        pub input_tokens: i64,
+        ///Number of output/completion tokens generated
+        #[serde(default)]
+        pub output_tokens: i64,
+        ///Total tokens (input + output)
+        #[serde(default)]
+        pub total_tokens: i64,
+    }
+    impl ::std::convert::From<&TokenUsage> for TokenUsage {
+        fn from(value: &TokenUsage) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for TokenUsage {
+        fn default() -> Self {
+            Self {
+                input_tokens: Default::default(),
+                output_tokens: Default::default(),
+                total_tokens: Default::default(),
+            }
+        }
+    }
+    ///Options for including tool calls in reflect results.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ToolCallsIncludeOptions",
+    ///  "description": "Options for including tool calls in reflect results.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "output": {
+    ///      "title": "Output",
+    ///      "description": "Include tool outputs in the trace. Set to false to only include inputs (smaller payload).",
+    ///      "default": true,
+    ///      "type": "boolean"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ToolCallsIncludeOptions {
+        ///Include tool outputs in the trace. Set to false to only include inputs (smaller payload).
+        #[serde(default = "defaults::default_bool::<true>")]
+        pub output: bool,
+    }
+    impl ::std::convert::From<&ToolCallsIncludeOptions> for ToolCallsIncludeOptions {
+        fn from(value: &ToolCallsIncludeOptions) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for ToolCallsIncludeOptions {
+        fn default() -> Self {
+            Self {
+                output: defaults::default_bool::<true>(),
+            }
+        }
+    }
+    ///Request model for updating a directive.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "UpdateDirectiveRequest",
+    ///  "description": "Request model for updating a directive.",
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "content": {
+    ///      "title": "Content",
+    ///      "description": "New content",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "is_active": {
+    ///      "title": "Is Active",
+    ///      "description": "New active status",
+    ///      "type": [
+    ///        "boolean",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "description": "New name",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "priority": {
+    ///      "title": "Priority",
+    ///      "description": "New priority",
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "New tags",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct UpdateDirectiveRequest {
+        ///New content
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub content: ::std::option::Option<::std::string::String>,
+        ///New active status
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub is_active: ::std::option::Option<bool>,
+        ///New name
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub name: ::std::option::Option<::std::string::String>,
+        ///New priority
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub priority: ::std::option::Option<i64>,
+        ///New tags
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+    }
+    impl ::std::convert::From<&UpdateDirectiveRequest> for UpdateDirectiveRequest {
+        fn from(value: &UpdateDirectiveRequest) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for UpdateDirectiveRequest {
+        fn default() -> Self {
+            Self {
+                content: Default::default(),
+                is_active: Default::default(),
+                name: Default::default(),
+                priority: Default::default(),
+                tags: Default::default(),
+            }
+        }
+    }
+    ///Request model for updating disposition traits.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "UpdateDispositionRequest",
+    ///  "description": "Request model for updating disposition traits.",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "disposition"
+    ///  ],
+    ///  "properties": {
+    ///    "disposition": {
+    ///      "$ref": "#/components/schemas/DispositionTraits"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct UpdateDispositionRequest {
+        pub disposition: DispositionTraits,
+    }
+    impl ::std::convert::From<&UpdateDispositionRequest> for UpdateDispositionRequest {
+        fn from(value: &UpdateDispositionRequest) -> Self {
+            value.clone()
+        }
+    }
+    ///Request model for updating a mental model.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "UpdateMentalModelRequest",
+    ///  "description": "Request model for updating a mental model.",
+    ///  "examples": [
+    ///    {
+    ///      "max_tokens": 4096,
+    ///      "name": "Updated Team Communication Preferences",
+    ///      "source_query": "How does the team prefer to communicate?",
+    ///      "tags": [
+    ///        "team",
+    ///        "communication"
+    ///      ],
+    ///      "trigger": {
+    ///        "refresh_after_consolidation": true
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "max_tokens": {
+    ///      "title": "Max Tokens",
+    ///      "description": "Maximum tokens for generated content",
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ],
+    ///      "maximum": 8192.0,
+    ///      "minimum": 256.0
+    ///    },
+    ///    "name": {
+    ///      "title": "Name",
+    ///      "description": "New name for the mental model",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "source_query": {
+    ///      "title": "Source Query",
+    ///      "description": "New source query for the mental model",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "tags": {
+    ///      "title": "Tags",
+    ///      "description": "Tags for scoped visibility",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+    ///    },
+    ///    "trigger": {
+    ///      "description": "Trigger settings",
+    ///      "oneOf": [
+    ///        {
+    ///          "type": "null"
+    ///        },
+    ///        {
+    ///          "allOf": [
+    ///            {
+    ///              "$ref": "#/components/schemas/MentalModelTrigger"
+    ///            }
+    ///          ]
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct UpdateMentalModelRequest {
+        ///Maximum tokens for generated content
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub max_tokens: ::std::option::Option<i64>,
+        ///New name for the mental model
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub name: ::std::option::Option<::std::string::String>,
+        ///New source query for the mental model
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub source_query: ::std::option::Option<::std::string::String>,
+        ///Tags for scoped visibility
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ///Trigger settings
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub trigger: ::std::option::Option<MentalModelTrigger>,
+    }
+    impl ::std::convert::From<&UpdateMentalModelRequest> for UpdateMentalModelRequest {
+        fn from(value: &UpdateMentalModelRequest) -> Self {
+            value.clone()
+        }
+    }
+    impl ::std::default::Default for UpdateMentalModelRequest {
+        fn default() -> Self {
+            Self {
+                max_tokens: Default::default(),
+                name: Default::default(),
+                source_query: Default::default(),
+                tags: Default::default(),
+                trigger: Default::default(),
+            }
+        }
+    }
+    ///`ValidationError`
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "ValidationError",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "loc",
+    ///    "msg",
+    ///    "type"
+    ///  ],
+    ///  "properties": {
+    ///    "loc": {
+    ///      "title": "Location",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "anyOf": [
+    ///          {
+    ///            "type": "string"
+    ///          },
+    ///          {
+    ///            "type": "integer"
+    ///          }
+    ///        ]
+    ///      }
+    ///    },
+    ///    "msg": {
+    ///      "title": "Message",
+    ///      "type": "string"
+    ///    },
+    ///    "type": {
+    ///      "title": "Error Type",
+    ///      "type": "string"
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct ValidationError {
+        pub loc: ::std::vec::Vec<LocationItem>,
+        pub msg: ::std::string::String,
+        #[serde(rename = "type")]
+        pub type_: ::std::string::String,
+    }
+    impl ::std::convert::From<&ValidationError> for ValidationError {
+        fn from(value: &ValidationError) -> Self {
+            value.clone()
+        }
+    }
+    ///Response model for the version/info endpoint.
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    ///{
+    ///  "title": "VersionResponse",
+    ///  "description": "Response model for the version/info endpoint.",
+    ///  "examples": [
+    ///    {
+    ///      "api_version": "0.4.0",
+    ///      "features": {
+    ///        "bank_config_api": false,
+    ///        "file_upload_api": true,
+    ///        "mcp": true,
+    ///        "observations": false,
+    ///        "worker": true
+    ///      }
+    ///    }
+    ///  ],
+    ///  "type": "object",
+    ///  "required": [
+    ///    "api_version",
+    ///    "features"
+    ///  ],
+    ///  "properties": {
+    ///    "api_version": {
+    ///      "title": "Api Version",
+    ///      "description": "API version string",
+    ///      "type": "string"
+    ///    },
+    ///    "features": {
+    ///      "description": "Enabled feature flags",
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/FeaturesInfo"
+    ///        }
+    ///      ]
+    ///    }
+    ///  }
+    ///}
+    /// ```
+    /// </details>
+    #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub struct VersionResponse {
+        ///API version string
+        pub api_version: ::std::string::String,
+        ///Enabled feature flags
+        pub features: FeaturesInfo,
+    }
+    impl ::std::convert::From<&VersionResponse> for VersionResponse {
+        fn from(value: &VersionResponse) -> Self {
+            value.clone()
+        }
+    }
+    /// Generation of default values for serde.
+    pub mod defaults {
+        pub(super) fn default_bool<const V: bool>() -> bool {
+            V
+        }
+        pub(super) fn default_u64<T, const V: u64>() -> T
+        where
+            T: ::std::convert::TryFrom<u64>,
+            <T as ::std::convert::TryFrom<u64>>::Error: ::std::fmt::Debug,
+        {
+            T::try_from(V).unwrap()
+        }
+        pub(super) fn recall_request_tags_match() -> super::TagsMatch {
+            super::TagsMatch::Any
+        }
+        pub(super) fn reflect_request_tags_match() -> super::TagsMatch {
+            super::TagsMatch::Any
+        }
+    }
+}
+#[derive(Clone, Debug)]
+/**Client for Hindsight HTTP API
+
+HTTP API for Hindsight
+
+Version: 0.4.13*/
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+}
+impl Client {
+    /// Create a new client.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = ::std::time::Duration::from_secs(15u64);
+            reqwest::ClientBuilder::new().connect_timeout(dur).timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+    /// Construct a new client with an existing `reqwest::Client`,
+    /// allowing more control over its configuration.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+        }
+    }
+}
+impl ClientInfo<()> for Client {
+    fn api_version() -> &'static str {
+        "0.4.13"
+    }
+    fn baseurl(&self) -> &str {
+        self.baseurl.as_str()
+    }
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+    fn inner(&self) -> &() {
+        &()
+    }
+}
+impl ClientHooks<()> for &Client {}
+#[allow(clippy::all)]
+impl Client {
+    /**Health check endpoint
+
+Checks the health of the API and database connection
+
+Sends a `GET` request to `/health`
+
+*/
+    pub async fn health_endpoint_health_get<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<::serde_json::Value>, Error<()>> {
+        let url = format!("{}/health", self.baseurl,);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "health_endpoint_health_get",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Prometheus metrics endpoint
+
+Exports metrics in Prometheus format for scraping
+
+Sends a `GET` request to `/metrics`
+
+*/
+    pub async fn metrics_endpoint_metrics_get<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<::serde_json::Value>, Error<()>> {
+        let url = format!("{}/metrics", self.baseurl,);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "metrics_endpoint_metrics_get",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List all memory banks
+
+Get a list of all agents with their profiles
+
+Sends a `GET` request to `/v1/default/banks`
+
+*/
+    pub async fn list_banks<'a>(
+        &'a self,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::BankListResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!("{}/v1/default/banks", self.baseurl,);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_banks",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Create or update memory bank
+
+Create a new agent or update existing agent with disposition and mission. Auto-fills missing fields with defaults.
+
+Sends a `PUT` request to `/v1/default/banks/{bank_id}`
+
+*/
+    pub async fn create_or_update_bank<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::CreateBankRequest,
+    ) -> Result<
+        ResponseValue<types::BankProfileResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}", self.baseurl, encode_path(& bank_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .put(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "create_or_update_bank",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Delete memory bank
+
+Delete an entire memory bank including all memories, entities, documents, and the bank profile itself. This is a destructive operation that cannot be undone.
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}`
+
+*/
+    pub async fn delete_bank<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::DeleteResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}", self.baseurl, encode_path(& bank_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "delete_bank",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Partial update memory bank
+
+Partially update an agent's profile. Only provided fields will be updated.
+
+Sends a `PATCH` request to `/v1/default/banks/{bank_id}`
+
+*/
+    pub async fn update_bank<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::CreateBankRequest,
+    ) -> Result<
+        ResponseValue<types::BankProfileResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}", self.baseurl, encode_path(& bank_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .patch(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "update_bank",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Add/merge memory bank background (deprecated)
+
+Deprecated: Use PUT /mission instead. This endpoint now updates the mission field.
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/background`
+
+*/
+    pub async fn add_bank_background<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::AddBackgroundRequest,
+    ) -> Result<
+        ResponseValue<types::BackgroundResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/background", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "add_bank_background",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get bank configuration
+
+Get fully resolved configuration for a bank including all hierarchical overrides (global → tenant → bank). The 'config' field contains all resolved config values. The 'overrides' field shows only bank-specific overrides.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/config`
+
+*/
+    pub async fn get_bank_config<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::BankConfigResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/config", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_bank_config",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Reset bank configuration
+
+Reset bank configuration to defaults by removing all bank-specific overrides. The bank will then use global and tenant-level configuration only.
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}/config`
+
+*/
+    pub async fn reset_bank_config<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::BankConfigResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/config", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "reset_bank_config",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Update bank configuration
+
+Update configuration overrides for a bank. Only hierarchical fields can be overridden (LLM settings, retention parameters, etc.). Keys can be provided in Python field format (llm_provider) or environment variable format (HINDSIGHT_API_LLM_PROVIDER).
+
+Sends a `PATCH` request to `/v1/default/banks/{bank_id}/config`
+
+*/
+    pub async fn update_bank_config<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::BankConfigUpdate,
+    ) -> Result<
+        ResponseValue<types::BankConfigResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/config", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .patch(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "update_bank_config",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Trigger consolidation
+
+Run memory consolidation to create/update observations from recent memories.
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/consolidate`
+
+*/
+    pub async fn trigger_consolidation<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::ConsolidationResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/consolidate", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "trigger_consolidation",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List directives
+
+List hard rules that are injected into prompts.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/directives`
+
+Arguments:
+- `bank_id`
+- `active_only`: Only return active directives
+- `limit`
+- `offset`
+- `tags`: Filter by tags
+- `tags_match`: How to match tags
+- `authorization`
+*/
+    pub async fn list_directives<'a>(
+        &'a self,
+        bank_id: &'a str,
+        active_only: Option<bool>,
+        limit: Option<::std::num::NonZeroU64>,
+        offset: Option<u64>,
+        tags: Option<&'a ::std::vec::Vec<::std::string::String>>,
+        tags_match: Option<types::TagsMatch>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::DirectiveListResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/directives", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("active_only", &active_only))
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("offset", &offset))
+            .query(&progenitor_client::QueryParam::new("tags", &tags))
+            .query(&progenitor_client::QueryParam::new("tags_match", &tags_match))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_directives",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Create directive
+
+Create a hard rule that will be injected into prompts.
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/directives`
+
+*/
+    pub async fn create_directive<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::CreateDirectiveRequest,
+    ) -> Result<
+        ResponseValue<types::DirectiveResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/directives", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "create_directive",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get directive
+
+Get a specific directive by ID.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/directives/{directive_id}`
+
+*/
+    pub async fn get_directive<'a>(
+        &'a self,
+        bank_id: &'a str,
+        directive_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::DirectiveResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/directives/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& directive_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_directive",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Delete directive
+
+Delete a directive.
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}/directives/{directive_id}`
+
+*/
+    pub async fn delete_directive<'a>(
+        &'a self,
+        bank_id: &'a str,
+        directive_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<ResponseValue<::serde_json::Value>, Error<types::HttpValidationError>> {
+        let url = format!(
+            "{}/v1/default/banks/{}/directives/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& directive_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "delete_directive",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Update directive
+
+Update a directive's properties.
+
+Sends a `PATCH` request to `/v1/default/banks/{bank_id}/directives/{directive_id}`
+
+*/
+    pub async fn update_directive<'a>(
+        &'a self,
+        bank_id: &'a str,
+        directive_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::UpdateDirectiveRequest,
+    ) -> Result<
+        ResponseValue<types::DirectiveResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/directives/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& directive_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .patch(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "update_directive",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List documents
+
+List documents with pagination and optional search. Documents are the source content from which memory units are extracted.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/documents`
+
+*/
+    pub async fn list_documents<'a>(
+        &'a self,
+        bank_id: &'a str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        q: Option<&'a str>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::ListDocumentsResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/documents", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("offset", &offset))
+            .query(&progenitor_client::QueryParam::new("q", &q))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_documents",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get document details
+
+Get a specific document including its original text
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/documents/{document_id}`
+
+*/
+    pub async fn get_document<'a>(
+        &'a self,
+        bank_id: &'a str,
+        document_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::DocumentResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/documents/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& document_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_document",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Delete a document
+
+Delete a document and all its associated memory units and links.
+
+This will cascade delete:
+- The document itself
+- All memory units extracted from this document
+- All links (temporal, semantic, entity) associated with those memory units
+
+This operation cannot be undone.
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}/documents/{document_id}`
+
+*/
+    pub async fn delete_document<'a>(
+        &'a self,
+        bank_id: &'a str,
+        document_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::DeleteDocumentResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/documents/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& document_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "delete_document",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List entities
+
+List all entities (people, organizations, etc.) known by the bank, ordered by mention count. Supports pagination.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/entities`
+
+Arguments:
+- `bank_id`
+- `limit`: Maximum number of entities to return
+- `offset`: Offset for pagination
+- `authorization`
+*/
+    pub async fn list_entities<'a>(
+        &'a self,
+        bank_id: &'a str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::EntityListResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/entities", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("offset", &offset))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_entities",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get entity details
+
+Get detailed information about an entity including observations (mental model).
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/entities/{entity_id}`
+
+*/
+    pub async fn get_entity<'a>(
+        &'a self,
+        bank_id: &'a str,
+        entity_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::EntityDetailResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/entities/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& entity_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_entity",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Regenerate entity observations (deprecated)
+
+This endpoint is deprecated. Entity observations have been replaced by mental models.
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/entities/{entity_id}/regenerate`
+
+*/
+    pub async fn regenerate_entity_observations<'a>(
+        &'a self,
+        bank_id: &'a str,
+        entity_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::EntityDetailResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/entities/{}/regenerate", self.baseurl, encode_path(&
+            bank_id.to_string()), encode_path(& entity_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "regenerate_entity_observations",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get memory graph data
+
+Retrieve graph data for visualization, optionally filtered by type (world/experience/opinion).
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/graph`
+
+*/
+    pub async fn get_graph<'a>(
+        &'a self,
+        bank_id: &'a str,
+        limit: Option<i64>,
+        type_: Option<&'a str>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::GraphDataResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/graph", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("type", &type_))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_graph",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Retain memories
+
+Retain memory items with automatic fact extraction.
+
+This is the main endpoint for storing memories. It supports both synchronous and asynchronous processing via the `async` parameter.
+
+**Features:**
+- Efficient batch processing
+- Automatic fact extraction from natural language
+- Entity recognition and linking
+- Document tracking with automatic upsert (when document_id is provided)
+- Temporal and semantic linking
+- Optional asynchronous processing
+
+**The system automatically:**
+1. Extracts semantic facts from the content
+2. Generates embeddings
+3. Deduplicates similar facts
+4. Creates temporal, semantic, and entity links
+5. Tracks document metadata
+
+**When `async=true`:** Returns immediately after queuing. Use the operations endpoint to monitor progress.
+
+**When `async=false` (default):** Waits for processing to complete.
+
+**Note:** If a memory item has a `document_id` that already exists, the old document and its memory units will be deleted before creating new ones (upsert behavior).
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/memories`
+
+*/
+    pub async fn retain_memories<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::RetainRequest,
+    ) -> Result<
+        ResponseValue<types::RetainResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/memories", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "retain_memories",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Clear memory bank memories
+
+Delete memory units for a memory bank. Optionally filter by type (world, experience, opinion) to delete only specific types. This is a destructive operation that cannot be undone. The bank profile (disposition and background) will be preserved.
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}/memories`
+
+Arguments:
+- `bank_id`
+- `type_`: Optional fact type filter (world, experience, opinion)
+- `authorization`
+*/
+    pub async fn clear_bank_memories<'a>(
+        &'a self,
+        bank_id: &'a str,
+        type_: Option<&'a str>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::DeleteResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/memories", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("type", &type_))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "clear_bank_memories",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List memory units
+
+List memory units with pagination and optional full-text search. Supports filtering by type. Results are sorted by most recent first (mentioned_at DESC, then created_at DESC).
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/memories/list`
+
+*/
+    pub async fn list_memories<'a>(
+        &'a self,
+        bank_id: &'a str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        q: Option<&'a str>,
+        type_: Option<&'a str>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::ListMemoryUnitsResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/memories/list", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("offset", &offset))
+            .query(&progenitor_client::QueryParam::new("q", &q))
+            .query(&progenitor_client::QueryParam::new("type", &type_))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_memories",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Recall memory
+
+Recall memory using semantic similarity and spreading activation.
+
+The type parameter is optional and must be one of:
+- `world`: General knowledge about people, places, events, and things that happen
+- `experience`: Memories about experience, conversations, actions taken, and tasks performed
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/memories/recall`
+
+*/
+    pub async fn recall_memories<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::RecallRequest,
+    ) -> Result<
+        ResponseValue<types::RecallResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/memories/recall", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "recall_memories",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get memory unit
+
+Get a single memory unit by ID with all its metadata including entities and tags.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/memories/{memory_id}`
+
+*/
+    pub async fn get_memory<'a>(
+        &'a self,
+        bank_id: &'a str,
+        memory_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<ResponseValue<::serde_json::Value>, Error<types::HttpValidationError>> {
+        let url = format!(
+            "{}/v1/default/banks/{}/memories/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& memory_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_memory",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List mental models
+
+List user-curated living documents that stay current.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/mental-models`
+
+Arguments:
+- `bank_id`
+- `limit`
+- `offset`
+- `tags`: Filter by tags
+- `tags_match`: How to match tags
+- `authorization`
+*/
+    pub async fn list_mental_models<'a>(
+        &'a self,
+        bank_id: &'a str,
+        limit: Option<::std::num::NonZeroU64>,
+        offset: Option<u64>,
+        tags: Option<&'a ::std::vec::Vec<::std::string::String>>,
+        tags_match: Option<types::TagsMatch>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::MentalModelListResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/mental-models", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("offset", &offset))
+            .query(&progenitor_client::QueryParam::new("tags", &tags))
+            .query(&progenitor_client::QueryParam::new("tags_match", &tags_match))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_mental_models",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Create mental model
+
+Create a mental model by running reflect with the source query in the background. Returns an operation ID to track progress. The content is auto-generated by the reflect endpoint. Use the operations endpoint to check completion status.
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/mental-models`
+
+*/
+    pub async fn create_mental_model<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::CreateMentalModelRequest,
+    ) -> Result<
+        ResponseValue<types::CreateMentalModelResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/mental-models", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "create_mental_model",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get mental model
+
+Get a specific mental model by ID.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/mental-models/{mental_model_id}`
+
+*/
+    pub async fn get_mental_model<'a>(
+        &'a self,
+        bank_id: &'a str,
+        mental_model_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::MentalModelResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/mental-models/{}", self.baseurl, encode_path(&
+            bank_id.to_string()), encode_path(& mental_model_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_mental_model",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Delete mental model
+
+Delete a mental model.
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}/mental-models/{mental_model_id}`
+
+*/
+    pub async fn delete_mental_model<'a>(
+        &'a self,
+        bank_id: &'a str,
+        mental_model_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<ResponseValue<::serde_json::Value>, Error<types::HttpValidationError>> {
+        let url = format!(
+            "{}/v1/default/banks/{}/mental-models/{}", self.baseurl, encode_path(&
+            bank_id.to_string()), encode_path(& mental_model_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "delete_mental_model",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Update mental model
+
+Update a mental model's name and/or source query.
+
+Sends a `PATCH` request to `/v1/default/banks/{bank_id}/mental-models/{mental_model_id}`
+
+*/
+    pub async fn update_mental_model<'a>(
+        &'a self,
+        bank_id: &'a str,
+        mental_model_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::UpdateMentalModelRequest,
+    ) -> Result<
+        ResponseValue<types::MentalModelResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/mental-models/{}", self.baseurl, encode_path(&
+            bank_id.to_string()), encode_path(& mental_model_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .patch(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "update_mental_model",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Refresh mental model
+
+Submit an async task to re-run the source query through reflect and update the content.
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/mental-models/{mental_model_id}/refresh`
+
+*/
+    pub async fn refresh_mental_model<'a>(
+        &'a self,
+        bank_id: &'a str,
+        mental_model_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::AsyncOperationSubmitResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/mental-models/{}/refresh", self.baseurl,
+            encode_path(& bank_id.to_string()), encode_path(& mental_model_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "refresh_mental_model",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Clear all observations
+
+Delete all observations for a memory bank. This is useful for resetting the consolidated knowledge.
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}/observations`
+
+*/
+    pub async fn clear_observations<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::DeleteResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/observations", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "clear_observations",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List async operations
+
+Get a list of async operations for a specific agent, with optional filtering by status. Results are sorted by most recent first.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/operations`
+
+Arguments:
+- `bank_id`
+- `limit`: Maximum number of operations to return
+- `offset`: Number of operations to skip
+- `status`: Filter by status: pending, completed, or failed
+- `authorization`
+*/
+    pub async fn list_operations<'a>(
+        &'a self,
+        bank_id: &'a str,
+        limit: Option<::std::num::NonZeroU64>,
+        offset: Option<u64>,
+        status: Option<&'a str>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::OperationsListResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/operations", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("offset", &offset))
+            .query(&progenitor_client::QueryParam::new("status", &status))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_operations",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get operation status
+
+Get the status of a specific async operation. Returns 'pending', 'completed', or 'failed'. Completed operations are removed from storage, so 'completed' means the operation finished successfully.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/operations/{operation_id}`
+
+*/
+    pub async fn get_operation_status<'a>(
+        &'a self,
+        bank_id: &'a str,
+        operation_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::OperationStatusResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/operations/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& operation_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_operation_status",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Cancel a pending async operation
+
+Cancel a pending async operation by removing it from the queue
+
+Sends a `DELETE` request to `/v1/default/banks/{bank_id}/operations/{operation_id}`
+
+*/
+    pub async fn cancel_operation<'a>(
+        &'a self,
+        bank_id: &'a str,
+        operation_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::CancelOperationResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/operations/{}", self.baseurl, encode_path(& bank_id
+            .to_string()), encode_path(& operation_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .delete(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "cancel_operation",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get memory bank profile
+
+Get disposition traits and mission for a memory bank. Auto-creates agent with defaults if not exists.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/profile`
+
+*/
+    pub async fn get_bank_profile<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::BankProfileResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/profile", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_bank_profile",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Update memory bank disposition
+
+Update bank's disposition traits (skepticism, literalism, empathy)
+
+Sends a `PUT` request to `/v1/default/banks/{bank_id}/profile`
+
+*/
+    pub async fn update_bank_disposition<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::UpdateDispositionRequest,
+    ) -> Result<
+        ResponseValue<types::BankProfileResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/profile", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .put(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "update_bank_disposition",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Reflect and generate answer
+
+Reflect and formulate an answer using bank identity, world facts, and opinions.
+
+This endpoint:
+1. Retrieves experience (conversations and events)
+2. Retrieves world facts relevant to the query
+3. Retrieves existing opinions (bank's perspectives)
+4. Uses LLM to formulate a contextual answer
+5. Returns plain text answer and the facts used
+
+Sends a `POST` request to `/v1/default/banks/{bank_id}/reflect`
+
+*/
+    pub async fn reflect<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+        body: &'a types::ReflectRequest,
+    ) -> Result<
+        ResponseValue<types::ReflectResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/reflect", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .post(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(&body)
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "reflect",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get statistics for memory bank
+
+Get statistics about nodes and links for a specific agent
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/stats`
+
+*/
+    pub async fn get_agent_stats<'a>(
+        &'a self,
+        bank_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::BankStatsResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/stats", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_agent_stats",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**List tags
+
+List all unique tags in a memory bank with usage counts. Supports wildcard search using '*' (e.g., 'user:*', '*-fred', 'tag*-2'). Case-insensitive.
+
+Sends a `GET` request to `/v1/default/banks/{bank_id}/tags`
+
+Arguments:
+- `bank_id`
+- `limit`: Maximum number of tags to return
+- `offset`: Offset for pagination
+- `q`: Wildcard pattern to filter tags (e.g., 'user:*' for user:alice, '*-admin' for role-admin). Use '*' as wildcard. Case-insensitive.
+- `authorization`
+*/
+    pub async fn list_tags<'a>(
+        &'a self,
+        bank_id: &'a str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        q: Option<&'a str>,
+        authorization: Option<&'a str>,
+    ) -> Result<
+        ResponseValue<types::ListTagsResponse>,
+        Error<types::HttpValidationError>,
+    > {
+        let url = format!(
+            "{}/v1/default/banks/{}/tags", self.baseurl, encode_path(& bank_id
+            .to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .query(&progenitor_client::QueryParam::new("limit", &limit))
+            .query(&progenitor_client::QueryParam::new("offset", &offset))
+            .query(&progenitor_client::QueryParam::new("q", &q))
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "list_tags",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get chunk details
+
+Get a specific chunk by its ID
+
+Sends a `GET` request to `/v1/default/chunks/{chunk_id}`
+
+*/
+    pub async fn get_chunk<'a>(
+        &'a self,
+        chunk_id: &'a str,
+        authorization: Option<&'a str>,
+    ) -> Result<ResponseValue<types::ChunkResponse>, Error<types::HttpValidationError>> {
+        let url = format!(
+            "{}/v1/default/chunks/{}", self.baseurl, encode_path(& chunk_id.to_string()),
+        );
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(2usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        if let Some(value) = authorization {
+            header_map.append("authorization", value.to_string().try_into()?);
+        }
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_chunk",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            422u16 => {
+                Err(Error::ErrorResponse(ResponseValue::from_response(response).await?))
+            }
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+    /**Get API version and feature flags
+
+Returns API version information and enabled feature flags. Use this to check which capabilities are available in this deployment.
+
+Sends a `GET` request to `/version`
+
+*/
+    pub async fn get_version<'a>(
+        &'a self,
+    ) -> Result<ResponseValue<types::VersionResponse>, Error<()>> {
+        let url = format!("{}/version", self.baseurl,);
+        let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+        header_map
+            .append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(Self::api_version()),
+            );
+        #[allow(unused_mut)]
+        let mut request = self
+            .client
+            .get(url)
+            .header(
+                ::reqwest::header::ACCEPT,
+                ::reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .headers(header_map)
+            .build()?;
+        let info = OperationInfo {
+            operation_id: "get_version",
+        };
+        self.pre(&mut request, &info).await?;
+        let result = self.exec(request, &info).await;
+        self.post(&result, &info).await?;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+}
+/// Items consumers will typically use such as the Client.
+pub mod prelude {
+    #[allow(unused_imports)]
+    pub use super::Client;
+}
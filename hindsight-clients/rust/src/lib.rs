@@ -19,8 +19,17 @@
 //! }
 //! ```
 
-// Include the generated client code (which already exports Error and ResponseValue)
-include!(concat!(env!("OUT_DIR"), "/hindsight_client_generated.rs"));
+// The generated client (which already exports Error and ResponseValue); see build.rs
+include!("generated.rs");
+
+mod capabilities;
+
+pub use capabilities::{compatibility, parse_version, CallError, Capabilities, Capability, Compatibility, Unsupported, VERSION_RETRY};
+
+// The spec conversion run by build.rs, compiled here for its unit tests
+#[cfg(test)]
+#[path = "../build/openapi.rs"]
+mod openapi;
 
 #[cfg(test)]
 mod tests {
@@ -33,6 +42,40 @@ mod tests {
         assert!(true);
     }
 
+    /// Same hash as `spec_hash` in build/regenerate.rs
+    fn spec_hash(spec: &str) -> u64 {
+        spec.bytes().filter(|&b| b != b'\r').fold(0xcbf29ce484222325, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        })
+    }
+
+    #[test]
+    fn test_vendored_spec_matches_docs() {
+        let manifest_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let docs_path = manifest_dir.join("../../hindsight-docs/static/openapi.json");
+        // Outside the monorepo (e.g. the packaged crate) there is nothing to compare with
+        let Ok(docs_spec) = std::fs::read_to_string(&docs_path) else {
+            return;
+        };
+        let vendored_spec = std::fs::read_to_string(manifest_dir.join("openapi.json")).unwrap();
+        assert!(
+            spec_hash(&vendored_spec) == spec_hash(&docs_spec),
+            "openapi.json is out of date with hindsight-docs/static/openapi.json; \
+             run `cargo build --features regenerate` and commit the result"
+        );
+    }
+
+    #[test]
+    fn test_generated_code_matches_spec() {
+        let vendored_spec = include_str!("../openapi.json");
+        let expected = format!("// spec-hash: {:016x}", spec_hash(vendored_spec));
+        assert!(
+            include_str!("generated.rs").lines().nth(1) == Some(expected.as_str()),
+            "src/generated.rs was not generated from openapi.json; \
+             run `cargo build --features regenerate` and commit the result"
+        );
+    }
+
     #[tokio::test]
     async fn test_memory_lifecycle() {
         let api_url = std::env::var("HINDSIGHT_API_URL")
@@ -2,7 +2,7 @@
 set -e
 
 # Script to generate Python, TypeScript, and Go clients from OpenAPI spec
-# Note: Rust client is auto-generated at build time via build.rs (uses progenitor)
+# Note: the Rust client is regenerated by build.rs (progenitor) with --features regenerate
 # Usage: ./scripts/generate-clients.sh
 
 # Pin openapi-generator version for reproducible builds across local and CI
@@ -56,12 +56,13 @@ RUST_CLIENT_DIR="$CLIENTS_DIR/rust"
 echo "Cleaning old Rust generated code..."
 rm -rf "$RUST_CLIENT_DIR/target"
 
-# Trigger regeneration by building
+# Regenerate by building with the regenerate feature, which refreshes the
+# vendored openapi.json and the checked-in src/generated.rs
 # Use --locked to ensure reproducible builds from committed Cargo.lock
 echo "Regenerating Rust client (via build.rs)..."
 cd "$RUST_CLIENT_DIR"
 cargo clean
-cargo build --release --locked
+cargo build --release --locked --features regenerate
 
 echo "✓ Rust client generated at $RUST_CLIENT_DIR"
 echo ""